@@ -1,6 +1,6 @@
 use std::path::Path;
 
-use anyhow::{Context, Result, anyhow, bail};
+use anyhow::{Context, Result, bail};
 use music_score::planner::{SectionTemplate, builtin_template, load_template_from_path};
 use music_theory::{Key12, PitchClass12, key::Mode};
 
@@ -58,58 +58,8 @@ pub fn resolve_template(
 }
 
 pub fn parse_key(tonic: &str, mode: Mode) -> Result<Key12> {
-    let pitch_class = parse_pitch_class(tonic)
+    let pitch_class: PitchClass12 = tonic
+        .parse()
         .with_context(|| format!("invalid tonic '{tonic}' (expected pitch like C, F#, Bb)"))?;
     Ok(Key12::new(pitch_class, mode))
 }
-
-fn parse_pitch_class(input: &str) -> Result<PitchClass12> {
-    let trimmed = input.trim();
-    if trimmed.is_empty() {
-        bail!("tonic cannot be empty");
-    }
-    let mut chars = trimmed.chars();
-    let letter = chars
-        .next()
-        .ok_or_else(|| anyhow!("tonic must start with A–G letter"))?
-        .to_ascii_uppercase();
-    let base = match letter {
-        'C' => 0,
-        'D' => 2,
-        'E' => 4,
-        'F' => 5,
-        'G' => 7,
-        'A' => 9,
-        'B' => 11,
-        _ => bail!("tonic must begin with A, B, C, D, E, F, or G"),
-    };
-    let mut offset: i32 = 0;
-    for ch in chars {
-        offset += match ch {
-            '#' | '♯' => 1,
-            'b' | '♭' => -1,
-            _ => bail!("unrecognized accidental '{ch}' (use # or b)"),
-        };
-    }
-    let semitone = (base + offset).rem_euclid(12) as u16;
-    Ok(PitchClass12::from_semitones(semitone))
-}
-
-const PITCH_CLASS_LABELS: [&str; 12] =
-    ["C", "C#", "D", "Eb", "E", "F", "F#", "G", "Ab", "A", "Bb", "B"];
-
-#[must_use]
-pub fn pitch_class_label(pc: PitchClass12) -> &'static str {
-    let index = pc.to_semitones() as usize % 12;
-    PITCH_CLASS_LABELS[index]
-}
-
-#[must_use]
-pub fn format_key_label(key: Key12) -> String {
-    let tonic = pitch_class_label(key.tonic);
-    let mode_label = match key.mode {
-        Mode::Major => "major",
-        Mode::Minor => "minor",
-    };
-    format!("{tonic} {mode_label}")
-}
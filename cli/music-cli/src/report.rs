@@ -1,4 +1,4 @@
-use crate::planner_util::{TemplateLocator, format_key_label, pitch_class_label};
+use crate::planner_util::TemplateLocator;
 use music_api::{
     BarSummary, CadenceSummary, ExplainModeDto, KeySpecification, ModeDto, PhraseSummary,
     PlanResponse, StateSnapshot, TemplateDescriptor, TemplateSourceDescriptor,
@@ -21,7 +21,7 @@ pub fn print_text_report(
         template.bars,
         locator.describe()
     );
-    println!("Key: {}", format_key_label(key));
+    println!("Key: {key}");
     println!("Style: {} | Explain: {:?}", style_label, explain_mode);
 
     if planned.diagnostics.is_empty() {
@@ -163,10 +163,7 @@ pub fn build_json_report(
                 path: locator.file_path.clone(),
             },
         },
-        key: KeySpecification {
-            tonic: pitch_class_label(key.tonic).to_string(),
-            mode: mode_dto(key.mode),
-        },
+        key: KeySpecification { tonic: key.tonic.to_string(), mode: mode_dto(key.mode) },
         style: style_label.to_string(),
         explain_mode: explain_mode_dto(explain_mode),
         diagnostics: planned.diagnostics.clone(),
@@ -11,6 +11,8 @@ use music_acoustic::{T12, Temperament};
 
 /// Triad quality (root + third + fifth).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum TriadKind {
     Major,
     Minor,
@@ -32,12 +34,15 @@ impl TriadKind {
 
 /// Seventh-chord quality (root + third + fifth + seventh).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum SeventhKind {
     Major7,
     Dominant7,
     Minor7,
     HalfDiminished7,
     Diminished7,
+    MinorMajor7,
 }
 
 impl SeventhKind {
@@ -49,12 +54,15 @@ impl SeventhKind {
             Self::Minor7 => &[0, 3, 7, 10],
             Self::HalfDiminished7 => &[0, 3, 6, 10],
             Self::Diminished7 => &[0, 3, 6, 9],
+            Self::MinorMajor7 => &[0, 3, 7, 11],
         }
     }
 }
 
 /// Common extended chords (add and 9/11/13 structures).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum ExtendedKind {
     Add9,
     Add11,
@@ -92,35 +100,281 @@ impl ExtendedKind {
     }
 }
 
+/// Suspended chords: the third replaced by a second or a fourth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SuspendedKind {
+    Sus2,
+    Sus4,
+    Dominant7Sus4,
+}
+
+impl SuspendedKind {
+    #[must_use]
+    pub const fn intervals(self) -> &'static [i32] {
+        match self {
+            Self::Sus2 => &[0, 2, 7],
+            Self::Sus4 => &[0, 5, 7],
+            Self::Dominant7Sus4 => &[0, 5, 7, 10],
+        }
+    }
+}
+
+/// Non-tertian stacks of perfect fourths or fifths (three to five tones).
+///
+/// Stacks are symmetric under reversal, so a quartal stack on C (`C F Bb`) holds the same
+/// pitch classes as a quintal stack on Bb; identification prefers the sounding bass as root.
+/// Stacks whose pitch classes also spell a tertian or suspended chord on the same root (three
+/// fifths are a sus2, five a 6/9) identify as that chord.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum StackedKind {
+    Quartal3,
+    Quartal4,
+    Quartal5,
+    Quintal3,
+    Quintal4,
+    Quintal5,
+}
+
+impl StackedKind {
+    #[must_use]
+    pub const fn intervals(self) -> &'static [i32] {
+        match self {
+            Self::Quartal3 => &[0, 5, 10],
+            Self::Quartal4 => &[0, 5, 10, 15],
+            Self::Quartal5 => &[0, 5, 10, 15, 20],
+            Self::Quintal3 => &[0, 7, 14],
+            Self::Quintal4 => &[0, 7, 14, 21],
+            Self::Quintal5 => &[0, 7, 14, 21, 28],
+        }
+    }
+
+    /// Stack of `count` fourths (`quintal == false`) or fifths, if `count` is 3–5.
+    #[must_use]
+    pub const fn of(quintal: bool, count: usize) -> Option<Self> {
+        Some(match (quintal, count) {
+            (false, 3) => Self::Quartal3,
+            (false, 4) => Self::Quartal4,
+            (false, 5) => Self::Quartal5,
+            (true, 3) => Self::Quintal3,
+            (true, 4) => Self::Quintal4,
+            (true, 5) => Self::Quintal5,
+            _ => return None,
+        })
+    }
+}
+
 /// Unified chord-kind wrapper.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ChordKind {
     Triad(TriadKind),
     Seventh(SeventhKind),
     Extended(ExtendedKind),
+    Suspended(SuspendedKind),
+    Stacked(StackedKind),
 }
 
 impl ChordKind {
+    /// Every canonical chord kind, ordered from triads to extended structures, then suspended
+    /// chords and quartal/quintal stacks.
+    pub const ALL: [Self; 32] = [
+        Self::Triad(TriadKind::Major),
+        Self::Triad(TriadKind::Minor),
+        Self::Triad(TriadKind::Diminished),
+        Self::Triad(TriadKind::Augmented),
+        Self::Seventh(SeventhKind::Major7),
+        Self::Seventh(SeventhKind::Dominant7),
+        Self::Seventh(SeventhKind::Minor7),
+        Self::Seventh(SeventhKind::HalfDiminished7),
+        Self::Seventh(SeventhKind::Diminished7),
+        Self::Seventh(SeventhKind::MinorMajor7),
+        Self::Extended(ExtendedKind::Add9),
+        Self::Extended(ExtendedKind::Add11),
+        Self::Extended(ExtendedKind::Add13),
+        Self::Extended(ExtendedKind::SixNine),
+        Self::Extended(ExtendedKind::Major9),
+        Self::Extended(ExtendedKind::Dominant9),
+        Self::Extended(ExtendedKind::Minor9),
+        Self::Extended(ExtendedKind::Major11),
+        Self::Extended(ExtendedKind::Dominant11),
+        Self::Extended(ExtendedKind::Minor11),
+        Self::Extended(ExtendedKind::Major13),
+        Self::Extended(ExtendedKind::Dominant13),
+        Self::Extended(ExtendedKind::Minor13),
+        Self::Suspended(SuspendedKind::Sus2),
+        Self::Suspended(SuspendedKind::Sus4),
+        Self::Suspended(SuspendedKind::Dominant7Sus4),
+        Self::Stacked(StackedKind::Quartal3),
+        Self::Stacked(StackedKind::Quartal4),
+        Self::Stacked(StackedKind::Quartal5),
+        Self::Stacked(StackedKind::Quintal3),
+        Self::Stacked(StackedKind::Quintal4),
+        Self::Stacked(StackedKind::Quintal5),
+    ];
+
     #[must_use]
     pub const fn intervals(self) -> &'static [i32] {
         match self {
             Self::Triad(kind) => kind.intervals(),
             Self::Seventh(kind) => kind.intervals(),
             Self::Extended(kind) => kind.intervals(),
+            Self::Suspended(kind) => kind.intervals(),
+            Self::Stacked(kind) => kind.intervals(),
         }
     }
+
+    /// Identify the chord kind whose semitone offsets (above the root) match the input.
+    ///
+    /// Exact matches on compound offsets (`14` for a ninth) win; otherwise offsets are compared
+    /// tone-by-tone within the octave so closely stacked forms (an add-13 written as a sixth)
+    /// still resolve. Quartal and quintal stacks are tried last, and only when no other kind
+    /// on the same root holds the same pitch classes (`C G D` is a sus2, not three fifths).
+    #[must_use]
+    pub fn identify(semitones: &[i32]) -> Option<Self> {
+        let ordered = |kind: &Self| {
+            let table = kind.intervals();
+            table.len() == semitones.len()
+                && table.iter().zip(semitones).all(|(a, b)| a.rem_euclid(12) == b.rem_euclid(12))
+        };
+        let (stacks, others): (Vec<Self>, Vec<Self>) =
+            Self::ALL.into_iter().partition(|kind| matches!(kind, Self::Stacked(_)));
+        let pitch_classes = semitone_mask(semitones);
+        others
+            .iter()
+            .copied()
+            .find(|kind| kind.intervals() == semitones)
+            .or_else(|| others.iter().copied().find(ordered))
+            .or_else(|| {
+                others.iter().copied().find(|kind| semitone_mask(kind.intervals()) == pitch_classes)
+            })
+            .or_else(|| stacks.iter().copied().find(|kind| kind.intervals() == semitones))
+            .or_else(|| stacks.iter().copied().find(ordered))
+    }
+
+    /// Underlying triad quality (root, third, fifth) of the chord kind, or `None` for kinds
+    /// without a third (suspended chords and quartal/quintal stacks).
+    #[must_use]
+    pub const fn triad(self) -> Option<TriadKind> {
+        Some(match self {
+            Self::Triad(kind) => kind,
+            Self::Seventh(SeventhKind::Major7 | SeventhKind::Dominant7) => TriadKind::Major,
+            Self::Seventh(SeventhKind::Minor7 | SeventhKind::MinorMajor7) => TriadKind::Minor,
+            Self::Seventh(SeventhKind::HalfDiminished7 | SeventhKind::Diminished7) => {
+                TriadKind::Diminished
+            }
+            Self::Extended(
+                ExtendedKind::Minor9 | ExtendedKind::Minor11 | ExtendedKind::Minor13,
+            ) => TriadKind::Minor,
+            Self::Extended(_) => TriadKind::Major,
+            Self::Suspended(_) | Self::Stacked(_) => return None,
+        })
+    }
+
+    /// Chord-symbol suffix appended to the root name (`""`, `"m"`, `"maj7"`, `"m7b5"`, ...).
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Triad(TriadKind::Major) => "",
+            Self::Triad(TriadKind::Minor) => "m",
+            Self::Triad(TriadKind::Diminished) => "dim",
+            Self::Triad(TriadKind::Augmented) => "aug",
+            Self::Seventh(SeventhKind::Major7) => "maj7",
+            Self::Seventh(SeventhKind::Dominant7) => "7",
+            Self::Seventh(SeventhKind::Minor7) => "m7",
+            Self::Seventh(SeventhKind::HalfDiminished7) => "m7b5",
+            Self::Seventh(SeventhKind::Diminished7) => "dim7",
+            Self::Seventh(SeventhKind::MinorMajor7) => "m(maj7)",
+            Self::Extended(ExtendedKind::Add9) => "add9",
+            Self::Extended(ExtendedKind::Add11) => "add11",
+            Self::Extended(ExtendedKind::Add13) => "add13",
+            Self::Extended(ExtendedKind::SixNine) => "6/9",
+            Self::Extended(ExtendedKind::Major9) => "maj9",
+            Self::Extended(ExtendedKind::Dominant9) => "9",
+            Self::Extended(ExtendedKind::Minor9) => "m9",
+            Self::Extended(ExtendedKind::Major11) => "maj11",
+            Self::Extended(ExtendedKind::Dominant11) => "11",
+            Self::Extended(ExtendedKind::Minor11) => "m11",
+            Self::Extended(ExtendedKind::Major13) => "maj13",
+            Self::Extended(ExtendedKind::Dominant13) => "13",
+            Self::Extended(ExtendedKind::Minor13) => "m13",
+            Self::Suspended(SuspendedKind::Sus2) => "sus2",
+            Self::Suspended(SuspendedKind::Sus4) => "sus4",
+            Self::Suspended(SuspendedKind::Dominant7Sus4) => "7sus4",
+            Self::Stacked(StackedKind::Quartal3) => "quartal3",
+            Self::Stacked(StackedKind::Quartal4) => "quartal4",
+            Self::Stacked(StackedKind::Quartal5) => "quartal5",
+            Self::Stacked(StackedKind::Quintal3) => "quintal3",
+            Self::Stacked(StackedKind::Quintal4) => "quintal4",
+            Self::Stacked(StackedKind::Quintal5) => "quintal5",
+        }
+    }
+
+    /// Parse a chord-symbol suffix, accepting [`ChordKind::symbol`] output plus common aliases
+    /// (`"min"`, `"-"`, `"M7"`, `"ø7"`, `"°7"`, `"+"`, `"sus"`, `"quartal"`).
+    #[must_use]
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        let canonical = match symbol {
+            "min" | "-" => "m",
+            "°" | "o" => "dim",
+            "+" => "aug",
+            "M7" | "Δ7" | "Δ" => "maj7",
+            "min7" | "-7" => "m7",
+            "mMaj7" | "mM7" | "mmaj7" | "-maj7" | "m(M7)" => "m(maj7)",
+            "ø" | "ø7" => "m7b5",
+            "°7" | "o7" => "dim7",
+            "M9" | "Δ9" => "maj9",
+            "min9" | "-9" => "m9",
+            "69" => "6/9",
+            "sus" => "sus4",
+            "quartal" | "4ths" => "quartal3",
+            "quintal" | "5ths" => "quintal3",
+            other => other,
+        };
+        Self::ALL.into_iter().find(|kind| kind.symbol() == canonical)
+    }
 }
 
-/// Chord as an ordered collection of pitch-classes in a temperament.
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Pitch-class set of semitone offsets as a 12-bit mask.
+fn semitone_mask(semitones: &[i32]) -> u16 {
+    semitones.iter().fold(0, |mask, semitone| mask | 1 << semitone.rem_euclid(12))
+}
+
+/// Chord as a collection of pitch-classes in a temperament with an explicit root.
+///
+/// [`Chord::root`] starts as the first tone and changes only through [`Chord::reroot`]; the
+/// sounding bass and inversion index are kept in step by [`Chord::with_bass`] and
+/// [`Chord::invert`], which never reorder the tones. The tones are read-only outside the crate
+/// so the root, bass and inversion cannot go stale; build a new chord to change them.
+#[derive(Debug, PartialEq, Eq)]
 pub struct Chord<T: Temperament> {
-    pub tones: Vec<PitchClass<T>>,
+    pub(crate) tones: Vec<PitchClass<T>>,
+    pub(crate) root: Option<PitchClass<T>>,
+    /// Bass pitch-class when it differs from the root (`C/E`, `D/C`); `None` = root position.
+    pub(crate) bass: Option<PitchClass<T>>,
+    /// Position of the sounding bass among the root-position tones.
+    pub(crate) inversion: Option<usize>,
+}
+
+impl<T: Temperament> Clone for Chord<T> {
+    fn clone(&self) -> Self {
+        Self {
+            tones: self.tones.clone(),
+            root: self.root,
+            bass: self.bass,
+            inversion: self.inversion,
+        }
+    }
 }
 
 impl<T: Temperament> Chord<T> {
+    /// Root-position chord rooted on the first tone.
     #[must_use]
     pub fn new(tones: Vec<PitchClass<T>>) -> Self {
-        Self { tones }
+        let root = tones.first().copied();
+        Self { tones, root, bass: None, inversion: root.map(|_| 0) }
     }
 
     /// Build a chord from root + explicit interval offsets (in temperament steps).
@@ -136,6 +390,12 @@ impl<T: Temperament> Chord<T> {
         Self::from_intervals(root, kind.intervals())
     }
 
+    /// Chord tones in stored order (see [`Chord::root_position`]).
+    #[must_use]
+    pub fn tones(&self) -> &[PitchClass<T>] {
+        &self.tones
+    }
+
     /// Number of unique tones in the chord.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -153,6 +413,47 @@ impl<T: Temperament> Chord<T> {
     pub fn contains(&self, pitch_class: PitchClass<T>) -> bool {
         self.tones.contains(&pitch_class)
     }
+
+    /// Step offsets of every tone above the root, stacked upward in root-position order.
+    ///
+    /// Each tone sits above its predecessor, so a ninth chord yields `[0, 4, 7, 11, 14]` in
+    /// 12-TET rather than wrapping the ninth back into the octave.
+    #[must_use]
+    pub fn stacked_intervals(&self) -> Vec<i32> {
+        let steps = i32::from(T::STEPS_PER_OCTAVE);
+        let mut offsets = Vec::with_capacity(self.tones.len());
+        let mut previous: Option<(i32, u16)> = None;
+        for tone in &self.root_position() {
+            let offset = match previous {
+                None => 0,
+                Some((offset, index)) => {
+                    let gap = (i32::from(tone.index()) - i32::from(index)).rem_euclid(steps);
+                    offset + gap
+                }
+            };
+            offsets.push(offset);
+            previous = Some((offset, tone.index()));
+        }
+        offsets
+    }
+
+    /// Identify the canonical [`ChordKind`] of the chord, if its stacked intervals match one.
+    ///
+    /// Kind tables are expressed in 12-TET semitones, so identification only succeeds for
+    /// temperaments whose octave divides evenly into twelve semitones (12-TET, 24-TET, ...).
+    #[must_use]
+    pub fn kind(&self) -> Option<ChordKind> {
+        let per_semitone = i32::from(T::STEPS_PER_OCTAVE) / 12;
+        if per_semitone == 0 || i32::from(T::STEPS_PER_OCTAVE) % 12 != 0 {
+            return None;
+        }
+        let offsets = self.stacked_intervals();
+        if offsets.iter().any(|steps| steps % per_semitone != 0) {
+            return None;
+        }
+        let semitones: Vec<i32> = offsets.iter().map(|steps| steps / per_semitone).collect();
+        ChordKind::identify(&semitones)
+    }
 }
 
 /// Convenience alias for 12-TET chords.
@@ -208,6 +509,11 @@ impl Chord12 {
     pub fn extended(root: PitchClass12, kind: ExtendedKind) -> Self {
         Self::from_kind(root, ChordKind::Extended(kind))
     }
+
+    #[must_use]
+    pub fn stacked(root: PitchClass12, kind: StackedKind) -> Self {
+        Self::from_kind(root, ChordKind::Stacked(kind))
+    }
 }
 
 #[cfg(test)]
@@ -256,4 +562,40 @@ mod tests {
         let expected = vec![semitone(0), semitone(4), semitone(7), semitone(11), semitone(2)];
         assert_eq!(chord.tones, expected);
     }
+
+    #[test]
+    fn every_kind_identifies_from_its_own_chord() {
+        for kind in ChordKind::ALL {
+            let chord = Chord12::from_kind(semitone(5), kind);
+            let expected = match kind {
+                // Three and five stacked fifths spell a sus2 and a 6/9 chord.
+                ChordKind::Stacked(StackedKind::Quintal3) => {
+                    ChordKind::Suspended(SuspendedKind::Sus2)
+                }
+                ChordKind::Stacked(StackedKind::Quintal5) => {
+                    ChordKind::Extended(ExtendedKind::SixNine)
+                }
+                other => other,
+            };
+            assert_eq!(chord.kind(), Some(expected), "failed for {kind:?}");
+        }
+        let sus2 = Chord12::from_intervals(semitone(0), &[0, 7, 14]);
+        assert_eq!(sus2.kind(), Some(ChordKind::Suspended(SuspendedKind::Sus2)));
+        let quartal = Chord12::from_intervals(semitone(0), &[0, 5, 10]);
+        assert_eq!(quartal.kind(), Some(ChordKind::Stacked(StackedKind::Quartal3)));
+        assert_eq!(ChordKind::Stacked(StackedKind::Quartal3).triad(), None);
+    }
+
+    #[test]
+    fn symbols_round_trip_and_accept_aliases() {
+        for kind in ChordKind::ALL {
+            assert_eq!(ChordKind::from_symbol(kind.symbol()), Some(kind));
+        }
+        assert_eq!(
+            ChordKind::from_symbol("ø7"),
+            Some(ChordKind::Seventh(SeventhKind::HalfDiminished7))
+        );
+        assert_eq!(ChordKind::from_symbol("sus"), Some(ChordKind::Suspended(SuspendedKind::Sus4)));
+        assert_eq!(ChordKind::from_symbol("sus3"), None);
+    }
 }
@@ -0,0 +1,286 @@
+//! core/music-theory/src/degree.rs
+//! Scale degrees with chromatic alterations and scale-step (diatonic) transposition.
+//!
+//! A [`ScaleDegree`] names a position in a [`Scale`] (`1` = root) plus an optional chromatic
+//! alteration in temperament steps, so `♭3` and `♯4` can be expressed against any scale. Degrees
+//! above the scale length are compound (`9` = the second, an octave up in a heptatonic scale).
+//! Scales map degrees to/from chromatic [`Interval`]s and move pitches by scale steps, keeping
+//! any alteration intact.
+//!
+//! ```
+//! use music_theory::{Pitch12, PitchClass12, Scale12, ScaleDegree};
+//! let c_major = Scale12::major(PitchClass12::from_semitones(0));
+//! // Move C4–D4–E4 up a third inside C major: E4–F4–G4.
+//! let phrase = [0, 2, 4].map(|s| Pitch12::from_semitones_and_octave(s, 4));
+//! let moved = c_major.transpose_melody(&phrase, 2).unwrap();
+//! assert_eq!(moved, [4, 5, 7].map(|s| Pitch12::from_semitones_and_octave(s, 4)));
+//!
+//! let minor_third = c_major.degree_for_interval(music_theory::Interval::new(3)).unwrap();
+//! assert_eq!(minor_third, ScaleDegree::flat(3));
+//! ```
+
+use core::fmt;
+
+use music_acoustic::Temperament;
+
+use crate::interval::Interval;
+use crate::pitch::{Pitch, PitchClass};
+use crate::scale::Scale;
+
+/// Scale degree (1-indexed) with a chromatic alteration in temperament steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScaleDegree {
+    /// 1-indexed degree; values above the scale length denote compound degrees.
+    pub degree: u8,
+    /// Chromatic alteration in temperament steps (negative = flat, positive = sharp).
+    pub alteration: i8,
+}
+
+impl ScaleDegree {
+    /// Construct a degree with an explicit alteration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degree` is zero.
+    #[must_use]
+    pub fn new(degree: u8, alteration: i8) -> Self {
+        assert!(degree > 0, "scale degrees are 1-indexed");
+        Self { degree, alteration }
+    }
+
+    /// Unaltered degree.
+    #[must_use]
+    pub fn natural(degree: u8) -> Self {
+        Self::new(degree, 0)
+    }
+
+    /// Degree lowered by one step (`♭3`).
+    #[must_use]
+    pub fn flat(degree: u8) -> Self {
+        Self::new(degree, -1)
+    }
+
+    /// Degree raised by one step (`♯4`).
+    #[must_use]
+    pub fn sharp(degree: u8) -> Self {
+        Self::new(degree, 1)
+    }
+
+    /// Whether the degree carries no alteration.
+    #[must_use]
+    pub fn is_natural(&self) -> bool {
+        self.alteration == 0
+    }
+
+    /// Move the degree by `steps` scale steps, keeping the alteration.
+    ///
+    /// Returns `None` when the result would fall below the first degree or overflow.
+    #[must_use]
+    pub fn checked_add_steps(self, steps: i32) -> Option<Self> {
+        let shifted = i32::from(self.degree) + steps;
+        let degree = u8::try_from(shifted).ok().filter(|degree| *degree > 0)?;
+        Some(Self { degree, ..self })
+    }
+
+    /// Reduce a compound degree into the first octave of a scale with `scale_len` degrees.
+    #[must_use]
+    pub fn simple(self, scale_len: usize) -> Self {
+        let Ok(len) = u8::try_from(scale_len) else {
+            return self;
+        };
+        if len == 0 {
+            return self;
+        }
+        Self { degree: self.degree.saturating_sub(1) % len + 1, ..self }
+    }
+}
+
+impl fmt::Display for ScaleDegree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = if self.alteration < 0 { "b" } else { "#" };
+        for _ in 0..self.alteration.unsigned_abs() {
+            f.write_str(symbol)?;
+        }
+        write!(f, "{}", self.degree)
+    }
+}
+
+impl<T: Temperament> Scale<T> {
+    /// Pitch-class of a (possibly altered or compound) scale degree.
+    #[must_use]
+    pub fn pitch_class_of(&self, degree: ScaleDegree) -> Option<PitchClass<T>> {
+        let index = usize::from(degree.degree.checked_sub(1)?) % self.len().max(1);
+        let natural = self.degrees.get(index)?;
+        Some(natural.transpose(Interval::new(i32::from(degree.alteration))))
+    }
+
+    /// Chromatic interval from the scale root up to the degree.
+    ///
+    /// Compound degrees add whole octaves, so `9` in a major scale is a major ninth.
+    #[must_use]
+    pub fn interval_of(&self, degree: ScaleDegree) -> Option<Interval<T>> {
+        if self.is_empty() {
+            return None;
+        }
+        let position = self.position(i32::from(degree.degree) - 1);
+        Some(Interval::new(position + i32::from(degree.alteration)))
+    }
+
+    /// Nearest scale degree (within the first octave) for any pitch-class.
+    ///
+    /// Scale members map to natural degrees. Chromatic pitch-classes are spelled as the closest
+    /// altered degree; ties prefer a lowered upper degree (`♭2`, `♭3`, `♭6`, `♭7`) except that the
+    /// raised fourth wins over a lowered fifth and the tonic is never lowered.
+    #[must_use]
+    pub fn scale_degree_of(&self, pitch_class: PitchClass<T>) -> Option<ScaleDegree> {
+        let root = self.degrees.first()?;
+        let steps = i32::from(T::STEPS_PER_OCTAVE);
+        let offset = (i32::from(pitch_class.index()) - i32::from(root.index())).rem_euclid(steps);
+        Some(self.nearest_degree(offset).simple(self.len()))
+    }
+
+    /// Scale degree reached by a non-negative chromatic interval above the root.
+    ///
+    /// Compound intervals produce compound degrees, and the result round-trips through
+    /// [`Scale::interval_of`]. Returns `None` for descending intervals or empty scales.
+    #[must_use]
+    pub fn degree_for_interval(&self, interval: Interval<T>) -> Option<ScaleDegree> {
+        if self.is_empty() || interval.steps() < 0 {
+            return None;
+        }
+        let steps = i32::from(T::STEPS_PER_OCTAVE);
+        let octaves = interval.steps() / steps;
+        let nearest = self.nearest_degree(interval.steps() % steps);
+        let len = i32::try_from(self.len()).ok()?;
+        nearest.checked_add_steps(octaves * len)
+    }
+
+    /// Transpose a pitch-class by `steps` scale steps (diatonic transposition).
+    #[must_use]
+    pub fn transpose_pitch_class(
+        &self,
+        pitch_class: PitchClass<T>,
+        steps: i32,
+    ) -> Option<PitchClass<T>> {
+        let delta = self.step_delta(pitch_class, steps)?;
+        Some(pitch_class.transpose(Interval::new(delta)))
+    }
+
+    /// Transpose a pitch by `steps` scale steps, crossing octaves as needed.
+    ///
+    /// Chromatic pitches keep their alteration relative to the nearest scale degree.
+    #[must_use]
+    pub fn transpose_pitch(&self, pitch: Pitch<T>, steps: i32) -> Option<Pitch<T>> {
+        let delta = self.step_delta(pitch.pitch_class, steps)?;
+        Some(pitch.transpose(Interval::new(delta)))
+    }
+
+    /// Transpose every pitch of a melody by `steps` scale steps.
+    #[must_use]
+    pub fn transpose_melody(&self, melody: &[Pitch<T>], steps: i32) -> Option<Vec<Pitch<T>>> {
+        melody.iter().map(|pitch| self.transpose_pitch(*pitch, steps)).collect()
+    }
+
+    /// Chromatic distance from the root to the 0-indexed (possibly negative/compound) degree.
+    fn position(&self, index: i32) -> i32 {
+        let steps = i32::from(T::STEPS_PER_OCTAVE);
+        let len = i32::try_from(self.len()).unwrap_or(i32::MAX);
+        let octave = index.div_euclid(len);
+        let simple = usize::try_from(index.rem_euclid(len)).unwrap_or_default();
+        let root = i32::from(self.degrees[0].index());
+        let degree = i32::from(self.degrees[simple].index());
+        (degree - root).rem_euclid(steps) + octave * steps
+    }
+
+    /// Closest degree for a chromatic offset (`0..STEPS_PER_OCTAVE`) above the root.
+    fn nearest_degree(&self, offset: i32) -> ScaleDegree {
+        let len = self.len();
+        let heptatonic = len == 7;
+        let mut best: Option<(i32, usize)> = None;
+        for index in 0..=len {
+            let alteration = offset - self.position(i32::try_from(index).unwrap_or(i32::MAX));
+            let candidate = (alteration, index);
+            best = match best {
+                None => Some(candidate),
+                Some((current, current_index)) => {
+                    let avoid_flat = index == 0 || index == len || (heptatonic && index == 4);
+                    let wins = alteration.abs() < current.abs()
+                        || (alteration.abs() == current.abs() && alteration < 0 && !avoid_flat);
+                    Some(if wins { candidate } else { (current, current_index) })
+                }
+            };
+        }
+        let (alteration, index) = best.unwrap_or((offset, 0));
+        ScaleDegree {
+            degree: u8::try_from(index + 1).unwrap_or(u8::MAX),
+            alteration: i8::try_from(alteration).unwrap_or_default(),
+        }
+    }
+
+    fn step_delta(&self, pitch_class: PitchClass<T>, steps: i32) -> Option<i32> {
+        let from = i32::from(self.scale_degree_of(pitch_class)?.degree) - 1;
+        Some(self.position(from + steps) - self.position(from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::Interval12;
+    use crate::pitch::{Pitch12, PitchClass12};
+    use crate::scale::Scale12;
+
+    fn c_major() -> Scale12 {
+        Scale12::major(PitchClass12::from_semitones(0))
+    }
+
+    #[test]
+    fn chromatic_degrees_prefer_common_spellings() {
+        let scale = c_major();
+        let spelled: Vec<String> = (0..12)
+            .map(|s| scale.scale_degree_of(PitchClass12::from_semitones(s)).unwrap().to_string())
+            .collect();
+        assert_eq!(spelled, ["1", "b2", "2", "b3", "3", "4", "#4", "5", "b6", "6", "b7", "7"]);
+        assert_eq!(
+            scale.pitch_class_of(ScaleDegree::flat(3)),
+            Some(PitchClass12::from_semitones(3))
+        );
+    }
+
+    #[test]
+    fn intervals_round_trip_through_degrees() {
+        for scale in [c_major(), Scale12::mixolydian(PitchClass12::from_semitones(7))] {
+            for semitones in 0..36 {
+                let interval = Interval12::from_semitones(semitones);
+                let degree = scale.degree_for_interval(interval).expect("ascending interval");
+                assert_eq!(scale.interval_of(degree), Some(interval), "{semitones} -> {degree}");
+            }
+        }
+        assert_eq!(
+            c_major().degree_for_interval(Interval12::from_semitones(14)),
+            Some(ScaleDegree::natural(9))
+        );
+        assert!(c_major().degree_for_interval(Interval12::from_semitones(-1)).is_none());
+    }
+
+    #[test]
+    fn diatonic_transposition_crosses_octaves_and_keeps_alterations() {
+        let scale = c_major();
+        let b4 = Pitch12::from_semitones_and_octave(11, 4);
+        assert_eq!(scale.transpose_pitch(b4, 2), Some(Pitch12::from_semitones_and_octave(2, 5)));
+        assert_eq!(scale.transpose_pitch(b4, -7), Some(Pitch12::from_semitones_and_octave(11, 3)));
+
+        // F#4 is #4; a step up keeps the sharp: G#4.
+        let f_sharp = Pitch12::from_semitones_and_octave(6, 4);
+        assert_eq!(
+            scale.transpose_pitch(f_sharp, 1),
+            Some(Pitch12::from_semitones_and_octave(8, 4))
+        );
+
+        let degree = ScaleDegree::sharp(4).checked_add_steps(-3).expect("stays above 1");
+        assert_eq!(degree, ScaleDegree::sharp(1));
+        assert!(ScaleDegree::natural(1).checked_add_steps(-1).is_none());
+        assert_eq!(ScaleDegree::natural(9).simple(7), ScaleDegree::natural(2));
+    }
+}
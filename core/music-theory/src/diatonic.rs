@@ -0,0 +1,219 @@
+//! core/music-theory/src/diatonic.rs
+//! Diatonic harmonization of heptatonic scales and keys.
+//!
+//! [`Scale::harmonize`] stacks every other (thirds) or every third (fourths) degree above each
+//! scale degree, producing one [`DiatonicChord`] per degree with its detected [`ChordKind`] and
+//! [`RomanNumeral`]. [`Key::diatonic_chords`] is the tertian shortcut for a key's scale.
+//!
+//! ```
+//! use music_theory::{ChordDepth, Key12, PitchClass12};
+//! let key = Key12::major(PitchClass12::from_semitones(0));
+//! let labels: Vec<String> =
+//!     key.diatonic_chords(ChordDepth::Seventh).iter().map(|c| c.roman.to_string()).collect();
+//! assert_eq!(labels, ["Imaj7", "ii7", "iii7", "IVmaj7", "V7", "vi7", "viiø7"]);
+//! ```
+
+use music_acoustic::{T12, Temperament};
+
+use crate::chord::{Chord, ChordKind};
+use crate::interval::Interval12;
+use crate::key::{Key, Key12, Mode};
+use crate::roman::RomanNumeral;
+use crate::scale::Scale;
+
+/// Number of stacked tones in a harmonized chord.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChordDepth {
+    Triad,
+    Seventh,
+    Ninth,
+}
+
+impl ChordDepth {
+    /// Tone count produced at this depth (3, 4 or 5).
+    #[must_use]
+    pub const fn tone_count(self) -> usize {
+        match self {
+            Self::Triad => 3,
+            Self::Seventh => 4,
+            Self::Ninth => 5,
+        }
+    }
+}
+
+/// Generic interval used to stack chord tones within the scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stacking {
+    /// Tertian harmony: skip one scale degree between chord tones.
+    Thirds,
+    /// Quartal harmony: skip two scale degrees between chord tones.
+    Fourths,
+}
+
+impl Stacking {
+    /// Scale-degree distance between successive chord tones.
+    #[must_use]
+    pub const fn degree_step(self) -> usize {
+        match self {
+            Self::Thirds => 2,
+            Self::Fourths => 3,
+        }
+    }
+}
+
+/// Chord built on a single scale degree during harmonization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiatonicChord<T: Temperament> {
+    /// Scale degree of the chord root (1–7).
+    pub degree: u8,
+    /// Chord tones, root first, in stacking order.
+    pub chord: Chord<T>,
+    /// Detected chord kind, if the stack matches a canonical table.
+    pub kind: Option<ChordKind>,
+    /// Roman-numeral label on the scale's first degree, read against the major scale like
+    /// every numeral in the crate (`bIII`, `bVI`, `bVII` in natural minor).
+    pub roman: RomanNumeral,
+}
+
+impl<T: Temperament> Scale<T> {
+    /// Harmonize every degree of a heptatonic scale.
+    ///
+    /// Returns `None` when the scale does not have exactly seven degrees, since generic
+    /// thirds/fourths are only defined for heptatonic collections.
+    #[must_use]
+    pub fn harmonize(
+        &self,
+        depth: ChordDepth,
+        stacking: Stacking,
+    ) -> Option<Vec<DiatonicChord<T>>> {
+        if self.len() != 7 {
+            return None;
+        }
+        let chords = (0..self.len())
+            .map(|root| {
+                let tones = (0..depth.tone_count())
+                    .map(|tone| self.degrees[(root + tone * stacking.degree_step()) % self.len()])
+                    .collect();
+                let chord = Chord::new(tones);
+                let kind = chord.kind();
+                let degree = u8::try_from(root + 1).unwrap_or(u8::MAX);
+                let roman = match self.semitones_above_first(root) {
+                    Some(semitones) => RomanNumeral::on_root(degree, kind, semitones),
+                    None => RomanNumeral::new(degree, kind),
+                };
+                DiatonicChord { degree, chord, kind, roman }
+            })
+            .collect();
+        Some(chords)
+    }
+
+    /// Whole semitones from the first degree up to `degree` (0-based), if the temperament
+    /// divides the octave into semitones and the degree lands on one.
+    fn semitones_above_first(&self, degree: usize) -> Option<i32> {
+        let per_semitone = T::STEPS_PER_OCTAVE / 12;
+        if per_semitone == 0 || T::STEPS_PER_OCTAVE % 12 != 0 {
+            return None;
+        }
+        let steps = T::STEPS_PER_OCTAVE;
+        let above = (self.degrees[degree].index() + steps - self.degrees[0].index()) % steps;
+        above.is_multiple_of(per_semitone).then(|| i32::from(above / per_semitone))
+    }
+}
+
+impl<T: Temperament> Key<T> {
+    /// Tertian chords on every degree of the key's scale at the requested depth.
+    #[must_use]
+    pub fn diatonic_chords(&self, depth: ChordDepth) -> Vec<DiatonicChord<T>> {
+        self.scale().harmonize(depth, Stacking::Thirds).unwrap_or_default()
+    }
+}
+
+impl Key12 {
+    /// Diatonic chords as used in functional harmony: in minor, the dominant (V) and
+    /// leading-tone (vii) chords take the raised seventh degree of harmonic minor.
+    #[must_use]
+    pub fn functional_chords(&self, depth: ChordDepth) -> Vec<DiatonicChord<T12>> {
+        let mut chords = self.diatonic_chords(depth);
+        if self.mode == Mode::Minor {
+            let subtonic = self.tonic.transpose(Interval12::from_semitones(-2));
+            let leading = self.tonic.transpose(Interval12::from_semitones(-1));
+            for diatonic in chords.iter_mut().filter(|c| matches!(c.degree, 5 | 7)) {
+                let raised = diatonic
+                    .chord
+                    .tones
+                    .iter()
+                    .map(|tone| if *tone == subtonic { leading } else { *tone });
+                diatonic.chord = Chord::new(raised.collect());
+                diatonic.kind = diatonic.chord.kind();
+                diatonic.roman = RomanNumeral::new(diatonic.degree, diatonic.kind);
+            }
+        }
+        chords
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chord::{ExtendedKind, SeventhKind, TriadKind};
+    use crate::key::Key12;
+    use crate::pitch::PitchClass12;
+    use crate::scale::Scale12;
+
+    fn pc(semitone: u16) -> PitchClass12 {
+        PitchClass12::from_semitones(semitone)
+    }
+
+    #[test]
+    fn d_dorian_triads() {
+        let chords =
+            Scale12::dorian(pc(2)).harmonize(ChordDepth::Triad, Stacking::Thirds).expect("7 notes");
+        let labels: Vec<String> = chords.iter().map(|c| c.roman.to_string()).collect();
+        assert_eq!(labels, ["i", "ii", "bIII", "IV", "v", "vi°", "bVII"]);
+        assert_eq!(chords[3].chord.tones, vec![pc(7), pc(11), pc(2)]);
+        assert_eq!(chords[3].kind, Some(ChordKind::Triad(TriadKind::Major)));
+    }
+
+    #[test]
+    fn a_minor_key_sevenths_and_ninths() {
+        let key = Key12::minor(pc(9));
+        let sevenths = key.diatonic_chords(ChordDepth::Seventh);
+        assert_eq!(sevenths[0].kind, Some(ChordKind::Seventh(SeventhKind::Minor7)));
+        assert_eq!(sevenths[1].roman.to_string(), "iiø7");
+
+        let ninths = key.diatonic_chords(ChordDepth::Ninth);
+        assert_eq!(ninths[2].kind, Some(ChordKind::Extended(ExtendedKind::Major9)));
+        // B half-diminished with a flat ninth has no canonical table entry.
+        assert_eq!(ninths[1].kind, None);
+        assert_eq!(ninths[1].chord.len(), 5);
+
+        let functional = key.functional_chords(ChordDepth::Seventh);
+        let labels: Vec<String> = functional.iter().map(|c| c.roman.to_string()).collect();
+        assert_eq!(labels, ["i7", "iiø7", "bIIImaj7", "iv7", "V7", "bVImaj7", "vii°7"]);
+        let leading = &functional[6].chord;
+        assert_eq!((leading.to_string(), leading.root()), ("Abdim7".to_string(), Some(pc(8))));
+        assert_eq!(leading.inversion(), Some(0));
+        assert_eq!(functional[4].chord.to_string(), "E7");
+    }
+
+    #[test]
+    fn numerals_realize_back_to_their_chords() {
+        for key in [Key12::minor(pc(9)), Key12::major(pc(2)), Key12::minor(pc(1))] {
+            let chords = key.diatonic_chords(ChordDepth::Seventh).into_iter();
+            for diatonic in chords.chain(key.functional_chords(ChordDepth::Triad)) {
+                let realized = diatonic.roman.realize(key).expect("identified kind");
+                assert_eq!(realized, diatonic.chord, "{} in {key}", diatonic.roman);
+            }
+        }
+    }
+
+    #[test]
+    fn quartal_stacks_and_non_heptatonic_scales() {
+        let major = Scale12::major(pc(0));
+        let quartal = major.harmonize(ChordDepth::Triad, Stacking::Fourths).expect("7 notes");
+        assert_eq!(quartal[0].chord.tones, vec![pc(0), pc(5), pc(11)]);
+
+        let pentatonic = Scale12::from_step_pattern(pc(0), &[2, 2, 3, 2, 3]);
+        assert!(pentatonic.harmonize(ChordDepth::Triad, Stacking::Thirds).is_none());
+    }
+}
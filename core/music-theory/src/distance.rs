@@ -0,0 +1,337 @@
+//! core/music-theory/src/distance.rs
+//! Harmonic distance: chord-to-chord metrics and progression alignment.
+//!
+//! Chord metrics:
+//! - [`Chord::common_tones`]: shared pitch classes.
+//! - [`Chord::voice_leading_distance`]: smallest total step motion taking one pitch-class set
+//!   to the other, doubling tones of the smaller chord as needed.
+//! - [`Chord12::tonal_pitch_space_distance`]: Lerdahl's chord distance `δ = i + j + k`
+//!   (regional fifths, chordal fifths, and distinctive tones of the basic space).
+//!
+//! [`ProgressionAlignment`] aligns two progressions by edit distance, either over Roman
+//! numerals or over chord symbols in the best transposition, and reports a `0..=1` similarity.
+//!
+//! ```
+//! use music_theory::{Chord12, Key12, PitchClass12, ProgressionAlignment};
+//! let pc = PitchClass12::from_semitones;
+//! let key = Key12::major(pc(0));
+//! let tonic = Chord12::major_triad(pc(0));
+//! let dominant = Chord12::major_triad(pc(7));
+//! assert_eq!(tonic.tonal_pitch_space_distance(key, &dominant, key), 5);
+//! assert_eq!(tonic.voice_leading_distance(&Chord12::minor_triad(pc(9))), Some(2));
+//!
+//! let parse = |symbols: &[&str]| -> Vec<Chord12> {
+//!     symbols.iter().map(|s| s.parse().unwrap()).collect()
+//! };
+//! let a = parse(&["C", "Am", "F", "G"]);
+//! let b = parse(&["D", "Bm", "G", "A"]);
+//! let alignment = ProgressionAlignment::chords(&a, &b);
+//! assert_eq!((alignment.cost, alignment.transposition), (0.0, 10));
+//! ```
+
+use music_acoustic::Temperament;
+
+use crate::chord::{Chord, Chord12};
+use crate::interval::Interval12;
+use crate::key::{Key12, Mode};
+use crate::pitch::{PitchClass, PitchClass12};
+use crate::roman::RomanNumeral;
+
+/// Aligned index pairs; `None` on either side marks an insertion or deletion.
+pub type AlignedPairs = Vec<(Option<usize>, Option<usize>)>;
+
+/// Edit cost of inserting or deleting one chord.
+const GAP_COST: f64 = 1.0;
+/// Substitution cost when roots agree but qualities differ.
+const QUALITY_COST: f64 = 0.5;
+
+impl<T: Temperament> Chord<T> {
+    /// Number of pitch classes both chords contain.
+    #[must_use]
+    pub fn common_tones(&self, other: &Self) -> usize {
+        let mut shared: Vec<PitchClass<T>> = Vec::new();
+        for tone in &self.tones {
+            if other.contains(*tone) && !shared.contains(tone) {
+                shared.push(*tone);
+            }
+        }
+        shared.len()
+    }
+
+    /// Minimal total motion (in steps, each voice by its shortest path) between the two
+    /// pitch-class sets.
+    ///
+    /// Duplicate tones and a slash bass outside the chord count once each. Every tone of the
+    /// larger set moves to or from some tone of the smaller one and every tone of the smaller
+    /// set is used, so `C → G7` doubles a C-major tone. Empty chords are at distance `0`;
+    /// returns `None` when the smaller set has more than 16 pitch classes (the search is
+    /// exponential in its size).
+    #[must_use]
+    pub fn voice_leading_distance(&self, other: &Self) -> Option<u32> {
+        let (ours, theirs) = (self.pitch_class_set(), other.pitch_class_set());
+        let (small, large) =
+            if ours.len() <= theirs.len() { (ours, theirs) } else { (theirs, ours) };
+        if small.is_empty() {
+            return Some(0);
+        }
+        if small.len() > 16 {
+            return None;
+        }
+        let steps = u32::from(T::STEPS_PER_OCTAVE);
+        let motion = |a: PitchClass<T>, b: PitchClass<T>| {
+            let up = (u32::from(b.index()) + steps - u32::from(a.index())) % steps;
+            up.min(steps - up)
+        };
+        // Cheapest cost of mapping the large tones seen so far onto each subset of small tones.
+        let full = (1_usize << small.len()) - 1;
+        let mut costs = vec![u32::MAX; full + 1];
+        costs[0] = 0;
+        for tone in &large {
+            let mut next = vec![u32::MAX; full + 1];
+            for (mask, cost) in costs.iter().enumerate().filter(|(_, cost)| **cost != u32::MAX) {
+                for (index, target) in small.iter().enumerate() {
+                    let reached = mask | (1 << index);
+                    next[reached] = next[reached].min(cost + motion(*tone, *target));
+                }
+            }
+            costs = next;
+        }
+        Some(costs[full])
+    }
+}
+
+impl Chord12 {
+    /// Lerdahl's tonal pitch space distance from `self` in `key` to `other` in `other_key`.
+    ///
+    /// `i` counts fifths between the keys' diatonic collections (relative keys share one);
+    /// `j` counts fifths between the roots on the destination key's diatonic circle (or the
+    /// chromatic circle when a root is foreign to it); `k` counts pitch classes new at each
+    /// level of the destination's basic space (root, fifth, chord, diatonic).
+    #[must_use]
+    pub fn tonal_pitch_space_distance(&self, key: Key12, other: &Self, other_key: Key12) -> u32 {
+        let regional = circle_distance(collection_center(key), collection_center(other_key), 12);
+        let chordal = match (self.root(), other.root()) {
+            (Some(from), Some(to)) => {
+                let scale = other_key.scale12();
+                let diatonic = |pc: PitchClass12| {
+                    scale
+                        .contains(pc)
+                        .then(|| (fifths(pc) + 13 - collection_center(other_key)) % 12)
+                };
+                match (diatonic(from), diatonic(to)) {
+                    (Some(from), Some(to)) => circle_distance(from, to, 7),
+                    _ => circle_distance(fifths(from), fifths(to), 12),
+                }
+            }
+            _ => 0,
+        };
+        let source = basic_space(self, key);
+        let target = basic_space(other, other_key);
+        let distinctive = target
+            .iter()
+            .zip(&source)
+            .map(|(level, previous)| level.iter().filter(|pc| !previous.contains(pc)).count())
+            .sum::<usize>();
+        regional + chordal + u32::try_from(distinctive).unwrap_or(u32::MAX)
+    }
+}
+
+/// Position of a pitch class on the circle of fifths (C = 0, G = 1, ...).
+fn fifths(pitch_class: PitchClass12) -> u32 {
+    u32::from(pitch_class.to_semitones()) * 7 % 12
+}
+
+/// Circle-of-fifths position of the key's relative-major tonic.
+fn collection_center(key: Key12) -> u32 {
+    let offset = match key.mode {
+        Mode::Major => 0,
+        Mode::Minor => 3,
+    };
+    (u32::from(key.tonic.to_semitones()) + offset) * 7 % 12
+}
+
+fn circle_distance(a: u32, b: u32, size: u32) -> u32 {
+    let forward = (a + size - b % size) % size;
+    forward.min(size - forward)
+}
+
+/// Root, fifth, chord, and diatonic levels of Lerdahl's basic space.
+fn basic_space(chord: &Chord12, key: Key12) -> [Vec<PitchClass12>; 4] {
+    let root: Vec<PitchClass12> = chord.root().into_iter().collect();
+    let fifth = root
+        .iter()
+        .map(|root| root.transpose(Interval12::from_semitones(7)))
+        .filter(|fifth| chord.contains(*fifth));
+    let fifth_level = root.iter().copied().chain(fifth).collect();
+    [root, fifth_level, chord.tones.clone(), key.scale12().degrees]
+}
+
+/// Edit-distance alignment between two progressions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressionAlignment {
+    /// Aligned index pairs in order; `None` marks an insertion or deletion.
+    pub pairs: AlignedPairs,
+    /// Total edit cost (gap `1`, quality change `0.5`, root change `1`).
+    pub cost: f64,
+    /// Semitones added to the second progression to line it up with the first.
+    pub transposition: u16,
+    longest: usize,
+}
+
+impl ProgressionAlignment {
+    /// Align Roman-numeral progressions (already key-relative, so `transposition` is `0`).
+    #[must_use]
+    pub fn roman(a: &[RomanNumeral], b: &[RomanNumeral]) -> Self {
+        let substitution = |x: &RomanNumeral, y: &RomanNumeral| {
+            if x == y {
+                0.0
+            } else if (x.degree, x.alteration) == (y.degree, y.alteration) {
+                QUALITY_COST
+            } else {
+                GAP_COST
+            }
+        };
+        let (cost, pairs) = align(a.len(), b.len(), |i, j| substitution(&a[i], &b[j]));
+        Self { pairs, cost, transposition: 0, longest: a.len().max(b.len()) }
+    }
+
+    /// Align chord sequences in whichever transposition of `b` is cheapest (ties favor the
+    /// smallest shift).
+    #[must_use]
+    pub fn chords(a: &[Chord12], b: &[Chord12]) -> Self {
+        let substitution = |x: &Chord12, y: &Chord12, shift: u16| {
+            let root =
+                y.root().map(|root| PitchClass12::from_semitones(root.to_semitones() + shift));
+            if x.root() != root {
+                GAP_COST
+            } else if x.kind() == y.kind() {
+                0.0
+            } else {
+                QUALITY_COST
+            }
+        };
+        let longest = a.len().max(b.len());
+        (0..12)
+            .map(|shift| {
+                let (cost, pairs) =
+                    align(a.len(), b.len(), |i, j| substitution(&a[i], &b[j], shift));
+                Self { pairs, cost, transposition: shift, longest }
+            })
+            .fold(None, |best: Option<Self>, candidate| match best {
+                Some(best) if best.cost <= candidate.cost => Some(best),
+                _ => Some(candidate),
+            })
+            .unwrap_or(Self { pairs: Vec::new(), cost: 0.0, transposition: 0, longest })
+    }
+
+    /// `1` for identical progressions, falling toward `0` as edits approach the longer length.
+    #[must_use]
+    pub fn similarity(&self) -> f64 {
+        if self.longest == 0 {
+            return 1.0;
+        }
+        (1.0 - self.cost / self.longest as f64).max(0.0)
+    }
+}
+
+/// Needleman–Wunsch global alignment with unit gaps.
+fn align(
+    rows: usize,
+    columns: usize,
+    substitution: impl Fn(usize, usize) -> f64,
+) -> (f64, AlignedPairs) {
+    let width = columns + 1;
+    let mut table = vec![0.0; (rows + 1) * width];
+    for i in 0..=rows {
+        for j in 0..=columns {
+            table[i * width + j] = match (i, j) {
+                (0, _) => j as f64 * GAP_COST,
+                (_, 0) => i as f64 * GAP_COST,
+                _ => (table[(i - 1) * width + j - 1] + substitution(i - 1, j - 1))
+                    .min(table[(i - 1) * width + j] + GAP_COST)
+                    .min(table[i * width + j - 1] + GAP_COST),
+            };
+        }
+    }
+    let mut pairs = Vec::with_capacity(rows.max(columns));
+    let (mut i, mut j) = (rows, columns);
+    while i > 0 || j > 0 {
+        let here = table[i * width + j];
+        if i > 0 && j > 0 && here == table[(i - 1) * width + j - 1] + substitution(i - 1, j - 1) {
+            pairs.push((Some(i - 1), Some(j - 1)));
+            (i, j) = (i - 1, j - 1);
+        } else if i > 0 && (j == 0 || here == table[(i - 1) * width + j] + GAP_COST) {
+            pairs.push((Some(i - 1), None));
+            i -= 1;
+        } else {
+            pairs.push((None, Some(j - 1)));
+            j -= 1;
+        }
+    }
+    pairs.reverse();
+    (table[rows * width + columns], pairs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pc(semitones: u16) -> PitchClass12 {
+        PitchClass12::from_semitones(semitones)
+    }
+
+    fn parse(symbols: &[&str]) -> Vec<Chord12> {
+        symbols.iter().filter_map(|symbol| symbol.parse().ok()).collect()
+    }
+
+    #[test]
+    fn chord_metrics_match_textbook_values() {
+        let c_major = Key12::major(pc(0));
+        let tonic = Chord12::major_triad(pc(0));
+        let tps =
+            |chord: Chord12, key: Key12| tonic.tonal_pitch_space_distance(c_major, &chord, key);
+        assert_eq!(tps(Chord12::minor_triad(pc(2)), c_major), 8);
+        assert_eq!(tps(Chord12::minor_triad(pc(9)), c_major), 7);
+        assert_eq!(tps(Chord12::major_triad(pc(7)), Key12::major(pc(7))), 7);
+        assert_eq!(tps(tonic.clone(), Key12::minor(pc(9))), 0);
+        assert_eq!(tonic.common_tones(&Chord12::minor_triad(pc(9))), 2);
+        assert_eq!(tonic.voice_leading_distance(&Chord12::dominant_seventh(pc(7))), Some(4));
+        assert_eq!(tonic.voice_leading_distance(&tonic), Some(0));
+        let doubled = Chord12::new(vec![pc(0), pc(4), pc(7), pc(0), pc(7)]);
+        assert_eq!(doubled.voice_leading_distance(&Chord12::minor_triad(pc(9))), Some(2));
+        let cluster = |count: u16| Chord12::new((0..count).map(|i| pc(i % 12)).collect());
+        assert_eq!(cluster(20).voice_leading_distance(&cluster(24)), Some(0));
+        let quarter_tones = |count: u16| {
+            Chord::new((0..count).map(PitchClass::<music_acoustic::T24>::new).collect())
+        };
+        assert_eq!(quarter_tones(16).voice_leading_distance(&quarter_tones(17)), Some(1));
+        assert_eq!(quarter_tones(17).voice_leading_distance(&quarter_tones(18)), None);
+    }
+
+    #[test]
+    fn chord_alignment_is_transposition_invariant() {
+        let a = parse(&["Dm7", "G7", "Cmaj7"]);
+        let b = parse(&["Fm7", "Bb7", "Ebmaj7"]);
+        let alignment = ProgressionAlignment::chords(&a, &b);
+        assert_eq!((alignment.cost, alignment.transposition), (0.0, 9));
+        assert_eq!(alignment.similarity(), 1.0);
+        let padded = parse(&["Am7", "Fm7", "Bb7", "Eb"]);
+        let loose = ProgressionAlignment::chords(&a, &padded);
+        assert_eq!(loose.cost, 1.5);
+        assert_eq!(loose.pairs[0], (None, Some(0)));
+        assert_eq!(loose.similarity(), 1.0 - 1.5 / 4.0);
+    }
+
+    #[test]
+    fn roman_edit_distance_weighs_quality_changes() {
+        let numerals = |text: &str| -> Vec<RomanNumeral> {
+            text.split_whitespace().filter_map(|numeral| numeral.parse().ok()).collect()
+        };
+        let plain = ProgressionAlignment::roman(&numerals("ii7 V7 Imaj7"), &numerals("ii V I"));
+        assert_eq!(plain.cost, 1.5);
+        let substituted =
+            ProgressionAlignment::roman(&numerals("ii7 V7 I"), &numerals("ii7 bII7 I"));
+        assert_eq!(substituted.cost, 1.0);
+        assert_eq!(ProgressionAlignment::roman(&[], &[]).similarity(), 1.0);
+    }
+}
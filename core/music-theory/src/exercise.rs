@@ -0,0 +1,595 @@
+//! core/music-theory/src/exercise.rs
+//! Seeded ear-training exercises with answer keys, JSON encoding, and MIDI rendering.
+//!
+//! [`ExerciseGenerator`] draws interval, chord-quality, scale/mode, progression, and melodic
+//! dictation items from a deterministic SplitMix64 stream, so the same seed and
+//! [`Difficulty`] always reproduce the same [`ExerciseSet`]. Every [`Exercise`] carries the
+//! notes to play (on the `music_time` beat axis), its answer, and multiple-choice options where
+//! the answer comes from a closed vocabulary. With the `serde` feature exercises serialize for
+//! teaching apps; [`Exercise::to_midi`] and [`ExerciseSet::to_midi`] write Standard MIDI Files.
+//!
+//! ```
+//! use music_theory::{Difficulty, ExerciseKind, ExerciseSet};
+//! let kinds = ExerciseKind::ALL;
+//! let set = ExerciseSet::generate(7, Difficulty::Beginner, &kinds, 10);
+//! assert_eq!(set, ExerciseSet::generate(7, Difficulty::Beginner, &kinds, 10));
+//! assert_eq!(set.exercises.len(), 10);
+//! let interval = &set.exercises[0];
+//! assert_eq!(interval.kind, ExerciseKind::Interval);
+//! assert!(interval.choices.contains(&interval.answer));
+//! assert_eq!(&set.to_midi(90.0)[..4], b"MThd");
+//! ```
+
+use music_time::{TimePoint, TimeSpan};
+
+use crate::chord::{Chord12, ChordKind, SeventhKind, TriadKind};
+use crate::interval::Interval12;
+use crate::key::{Key12, Mode};
+use crate::melody::MelodyNote;
+use crate::modal::ScaleFamily;
+use crate::pitch::{Pitch12, PitchClass12};
+use crate::progression::Progression;
+use crate::roman::RomanNumeral;
+
+/// Ticks per quarter note in rendered MIDI files.
+pub const MIDI_TICKS_PER_BEAT: u16 = 480;
+
+/// Exercise categories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ExerciseKind {
+    Interval,
+    ChordQuality,
+    /// Scale or mode recognition.
+    Scale,
+    /// Roman-numeral dictation of a chord progression.
+    Progression,
+    /// Melodic dictation within a key.
+    Melody,
+}
+
+impl ExerciseKind {
+    pub const ALL: [Self; 5] =
+        [Self::Interval, Self::ChordQuality, Self::Scale, Self::Progression, Self::Melody];
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Interval => "interval",
+            Self::ChordQuality => "chord quality",
+            Self::Scale => "scale",
+            Self::Progression => "progression",
+            Self::Melody => "melody",
+        }
+    }
+}
+
+/// Difficulty levels, easiest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Difficulty {
+    Beginner,
+    Intermediate,
+    Advanced,
+}
+
+impl Difficulty {
+    pub const ALL: [Self; 3] = [Self::Beginner, Self::Intermediate, Self::Advanced];
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Beginner => "beginner",
+            Self::Intermediate => "intermediate",
+            Self::Advanced => "advanced",
+        }
+    }
+}
+
+/// One ear-training item.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Exercise {
+    pub kind: ExerciseKind,
+    pub difficulty: Difficulty,
+    /// Instruction shown to the student.
+    pub prompt: String,
+    /// Notes to play, in beats from the start of the exercise.
+    pub notes: Vec<MelodyNote>,
+    /// Answer key (`"M3"`, `"minor seventh"`, `"I vi IV V"`, `"C4 D4 E4"`).
+    pub answer: String,
+    /// Multiple-choice options including the answer; empty for dictation items.
+    pub choices: Vec<String>,
+}
+
+impl Exercise {
+    /// Beat at which the last note stops sounding.
+    #[must_use]
+    pub fn length_beats(&self) -> f64 {
+        self.notes.iter().map(|note| note.end().as_beats()).fold(0.0, f64::max)
+    }
+
+    /// Standard MIDI File (format 0, piano on channel 1) playing the exercise at `bpm`.
+    #[must_use]
+    pub fn to_midi(&self, bpm: f64) -> Vec<u8> {
+        midi_file(&self.notes, bpm)
+    }
+}
+
+/// A reproducible batch of exercises.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExerciseSet {
+    pub seed: u64,
+    pub difficulty: Difficulty,
+    pub exercises: Vec<Exercise>,
+}
+
+impl ExerciseSet {
+    /// Rest between exercises when a whole set is rendered, in beats.
+    pub const GAP_BEATS: f64 = 4.0;
+
+    /// `count` exercises cycling through `kinds` in order.
+    #[must_use]
+    pub fn generate(
+        seed: u64,
+        difficulty: Difficulty,
+        kinds: &[ExerciseKind],
+        count: usize,
+    ) -> Self {
+        let mut generator = ExerciseGenerator::new(seed, difficulty);
+        let exercises = kinds.iter().cycle().take(count).map(|kind| generator.generate(*kind));
+        Self { seed, difficulty, exercises: exercises.collect() }
+    }
+
+    /// Every exercise back to back, separated by [`ExerciseSet::GAP_BEATS`] of rest.
+    #[must_use]
+    pub fn to_midi(&self, bpm: f64) -> Vec<u8> {
+        let mut notes = Vec::new();
+        let mut offset = 0.0;
+        for exercise in &self.exercises {
+            notes.extend(exercise.notes.iter().map(|note| MelodyNote {
+                onset: TimePoint::new(note.onset.as_beats() + offset),
+                ..*note
+            }));
+            offset += exercise.length_beats() + Self::GAP_BEATS;
+        }
+        midi_file(&notes, bpm)
+    }
+}
+
+/// Deterministic exercise source.
+#[derive(Debug, Clone)]
+pub struct ExerciseGenerator {
+    pub difficulty: Difficulty,
+    rng: SplitMix64,
+}
+
+impl ExerciseGenerator {
+    #[must_use]
+    pub fn new(seed: u64, difficulty: Difficulty) -> Self {
+        Self { difficulty, rng: SplitMix64(seed) }
+    }
+
+    /// Next exercise of the given kind.
+    pub fn generate(&mut self, kind: ExerciseKind) -> Exercise {
+        match kind {
+            ExerciseKind::Interval => self.interval(),
+            ExerciseKind::ChordQuality => self.chord_quality(),
+            ExerciseKind::Scale => self.scale(),
+            ExerciseKind::Progression => self.progression(),
+            ExerciseKind::Melody => self.melody(),
+        }
+    }
+
+    /// Identify a melodic (or, when advanced, harmonic) interval.
+    ///
+    /// Beginners hear ascending M2, M3, P4, P5, and P8; intermediate items add every simple
+    /// interval in both directions; advanced items reach two octaves.
+    pub fn interval(&mut self) -> Exercise {
+        let pool: Vec<i32> = match self.difficulty {
+            Difficulty::Beginner => vec![2, 4, 5, 7, 12],
+            Difficulty::Intermediate => (1..=12).collect(),
+            Difficulty::Advanced => (1..=24).collect(),
+        };
+        let semitones = *self.rng.pick(&pool);
+        let descending = self.difficulty > Difficulty::Beginner && self.rng.chance();
+        let harmonic = self.difficulty == Difficulty::Advanced && self.rng.chance();
+        let start = self.pitch_in_octave(if descending { 5 } else { 4 });
+        let second = start.transpose(Interval12::from_semitones(if descending {
+            -semitones
+        } else {
+            semitones
+        }));
+        let notes = if harmonic {
+            vec![note(start, 0.0, 2.0), note(second, 0.0, 2.0)]
+        } else {
+            vec![note(start, 0.0, 1.0), note(second, 1.0, 1.0)]
+        };
+        let manner = match (harmonic, descending) {
+            (true, _) => "harmonic",
+            (false, true) => "descending melodic",
+            (false, false) => "ascending melodic",
+        };
+        self.exercise(
+            ExerciseKind::Interval,
+            format!("Identify the {manner} interval."),
+            notes,
+            Interval12::from_semitones(semitones).to_string(),
+            pool.iter().map(|s| Interval12::from_semitones(*s).to_string()).collect(),
+        )
+    }
+
+    /// Name the quality of a block chord (advanced items may be inverted).
+    pub fn chord_quality(&mut self) -> Exercise {
+        let pool: &[ChordKind] = match self.difficulty {
+            Difficulty::Beginner => &ChordKind::ALL[..2],
+            Difficulty::Intermediate => &ChordKind::ALL[..7],
+            Difficulty::Advanced => &ChordKind::ALL[..10],
+        };
+        let kind = *self.rng.pick(pool);
+        let mut chord = Chord12::from_kind(self.pitch_class(), kind);
+        if self.difficulty == Difficulty::Advanced {
+            chord = chord.invert(self.rng.below(kind.intervals().len()));
+        }
+        let notes = chord.close_voicing(4).into_iter().map(|p| note(p, 0.0, 2.0)).collect();
+        let inversions =
+            if self.difficulty == Difficulty::Advanced { " in any inversion" } else { "" };
+        self.exercise(
+            ExerciseKind::ChordQuality,
+            format!("Name the chord quality{inversions}."),
+            notes,
+            quality_name(kind).to_string(),
+            pool.iter().map(|kind| quality_name(*kind).to_string()).collect(),
+        )
+    }
+
+    /// Recognize a scale or mode played up (and, when advanced, back down) from its tonic.
+    pub fn scale(&mut self) -> Exercise {
+        let pool: Vec<(ScaleFamily, usize)> = match self.difficulty {
+            Difficulty::Beginner => vec![(ScaleFamily::Diatonic, 1), (ScaleFamily::Diatonic, 6)],
+            Difficulty::Intermediate => (1..=7)
+                .map(|mode| (ScaleFamily::Diatonic, mode))
+                .chain([(ScaleFamily::HarmonicMinor, 1), (ScaleFamily::MelodicMinor, 1)])
+                .collect(),
+            Difficulty::Advanced => ScaleFamily::ALL
+                .into_iter()
+                .flat_map(|family| (1..=7).map(move |mode| (family, mode)))
+                .collect(),
+        };
+        let (family, mode) = *self.rng.pick(&pool);
+        let pattern = family.step_pattern();
+        let mut pitch = self.pitch_in_octave(4);
+        let mut line = vec![pitch];
+        for step in (0..7).map(|index| pattern[(mode - 1 + index) % 7]) {
+            pitch = pitch.transpose(Interval12::from_semitones(step));
+            line.push(pitch);
+        }
+        if self.difficulty == Difficulty::Advanced {
+            let descent: Vec<Pitch12> = line.iter().rev().skip(1).copied().collect();
+            line.extend(descent);
+        }
+        let notes = line.iter().enumerate().map(|(i, p)| note(*p, beat(i), 1.0)).collect();
+        self.exercise(
+            ExerciseKind::Scale,
+            "Name the scale or mode.".to_string(),
+            notes,
+            scale_name(family, mode).to_string(),
+            pool.iter().map(|(family, mode)| scale_name(*family, *mode).to_string()).collect(),
+        )
+    }
+
+    /// Write the Roman numerals of a progression, two beats per chord with the root in the bass.
+    ///
+    /// Beginners hear four major-key triads ending V–I; intermediate items run six chords in
+    /// major or minor with sevenths; advanced items are catalog progressions of up to eight
+    /// chords (see [`Progression::CATALOG`]).
+    pub fn progression(&mut self) -> Exercise {
+        let (key, numerals) = match self.difficulty {
+            Difficulty::Advanced => {
+                let short: Vec<&Progression> = Progression::CATALOG
+                    .iter()
+                    .filter(|p| p.numerals.split_whitespace().count() <= 8)
+                    .collect();
+                let progression = *self.rng.pick(&short);
+                (
+                    Key12::new(self.pitch_class(), progression.mode),
+                    progression.roman_numerals().unwrap_or_default(),
+                )
+            }
+            difficulty => {
+                let minor = difficulty == Difficulty::Intermediate && self.rng.chance();
+                let (tonic, pool, dominant): (&str, &[&str], &[&str]) = match (difficulty, minor) {
+                    (Difficulty::Beginner, _) => ("I", &["IV", "vi", "ii"], &["V"]),
+                    (_, false) => ("I", &["ii", "iii", "IV", "vi", "ii7"], &["V", "V7"]),
+                    (_, true) => ("i", &["iv", "bVI", "bIII", "iiø7", "bVII"], &["V", "V7"]),
+                };
+                let length = if difficulty == Difficulty::Beginner { 4 } else { 6 };
+                let mut symbols = vec![tonic];
+                while symbols.len() < length - 2 {
+                    let next = *self.rng.pick(pool);
+                    if symbols.last() != Some(&next) {
+                        symbols.push(next);
+                    }
+                }
+                symbols.push(*self.rng.pick(dominant));
+                symbols.push(tonic);
+                let mode = if minor { Mode::Minor } else { Mode::Major };
+                let numerals = symbols.iter().filter_map(|symbol| symbol.parse().ok()).collect();
+                (Key12::new(self.pitch_class(), mode), numerals)
+            }
+        };
+        let notes = numerals
+            .iter()
+            .filter_map(|numeral| numeral.realize(key))
+            .enumerate()
+            .flat_map(|(index, chord)| {
+                let bass = chord.root().map(|root| Pitch12::new(root, 3));
+                bass.into_iter()
+                    .chain(chord.close_voicing(4))
+                    .map(move |pitch| note(pitch, beat(2 * index), 2.0))
+            })
+            .collect();
+        let answer: Vec<String> = numerals.iter().map(RomanNumeral::to_string).collect();
+        self.exercise(
+            ExerciseKind::Progression,
+            format!("Write the Roman numerals ({key}, {} chords).", numerals.len()),
+            notes,
+            answer.join(" "),
+            Vec::new(),
+        )
+    }
+
+    /// Notate a short melody within a key, given its first note.
+    ///
+    /// Beginners get four stepwise quarter notes within the first five degrees; intermediate
+    /// items six notes with skips across an octave; advanced items eight notes with leaps up to
+    /// a fifth, mixed rhythms, and minor keys.
+    pub fn melody(&mut self) -> Exercise {
+        let (length, moves, lowest, highest): (usize, &[i32], i32, i32) = match self.difficulty {
+            Difficulty::Beginner => (4, &[-1, 1], 0, 4),
+            Difficulty::Intermediate => (6, &[-2, -1, 1, 2], 0, 7),
+            Difficulty::Advanced => (8, &[-4, -3, -2, -1, 1, 2, 3, 4], -3, 9),
+        };
+        let minor = self.difficulty == Difficulty::Advanced && self.rng.chance();
+        let key = Key12::new(self.pitch_class(), if minor { Mode::Minor } else { Mode::Major });
+        let scale = key.scale12();
+        let tonic = Pitch12::new(key.tonic, 4).absolute_steps();
+        let pitch_of = |degree: i32| {
+            let class = scale.degrees[usize::try_from(degree.rem_euclid(7)).unwrap_or_default()];
+            let above = (i32::from(class.to_semitones()) - i32::from(key.tonic.to_semitones()))
+                .rem_euclid(12);
+            Pitch12::from_absolute_steps(tonic + above + 12 * degree.div_euclid(7))
+        };
+        let mut degree = 0;
+        let mut onset = 0.0;
+        let mut notes = Vec::with_capacity(length);
+        for index in 0..length {
+            if index > 0 {
+                let candidates: Vec<i32> = moves
+                    .iter()
+                    .map(|step| degree + step)
+                    .filter(|next| (lowest..=highest).contains(next))
+                    .collect();
+                degree = *self.rng.pick(&candidates);
+            }
+            let duration = match self.difficulty {
+                Difficulty::Advanced => *self.rng.pick(&[0.5, 1.0, 1.0, 1.5, 2.0]),
+                _ => 1.0,
+            };
+            notes.push(note(pitch_of(degree), onset, duration));
+            onset += duration;
+        }
+        let answer: Vec<String> = notes.iter().map(|n| n.pitch.to_string()).collect();
+        self.exercise(
+            ExerciseKind::Melody,
+            format!("Notate the melody in {key}; it starts on {}.", answer[0]),
+            notes,
+            answer.join(" "),
+            Vec::new(),
+        )
+    }
+
+    fn exercise(
+        &self,
+        kind: ExerciseKind,
+        prompt: String,
+        notes: Vec<MelodyNote>,
+        answer: String,
+        mut choices: Vec<String>,
+    ) -> Exercise {
+        let mut seen = Vec::with_capacity(choices.len());
+        choices.retain(|choice| {
+            let fresh = !seen.contains(choice);
+            seen.push(choice.clone());
+            fresh
+        });
+        Exercise { kind, difficulty: self.difficulty, prompt, notes, answer, choices }
+    }
+
+    fn pitch_class(&mut self) -> PitchClass12 {
+        PitchClass12::from_semitones(u16::try_from(self.rng.below(12)).unwrap_or_default())
+    }
+
+    fn pitch_in_octave(&mut self, octave: i16) -> Pitch12 {
+        Pitch12::new(self.pitch_class(), octave)
+    }
+}
+
+fn note(pitch: Pitch12, onset: f64, duration: f64) -> MelodyNote {
+    MelodyNote::new(pitch, TimePoint::new(onset), TimeSpan::new(duration))
+}
+
+#[allow(clippy::cast_precision_loss)] // Exercise indices are tiny.
+fn beat(index: usize) -> f64 {
+    index as f64
+}
+
+/// Descriptive quality names for triads and seventh chords.
+fn quality_name(kind: ChordKind) -> &'static str {
+    match kind {
+        ChordKind::Triad(TriadKind::Major) => "major",
+        ChordKind::Triad(TriadKind::Minor) => "minor",
+        ChordKind::Triad(TriadKind::Diminished) => "diminished",
+        ChordKind::Triad(TriadKind::Augmented) => "augmented",
+        ChordKind::Seventh(SeventhKind::Major7) => "major seventh",
+        ChordKind::Seventh(SeventhKind::Dominant7) => "dominant seventh",
+        ChordKind::Seventh(SeventhKind::Minor7) => "minor seventh",
+        ChordKind::Seventh(SeventhKind::HalfDiminished7) => "half-diminished seventh",
+        ChordKind::Seventh(SeventhKind::Diminished7) => "diminished seventh",
+        ChordKind::Seventh(SeventhKind::MinorMajor7) => "minor-major seventh",
+        ChordKind::Extended(_) | ChordKind::Suspended(_) | ChordKind::Stacked(_) => kind.symbol(),
+    }
+}
+
+/// Mode names, using the familiar names for the parent scales students meet first.
+fn scale_name(family: ScaleFamily, mode: usize) -> &'static str {
+    match (family, mode) {
+        (ScaleFamily::Diatonic, 1) => "major",
+        (ScaleFamily::Diatonic, 6) => "natural minor",
+        _ => family.mode_names()[(mode - 1) % 7],
+    }
+}
+
+/// SplitMix64 (Steele, Lea & Flood): tiny, fast, and stable across platforms and releases.
+#[derive(Debug, Clone)]
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform-enough index in `0..bound` (`0` when `bound` is zero).
+    fn below(&mut self, bound: usize) -> usize {
+        let bound = u64::try_from(bound).unwrap_or(u64::MAX).max(1);
+        usize::try_from(self.next() % bound).unwrap_or_default()
+    }
+
+    fn chance(&mut self) -> bool {
+        self.next() >> 63 == 1
+    }
+
+    /// Random element of a non-empty slice.
+    fn pick<'a, V>(&mut self, items: &'a [V]) -> &'a V {
+        &items[self.below(items.len())]
+    }
+}
+
+/// Format-0 Standard MIDI File: tempo meta event, then note on/off pairs on channel 1.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // Clamped before casting.
+fn midi_file(notes: &[MelodyNote], bpm: f64) -> Vec<u8> {
+    let ticks = |beats: f64| (beats.max(0.0) * f64::from(MIDI_TICKS_PER_BEAT)).round() as u32;
+    let mut events: Vec<(u32, u8, u8)> = Vec::with_capacity(notes.len() * 2);
+    for melody_note in notes {
+        let key = (melody_note.pitch.absolute_steps() + 12).clamp(0, 127) as u8;
+        let start = ticks(melody_note.onset.as_beats());
+        events.push((start, 0x90, key));
+        events.push((start.max(ticks(melody_note.end().as_beats())), 0x80, key));
+    }
+    // Note-offs sort before note-ons on the same tick so repeated keys retrigger.
+    events.sort_by_key(|(tick, status, key)| (*tick, *status, *key));
+
+    let tempo = (60_000_000.0 / bpm.max(1.0)).round().min(f64::from(0x00FF_FFFF)) as u32;
+    let mut track = vec![0x00, 0xFF, 0x51, 0x03];
+    track.extend_from_slice(&tempo.to_be_bytes()[1..]);
+    let mut now = 0;
+    for (tick, status, key) in events {
+        push_variable_length(&mut track, tick - now);
+        let velocity = if status == 0x90 { 80 } else { 0 };
+        track.extend_from_slice(&[status, key, velocity]);
+        now = tick;
+    }
+    track.extend_from_slice(&[0x00, 0xFF, 0x2F, 0x00]);
+
+    let mut file = b"MThd".to_vec();
+    file.extend_from_slice(&6_u32.to_be_bytes());
+    file.extend_from_slice(&[0, 0, 0, 1]);
+    file.extend_from_slice(&MIDI_TICKS_PER_BEAT.to_be_bytes());
+    file.extend_from_slice(b"MTrk");
+    file.extend_from_slice(&u32::try_from(track.len()).unwrap_or(u32::MAX).to_be_bytes());
+    file.extend(track);
+    file
+}
+
+/// MIDI variable-length quantity: 7 bits per byte, high bit set on all but the last.
+fn push_variable_length(buffer: &mut Vec<u8>, mut value: u32) {
+    let mut bytes = vec![(value & 0x7F) as u8];
+    value >>= 7;
+    while value > 0 {
+        bytes.push(((value & 0x7F) as u8) | 0x80);
+        value >>= 7;
+    }
+    buffer.extend(bytes.iter().rev());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_set_at_every_difficulty() {
+        for difficulty in Difficulty::ALL {
+            let set = ExerciseSet::generate(42, difficulty, &ExerciseKind::ALL, 25);
+            assert_eq!(set, ExerciseSet::generate(42, difficulty, &ExerciseKind::ALL, 25));
+            assert_ne!(set, ExerciseSet::generate(43, difficulty, &ExerciseKind::ALL, 25));
+            for exercise in &set.exercises {
+                assert!(!exercise.notes.is_empty() && !exercise.answer.is_empty());
+                assert!(exercise.choices.is_empty() || exercise.choices.contains(&exercise.answer));
+            }
+        }
+    }
+
+    #[test]
+    fn answer_keys_match_the_notes() {
+        let mut generator = ExerciseGenerator::new(9, Difficulty::Intermediate);
+        for _ in 0..20 {
+            let interval = generator.interval();
+            let [a, b] = [&interval.notes[0], &interval.notes[1]].map(|n| n.pitch.absolute_steps());
+            let label = Interval12::from_semitones((b - a).abs()).to_string();
+            assert_eq!(label, interval.answer);
+            let chord = generator.chord_quality();
+            let tones: Vec<PitchClass12> = chord.notes.iter().map(|n| n.pitch_class()).collect();
+            let kind = Chord12::new(tones).kind().expect("named chord");
+            assert_eq!(quality_name(kind), chord.answer);
+            let melody = generator.melody();
+            let names: Vec<String> = melody.notes.iter().map(|n| n.pitch.to_string()).collect();
+            assert_eq!(names.join(" "), melody.answer);
+            let progression = generator.progression();
+            assert!(progression.answer.ends_with(" I") || progression.answer.ends_with(" i"));
+        }
+    }
+
+    #[test]
+    fn midi_rendering_writes_every_note() {
+        let scale = ExerciseGenerator::new(3, Difficulty::Beginner).scale();
+        let midi = scale.to_midi(120.0);
+        assert_eq!(&midi[..4], b"MThd");
+        assert_eq!(&midi[12..14], &MIDI_TICKS_PER_BEAT.to_be_bytes());
+        assert_eq!(&midi[14..18], b"MTrk");
+        assert_eq!(&midi[22..29], &[0x00, 0xFF, 0x51, 0x03, 0x07, 0xA1, 0x20]);
+        let note_ons = midi.windows(3).filter(|w| w[0] == 0x90 && w[2] == 80).count();
+        assert_eq!(note_ons, 8);
+        assert_eq!(&midi[midi.len() - 3..], &[0xFF, 0x2F, 0x00]);
+        let mut buffer = Vec::new();
+        push_variable_length(&mut buffer, 480);
+        assert_eq!(buffer, [0x83, 0x60]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn exercise_sets_round_trip_through_json() {
+        let set = ExerciseSet::generate(5, Difficulty::Advanced, &ExerciseKind::ALL, 5);
+        let json = serde_json::to_value(&set).expect("serialize");
+        assert_eq!(json["difficulty"], "advanced");
+        assert_eq!(json["exercises"][1]["kind"], "chord_quality");
+        let decoded: ExerciseSet = serde_json::from_value(json).expect("deserialize");
+        assert_eq!(decoded, set);
+    }
+}
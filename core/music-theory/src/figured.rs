@@ -0,0 +1,258 @@
+//! core/music-theory/src/figured.rs
+//! Figured-bass symbols: parsing, abbreviation expansion, and realization in a key.
+//!
+//! A [`FiguredBass`] stores the complete figure stack above a bass note. Parsing expands the
+//! usual abbreviations (`""` = 5/3, `6` = 6/3, `7` = 7/5/3, `6/5` = 6/5/3, `4/3` = 6/4/3,
+//! `4/2` or `2` = 6/4/2) and accepts accidentals before a figure (`#6`, `b7`, `n3`); a lone
+//! accidental alters the third. Figures are diatonic intervals above the bass in the key.
+//!
+//! ```
+//! use music_theory::{FiguredBass, Key12, PitchClass12};
+//! let pc = PitchClass12::from_semitones;
+//! let figures: FiguredBass = "6/5".parse().unwrap();
+//! assert_eq!(figures.numbers(), [6, 5, 3]);
+//! assert_eq!(figures.inversion(), 1);
+//! // B with 6/5 in C major spells G7 in first inversion.
+//! let tones = figures.pitch_classes(Key12::major(pc(0)), pc(11));
+//! assert_eq!(tones, [pc(11), pc(7), pc(5), pc(2)]);
+//! ```
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::degree::ScaleDegree;
+use crate::interval::Interval12;
+use crate::key::{Key12, Mode};
+use crate::pitch::PitchClass12;
+use crate::text::ParseError;
+
+/// A single figure: a generic interval above the bass with an optional accidental.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Figure {
+    /// Generic interval number (`2`–`9`).
+    pub number: u8,
+    /// `Some(1)` sharp, `Some(-1)` flat, `Some(0)` natural; `None` when unaltered.
+    pub accidental: Option<i8>,
+}
+
+impl Figure {
+    #[must_use]
+    pub fn new(number: u8, accidental: Option<i8>) -> Self {
+        Self { number, accidental }
+    }
+}
+
+/// Complete figure stack above a bass note, highest figure first.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FiguredBass {
+    pub figures: Vec<Figure>,
+}
+
+impl Default for FiguredBass {
+    /// Root-position triad (`5/3`).
+    fn default() -> Self {
+        Self::expand(Vec::new())
+    }
+}
+
+impl FiguredBass {
+    /// Expand abbreviated figures into the full stack.
+    #[must_use]
+    pub fn expand(figures: Vec<Figure>) -> Self {
+        let mut numbers: Vec<u8> = figures.iter().map(|figure| figure.number).collect();
+        numbers.sort_unstable_by(|a, b| b.cmp(a));
+        let implied: &[u8] = match numbers.as_slice() {
+            [] | [3] | [5] | [5, 3] => &[5, 3],
+            [6] | [6, 3] => &[6, 3],
+            [7] | [7, 3] | [7, 5] => &[7, 5, 3],
+            [6, 5] => &[6, 5, 3],
+            [4, 3] | [6, 4, 3] => &[6, 4, 3],
+            [2] | [4, 2] | [6, 4, 2] => &[6, 4, 2],
+            [9] => &[9, 5, 3],
+            _ => &[],
+        };
+        let mut stack = figures;
+        for number in implied {
+            if !stack.iter().any(|figure| figure.number == *number) {
+                stack.push(Figure::new(*number, None));
+            }
+        }
+        stack.sort_by(|a, b| b.number.cmp(&a.number));
+        Self { figures: stack }
+    }
+
+    /// Figure numbers, highest first.
+    #[must_use]
+    pub fn numbers(&self) -> Vec<u8> {
+        self.figures.iter().map(|figure| figure.number).collect()
+    }
+
+    /// Whether the stack spells a seventh chord (`7`, `6/5`, `4/3`, `4/2`).
+    #[must_use]
+    pub fn is_seventh(&self) -> bool {
+        matches!(self.numbers().as_slice(), [7, 5, 3] | [6, 5, 3] | [6, 4, 3] | [6, 4, 2])
+    }
+
+    /// Chord inversion implied by the stack (`6` and `6/5` = 1, `6/4` and `4/3` = 2, `4/2` = 3).
+    #[must_use]
+    pub fn inversion(&self) -> usize {
+        match self.numbers().as_slice() {
+            [6, 3] | [6, 5, 3] => 1,
+            [6, 4] | [6, 4, 3] => 2,
+            [6, 4, 2] => 3,
+            _ => 0,
+        }
+    }
+
+    /// Bass plus every figure as pitch classes, realized diatonically in `key`.
+    ///
+    /// Figures follow the key signature even above a chromatic bass (`6` over G# in A minor is
+    /// B and E). Sharps and flats alter the diatonic note by a semitone; a natural cancels the
+    /// key signature's accidental on that note.
+    #[must_use]
+    pub fn pitch_classes(&self, key: Key12, bass: PitchClass12) -> Vec<PitchClass12> {
+        let scale = key.scale12();
+        let letter = scale
+            .scale_degree_of(bass)
+            .and_then(|degree| scale.pitch_class_of(ScaleDegree::natural(degree.degree)))
+            .unwrap_or(bass);
+        let mut tones = vec![bass];
+        for figure in &self.figures {
+            let steps = i32::from(figure.number) - 1;
+            let Some(diatonic) = scale.transpose_pitch_class(letter, steps) else {
+                continue;
+            };
+            let shift = match figure.accidental {
+                Some(0) => natural_shift(key, diatonic),
+                Some(accidental) => i32::from(accidental),
+                None => 0,
+            };
+            let tone = diatonic.transpose(Interval12::from_semitones(shift));
+            if !tones.contains(&tone) {
+                tones.push(tone);
+            }
+        }
+        tones
+    }
+}
+
+/// Semitone shift that turns a black-key diatonic note back into its natural letter.
+fn natural_shift(key: Key12, diatonic: PitchClass12) -> i32 {
+    if !matches!(diatonic.to_semitones(), 1 | 3 | 6 | 8 | 10) {
+        return 0;
+    }
+    let major_tonic = match key.mode {
+        Mode::Major => key.tonic,
+        Mode::Minor => key.tonic.transpose(Interval12::from_semitones(3)),
+    };
+    let flat_key = matches!(major_tonic.to_semitones(), 1 | 3 | 5 | 8 | 10);
+    if flat_key { 1 } else { -1 }
+}
+
+impl FromStr for FiguredBass {
+    type Err = ParseError;
+
+    /// Parses `"6"`, `"6/4"`, `"64"`, `"#6"`, `"b7"`, `"4/2"`, `"#"`, or `""` (5/3).
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut figures = Vec::new();
+        let mut accidental: Option<i8> = None;
+        for ch in input.trim().chars() {
+            match ch {
+                '/' | ' ' => {}
+                '#' | '♯' | '+' => accidental = Some(1),
+                'b' | '♭' => accidental = Some(-1),
+                'n' | '♮' => accidental = Some(0),
+                '2'..='9' => {
+                    let number = u8::try_from(ch.to_digit(10).unwrap_or_default()).unwrap_or(0);
+                    figures.push(Figure::new(number, accidental.take()));
+                }
+                _ => return Err(ParseError::InvalidFigure(input.trim().to_string())),
+            }
+        }
+        if let Some(accidental) = accidental {
+            match figures.iter_mut().find(|figure| figure.number == 3) {
+                Some(third) => third.accidental = Some(accidental),
+                None => figures.push(Figure::new(3, Some(accidental))),
+            }
+        }
+        Ok(Self::expand(figures))
+    }
+}
+
+impl fmt::Display for FiguredBass {
+    /// Abbreviated figures (`"6"`, `"6/5"`, `"4/2"`); altered stacks print every figure.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.figures.iter().all(|figure| figure.accidental.is_none()) {
+            let short = match self.numbers().as_slice() {
+                [5, 3] => Some(""),
+                [6, 3] => Some("6"),
+                [6, 4] => Some("6/4"),
+                [7, 5, 3] => Some("7"),
+                [6, 5, 3] => Some("6/5"),
+                [6, 4, 3] => Some("4/3"),
+                [6, 4, 2] => Some("4/2"),
+                _ => None,
+            };
+            if let Some(short) = short {
+                return f.write_str(short);
+            }
+        }
+        let labels: Vec<String> = self
+            .figures
+            .iter()
+            .map(|figure| {
+                let accidental = match figure.accidental {
+                    Some(1) => "#",
+                    Some(-1) => "b",
+                    Some(0) => "n",
+                    _ => "",
+                };
+                format!("{accidental}{}", figure.number)
+            })
+            .collect();
+        f.write_str(&labels.join("/"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pc(semitones: u16) -> PitchClass12 {
+        PitchClass12::from_semitones(semitones)
+    }
+
+    #[test]
+    fn abbreviations_expand_to_full_stacks() {
+        let parse = |input: &str| input.parse::<FiguredBass>().expect(input).numbers();
+        assert_eq!(parse(""), [5, 3]);
+        assert_eq!(parse("6"), [6, 3]);
+        assert_eq!(parse("64"), [6, 4]);
+        assert_eq!(parse("7"), [7, 5, 3]);
+        assert_eq!(parse("4/3"), [6, 4, 3]);
+        assert_eq!(parse("2"), [6, 4, 2]);
+        assert_eq!("6x".parse::<FiguredBass>(), Err(ParseError::InvalidFigure("6x".into())));
+        assert_eq!("1".parse::<FiguredBass>(), Err(ParseError::InvalidFigure("1".into())));
+        for label in ["", "6", "6/4", "7", "6/5", "4/3", "4/2", "#6/3", "5/b3"] {
+            let figures: FiguredBass = label.parse().expect(label);
+            assert_eq!(figures.to_string(), label);
+        }
+    }
+
+    #[test]
+    fn accidentals_alter_diatonic_figures() {
+        let a_minor = Key12::minor(pc(9));
+        // E with a raised third: E–G#–B, the major dominant of A minor.
+        let dominant: FiguredBass = "#".parse().expect("sharp third");
+        assert_eq!(dominant.pitch_classes(a_minor, pc(4)), [pc(4), pc(11), pc(8)]);
+        // Natural in F major cancels the B flat: G–Bn–D.
+        let natural: FiguredBass = "n".parse().expect("natural third");
+        assert_eq!(natural.pitch_classes(Key12::major(pc(5)), pc(7)), [pc(7), pc(2), pc(11)]);
+        let sharp_six: FiguredBass = "#6".parse().expect("sharp six");
+        assert_eq!(sharp_six.pitch_classes(Key12::major(pc(0)), pc(4)), [pc(4), pc(1), pc(7)]);
+        let six: FiguredBass = "6".parse().expect("six");
+        assert_eq!(six.pitch_classes(a_minor, pc(8)), [pc(8), pc(4), pc(11)]);
+    }
+}
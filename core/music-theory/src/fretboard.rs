@@ -0,0 +1,418 @@
+//! core/music-theory/src/fretboard.rs
+//! Fretted-instrument model: pitch positions, chord shapes, and melodic fingerings.
+//!
+//! A [`Fretboard`] is a tuning (open-string pitches, lowest string first), a fret count, and an
+//! optional capo. Fret numbers are always counted from the nut; with a capo, the capo fret acts
+//! as the open string and lower frets are unavailable.
+//!
+//! [`Fretboard::chord_shapes`] enumerates playable voicings of a [`Chord12`] under
+//! [`ShapeOptions`] (fret span, highest fret, open-string preference), cheapest first.
+//! [`Fretboard::fingering`] picks one position per melody note, minimizing hand shifts, and
+//! [`Fretboard::tab`] renders positions as ASCII tablature.
+//!
+//! ```
+//! use music_theory::{Chord12, Fretboard, PitchClass12, ShapeOptions};
+//! let guitar = Fretboard::guitar();
+//! let c = Chord12::major_triad(PitchClass12::from_semitones(0));
+//! let shapes = guitar.chord_shapes(&c, &ShapeOptions::default());
+//! assert_eq!(shapes[0].to_string(), "x32010");
+//! ```
+
+use core::fmt;
+
+use crate::chord::Chord12;
+use crate::pitch::Pitch12;
+
+/// A string (0 = lowest) and fret (0 = nut).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FretPosition {
+    pub string: usize,
+    pub fret: u8,
+}
+
+impl FretPosition {
+    #[must_use]
+    pub fn new(string: usize, fret: u8) -> Self {
+        Self { string, fret }
+    }
+}
+
+/// Preferences for [`Fretboard::chord_shapes`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapeOptions {
+    /// Largest stretch between fretted notes, in frets (inclusive: `4` = four adjacent frets).
+    pub max_span: u8,
+    /// Highest fret to consider.
+    pub max_fret: u8,
+    /// Reward open strings (and shapes near the nut).
+    pub prefer_open: bool,
+    /// Require the chord's sounding bass on the lowest played string.
+    pub bass_on_lowest: bool,
+    /// Maximum number of shapes returned.
+    pub limit: usize,
+}
+
+impl Default for ShapeOptions {
+    fn default() -> Self {
+        Self { max_span: 4, max_fret: 12, prefer_open: true, bass_on_lowest: true, limit: 10 }
+    }
+}
+
+/// A chord shape: one fret per string (lowest first), `None` for muted strings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChordShape {
+    pub frets: Vec<Option<u8>>,
+    /// Playability cost (lower is easier).
+    pub cost: f64,
+}
+
+impl ChordShape {
+    /// Positions of every played string.
+    #[must_use]
+    pub fn positions(&self) -> Vec<FretPosition> {
+        self.frets
+            .iter()
+            .enumerate()
+            .filter_map(|(string, fret)| fret.map(|fret| FretPosition::new(string, fret)))
+            .collect()
+    }
+}
+
+impl fmt::Display for ChordShape {
+    /// Chord-chart notation from the lowest string (`"x32010"`); frets above 9 are bracketed.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for fret in &self.frets {
+            match fret {
+                None => f.write_str("x")?,
+                Some(fret) if *fret < 10 => write!(f, "{fret}")?,
+                Some(fret) => write!(f, "({fret})")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A fretted instrument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fretboard {
+    /// Open-string pitches, lowest string first.
+    pub tuning: Vec<Pitch12>,
+    pub frets: u8,
+    /// Capo fret (`0` = no capo).
+    pub capo: u8,
+}
+
+impl Fretboard {
+    #[must_use]
+    pub fn new(tuning: Vec<Pitch12>, frets: u8) -> Self {
+        Self { tuning, frets, capo: 0 }
+    }
+
+    /// Six-string guitar in standard tuning (E2 A2 D3 G3 B3 E4), 22 frets.
+    #[must_use]
+    pub fn guitar() -> Self {
+        let p = Pitch12::from_semitones_and_octave;
+        Self::new(vec![p(4, 2), p(9, 2), p(2, 3), p(7, 3), p(11, 3), p(4, 4)], 22)
+    }
+
+    /// Four-string bass in standard tuning (E1 A1 D2 G2), 20 frets.
+    #[must_use]
+    pub fn bass() -> Self {
+        let p = Pitch12::from_semitones_and_octave;
+        Self::new(vec![p(4, 1), p(9, 1), p(2, 2), p(7, 2)], 20)
+    }
+
+    /// Same instrument with a capo at `fret`.
+    #[must_use]
+    pub fn with_capo(self, capo: u8) -> Self {
+        Self { capo: capo.min(self.frets), ..self }
+    }
+
+    /// Pitch sounding at a position, or `None` if the position is off the board or behind the capo.
+    #[must_use]
+    pub fn pitch_at(&self, position: FretPosition) -> Option<Pitch12> {
+        if position.fret < self.capo || position.fret > self.frets {
+            return None;
+        }
+        let open = self.tuning.get(position.string)?;
+        Some(Pitch12::from_absolute_steps(open.absolute_steps() + i32::from(position.fret)))
+    }
+
+    /// Every position producing `pitch`, lowest string first.
+    #[must_use]
+    pub fn positions(&self, pitch: Pitch12) -> Vec<FretPosition> {
+        self.tuning
+            .iter()
+            .enumerate()
+            .filter_map(|(string, open)| {
+                let fret = u8::try_from(pitch.absolute_steps() - open.absolute_steps()).ok()?;
+                (self.capo..=self.frets).contains(&fret).then(|| FretPosition::new(string, fret))
+            })
+            .collect()
+    }
+
+    /// Playable shapes for `chord`, cheapest first.
+    ///
+    /// Every chord tone must sound (up to the number of strings); interior muted strings,
+    /// unused strings, wide stretches, and high positions add cost, open strings reduce it when
+    /// [`ShapeOptions::prefer_open`] is set.
+    #[must_use]
+    pub fn chord_shapes(&self, chord: &Chord12, options: &ShapeOptions) -> Vec<ChordShape> {
+        let top = options.max_fret.min(self.frets);
+        let choices: Vec<Vec<Option<u8>>> = self
+            .tuning
+            .iter()
+            .enumerate()
+            .map(|(string, _)| {
+                let frets = (self.capo..=top).filter(|fret| {
+                    self.pitch_at(FretPosition::new(string, *fret))
+                        .is_some_and(|pitch| chord.contains(pitch.pitch_class))
+                });
+                core::iter::once(None).chain(frets.map(Some)).collect()
+            })
+            .collect();
+        let mut shapes = Vec::new();
+        let mut current = Vec::with_capacity(self.tuning.len());
+        self.collect_shapes(chord, options, &choices, &mut current, &mut shapes);
+        shapes.sort_by(|a, b| a.cost.total_cmp(&b.cost));
+        shapes.truncate(options.limit);
+        shapes
+    }
+
+    fn collect_shapes(
+        &self,
+        chord: &Chord12,
+        options: &ShapeOptions,
+        choices: &[Vec<Option<u8>>],
+        current: &mut Vec<Option<u8>>,
+        out: &mut Vec<ChordShape>,
+    ) {
+        let Some(options_here) = choices.get(current.len()) else {
+            if let Some(cost) = self.shape_cost(chord, options, current) {
+                out.push(ChordShape { frets: current.clone(), cost });
+            }
+            return;
+        };
+        for choice in options_here {
+            current.push(*choice);
+            if self.within_span(current, options.max_span) {
+                self.collect_shapes(chord, options, choices, current, out);
+            }
+            current.pop();
+        }
+    }
+
+    fn within_span(&self, frets: &[Option<u8>], max_span: u8) -> bool {
+        let fretted = frets.iter().flatten().filter(|fret| **fret > self.capo);
+        let (low, high) =
+            fretted.fold((u8::MAX, 0), |(low, high), fret| (low.min(*fret), high.max(*fret)));
+        low == u8::MAX || high - low < max_span.max(1)
+    }
+
+    fn shape_cost(
+        &self,
+        chord: &Chord12,
+        options: &ShapeOptions,
+        frets: &[Option<u8>],
+    ) -> Option<f64> {
+        let played: Vec<(usize, u8)> = frets
+            .iter()
+            .enumerate()
+            .filter_map(|(string, fret)| fret.map(|f| (string, f)))
+            .collect();
+        let pitches: Vec<Pitch12> = played
+            .iter()
+            .filter_map(|(string, fret)| self.pitch_at(FretPosition::new(*string, *fret)))
+            .collect();
+        let needed = chord.tones.len().min(self.tuning.len());
+        let covered =
+            chord.tones.iter().filter(|tone| pitches.iter().any(|p| p.pitch_class == **tone));
+        if covered.count() < needed || played.len() < needed.max(2) {
+            return None;
+        }
+        let lowest = pitches.iter().min_by_key(|pitch| pitch.absolute_steps())?;
+        if options.bass_on_lowest && Some(lowest.pitch_class) != chord.sounding_bass() {
+            return None;
+        }
+        let fretted: Vec<u8> =
+            played.iter().map(|(_, fret)| *fret).filter(|f| *f > self.capo).collect();
+        let lowest_fret = fretted.iter().copied().min().unwrap_or(self.capo);
+        let barre = fretted.iter().filter(|fret| **fret == lowest_fret).count() > 1;
+        let fingers = fretted.len()
+            - if barre { fretted.iter().filter(|f| **f == lowest_fret).count() - 1 } else { 0 };
+        if fingers > 4 {
+            return None;
+        }
+        let first = played.first().map_or(0, |(string, _)| *string);
+        let last = played.last().map_or(0, |(string, _)| *string);
+        let interior_mutes = (first..=last).filter(|string| frets[*string].is_none()).count();
+        let unused = self.tuning.len() - played.len();
+        let open = played.len() - fretted.len();
+        let span = fretted.iter().copied().max().map_or(0, |high| high - lowest_fret);
+        let mut cost = f64::from(lowest_fret - self.capo) * 0.3
+            + f64::from(span) * 0.5
+            + unused as f64
+            + interior_mutes as f64 * 2.0
+            + if barre { 1.0 } else { 0.0 };
+        if options.prefer_open {
+            cost -= open as f64 * 0.5;
+        }
+        Some(cost)
+    }
+
+    /// One position per note minimizing hand movement, or `None` if a note is unplayable.
+    ///
+    /// The search tracks the fretting hand's position (the fret under the first finger, covering
+    /// four frets); shifting the hand costs one per fret, crossing strings a little, and open
+    /// strings are playable from any hand position.
+    #[must_use]
+    pub fn fingering(&self, melody: &[Pitch12]) -> Option<Vec<FretPosition>> {
+        let candidates: Vec<Vec<(FretPosition, u8)>> =
+            melody.iter().map(|pitch| self.hand_states(*pitch)).collect();
+        if candidates.iter().any(Vec::is_empty) {
+            return None;
+        }
+        let local = |(_, hand): &(FretPosition, u8)| f64::from(hand - self.capo) * 0.05;
+        let mut costs: Vec<f64> = candidates.first()?.iter().map(local).collect();
+        let mut back: Vec<Vec<usize>> = Vec::with_capacity(candidates.len());
+        for window in candidates.windows(2) {
+            let (previous, current) = (&window[0], &window[1]);
+            let mut next_costs = Vec::with_capacity(current.len());
+            let mut pointers = Vec::with_capacity(current.len());
+            for to in current {
+                let (best, cost) = previous
+                    .iter()
+                    .zip(&costs)
+                    .enumerate()
+                    .map(|(index, (from, cost))| (index, cost + Self::movement(*from, *to)))
+                    .min_by(|a, b| a.1.total_cmp(&b.1))?;
+                next_costs.push(cost + local(to));
+                pointers.push(best);
+            }
+            costs = next_costs;
+            back.push(pointers);
+        }
+        let (mut index, _) = costs.iter().enumerate().min_by(|a, b| a.1.total_cmp(b.1))?;
+        let mut path = vec![candidates[candidates.len() - 1][index].0];
+        for (position, pointers) in back.iter().enumerate().rev() {
+            index = pointers[index];
+            path.push(candidates[position][index].0);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every (position, hand position) pair that can play `pitch`.
+    fn hand_states(&self, pitch: Pitch12) -> Vec<(FretPosition, u8)> {
+        let lowest = self.capo + 1;
+        let highest = self.frets.saturating_sub(3).max(lowest);
+        self.positions(pitch)
+            .into_iter()
+            .flat_map(|position| {
+                let hands = if position.fret == self.capo {
+                    lowest..=highest
+                } else {
+                    position.fret.saturating_sub(3).max(lowest)..=position.fret.min(highest)
+                };
+                hands.map(move |hand| (position, hand))
+            })
+            .collect()
+    }
+
+    /// Cost of moving from one (position, hand position) state to another.
+    fn movement(from: (FretPosition, u8), to: (FretPosition, u8)) -> f64 {
+        let strings = from.0.string.abs_diff(to.0.string) as f64 * 0.1;
+        strings + f64::from(from.1.abs_diff(to.1))
+    }
+
+    /// ASCII tablature for a sequence of positions, highest string on top.
+    #[must_use]
+    pub fn tab(&self, positions: &[FretPosition]) -> String {
+        let lines = (0..self.tuning.len()).rev().map(|string| {
+            let name = crate::text::PITCH_CLASS_LABELS
+                [usize::from(self.tuning[string].pitch_class.to_semitones() % 12)];
+            let cells: String = positions
+                .iter()
+                .map(|position| {
+                    let cell = if position.string == string {
+                        position.fret.to_string()
+                    } else {
+                        "-".repeat(position.fret.to_string().len())
+                    };
+                    format!("-{cell}")
+                })
+                .collect();
+            format!("{name:<2}|{cells}-|")
+        });
+        lines.collect::<Vec<_>>().join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch::PitchClass12;
+
+    fn p(semitones: u16, octave: i16) -> Pitch12 {
+        Pitch12::from_semitones_and_octave(semitones, octave)
+    }
+
+    #[test]
+    fn positions_respect_tuning_and_capo() {
+        let guitar = Fretboard::guitar();
+        let e4 = guitar.positions(p(4, 4));
+        assert_eq!(e4.len(), 5);
+        assert_eq!(e4.last(), Some(&FretPosition::new(5, 0)));
+        assert_eq!(guitar.pitch_at(FretPosition::new(1, 3)), Some(p(0, 3)));
+        let capo = Fretboard::guitar().with_capo(2);
+        assert!(capo.pitch_at(FretPosition::new(0, 1)).is_none());
+        assert!(capo.positions(p(4, 2)).is_empty());
+        assert!(Fretboard::bass().positions(p(4, 1)).contains(&FretPosition::new(0, 0)));
+    }
+
+    #[test]
+    fn chord_shapes_prefer_open_positions() {
+        let guitar = Fretboard::guitar();
+        let g = Chord12::major_triad(PitchClass12::from_semitones(7));
+        let shapes = guitar.chord_shapes(&g, &ShapeOptions::default());
+        assert_eq!(shapes[0].to_string(), "320003");
+        let barre = ShapeOptions { prefer_open: false, ..ShapeOptions::default() };
+        let closed = guitar
+            .with_capo(0)
+            .chord_shapes(&Chord12::minor_triad(PitchClass12::from_semitones(11)), &barre);
+        assert!(closed.iter().all(|shape| {
+            let fretted: Vec<u8> =
+                shape.frets.iter().flatten().copied().filter(|f| *f > 0).collect();
+            fretted.iter().max().zip(fretted.iter().min()).is_none_or(|(high, low)| high - low < 4)
+        }));
+        assert!(!closed.is_empty());
+        // Capo 2 shifts the open C shape up to sound as D.
+        let d = Chord12::major_triad(PitchClass12::from_semitones(2));
+        let capo = Fretboard::guitar().with_capo(2).chord_shapes(&d, &ShapeOptions::default());
+        assert_eq!(capo[0].to_string(), "x54232");
+    }
+
+    #[test]
+    fn melodic_fingering_stays_in_position() {
+        let guitar = Fretboard::guitar();
+        // C major scale from C4 to C5.
+        let scale = [0, 2, 4, 5, 7, 9, 11]
+            .map(|s| p(s, 4))
+            .into_iter()
+            .chain([p(0, 5)])
+            .collect::<Vec<_>>();
+        let path = guitar.fingering(&scale).expect("playable");
+        let frets: Vec<u8> = path.iter().map(|position| position.fret).collect();
+        let fretted: Vec<u8> = frets.iter().copied().filter(|fret| *fret > 0).collect();
+        let spread = fretted.iter().max().unwrap_or(&0) - fretted.iter().min().unwrap_or(&0);
+        assert!(spread <= 4, "{frets:?}");
+        for (position, pitch) in path.iter().zip(&scale) {
+            assert_eq!(guitar.pitch_at(*position), Some(*pitch));
+        }
+        let tab = guitar.tab(&path[..2]);
+        assert_eq!(tab.lines().count(), 6);
+        assert!(tab.lines().last().is_some_and(|line| line.starts_with("E |")));
+        assert!(guitar.fingering(&[p(0, 1)]).is_none());
+    }
+}
@@ -12,6 +12,8 @@ use crate::pitch::PitchClass;
 
 /// High-level tonal function kind.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FunctionKind {
     Tonic,
     Subdominant,
@@ -24,6 +26,8 @@ pub enum FunctionKind {
 
 /// Harmonic function for a scale degree in a key.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
 pub struct HarmonicFunction<T: Temperament> {
     /// Descriptive bucket (tonic, dominant, etc.).
     pub kind: FunctionKind,
@@ -0,0 +1,342 @@
+//! core/music-theory/src/harmonic.rs
+//! Harmonic series, virtual (subharmonic) pitch, and combination tones.
+//!
+//! Frequencies are in Hz and map to [`Pitch`] values through the equal-tempered grid of a
+//! [`Temperament`] with A4 = 440 Hz ([`Pitch::frequency`], [`Pitch::nearest_to_frequency`]).
+//! [`HarmonicSeries`] lists the partials of a fundamental with their nearest pitch and cents
+//! deviation; [`VirtualPitchEstimator`] finds the highest fundamental whose harmonics explain a
+//! set of frequencies; [`combination_tones`] computes the difference, cubic difference, and sum
+//! tones of every pair.
+//!
+//! ```
+//! use music_theory::{HarmonicSeries, Pitch12, VirtualPitchEstimator};
+//! let c2: Pitch12 = "C2".parse().unwrap();
+//! let seventh = &HarmonicSeries::of_pitch(&c2).partials::<music_acoustic::T12>(7)[6];
+//! assert_eq!(seventh.nearest.to_string(), "Bb4");
+//! assert!((seventh.cents + 31.2).abs() < 0.1);
+//!
+//! let triad: Vec<Pitch12> = ["C4", "E4", "G4"].iter().map(|p| p.parse().unwrap()).collect();
+//! let root = VirtualPitchEstimator::default().estimate_pitches(&triad).unwrap();
+//! assert_eq!((root.nearest.to_string(), root.harmonics), ("C2".to_string(), vec![4, 5, 6]));
+//! ```
+
+use music_acoustic::Temperament;
+
+use crate::pitch::Pitch;
+
+/// Frequency of A4, the tuning reference.
+pub const A4_HZ: f64 = 440.0;
+
+impl<T: Temperament> Pitch<T> {
+    /// Frequency in Hz on the temperament's equal grid, A4 = 440 Hz.
+    #[must_use]
+    pub fn frequency(&self) -> f64 {
+        let steps = f64::from(T::STEPS_PER_OCTAVE);
+        let above_a4 = f64::from(self.absolute_steps()) - (4.0 + 9.0 / 12.0) * steps;
+        A4_HZ * (above_a4 / steps).exp2()
+    }
+
+    /// Nearest grid pitch to `hz` and the deviation of `hz` from it in cents.
+    ///
+    /// Returns `None` for non-positive or non-finite frequencies.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // Finite audio frequencies stay far inside i32.
+    pub fn nearest_to_frequency(hz: f64) -> Option<(Self, f64)> {
+        if !hz.is_finite() || hz <= 0.0 {
+            return None;
+        }
+        let steps = f64::from(T::STEPS_PER_OCTAVE);
+        let exact = (hz / A4_HZ).log2() * steps + (4.0 + 9.0 / 12.0) * steps;
+        let pitch = Self::from_absolute_steps(exact.round() as i32);
+        Some((pitch, cents_between(pitch.frequency(), hz)))
+    }
+}
+
+/// Signed distance from `from` to `to` in cents.
+#[must_use]
+pub fn cents_between(from: f64, to: f64) -> f64 {
+    1200.0 * (to / from).log2()
+}
+
+/// One partial of a harmonic series located on a temperament's grid.
+#[derive(Debug, PartialEq)]
+pub struct Partial<T: Temperament> {
+    /// Harmonic number (1 = fundamental).
+    pub number: u32,
+    pub frequency: f64,
+    /// Nearest grid pitch.
+    pub nearest: Pitch<T>,
+    /// Deviation of the partial from [`Partial::nearest`] (positive = sharp).
+    pub cents: f64,
+}
+
+impl<T: Temperament> Clone for Partial<T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+/// Harmonic series above a fundamental frequency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HarmonicSeries {
+    /// Fundamental in Hz.
+    pub fundamental: f64,
+}
+
+impl HarmonicSeries {
+    #[must_use]
+    pub fn new(fundamental: f64) -> Self {
+        Self { fundamental }
+    }
+
+    /// Series on a pitch's frequency.
+    #[must_use]
+    pub fn of_pitch<T: Temperament>(pitch: &Pitch<T>) -> Self {
+        Self::new(pitch.frequency())
+    }
+
+    /// Frequency of harmonic `number` (1 = fundamental).
+    #[must_use]
+    pub fn frequency(&self, number: u32) -> f64 {
+        self.fundamental * f64::from(number)
+    }
+
+    /// Frequencies of the first `count` harmonics.
+    #[must_use]
+    pub fn frequencies(&self, count: u32) -> Vec<f64> {
+        (1..=count).map(|number| self.frequency(number)).collect()
+    }
+
+    /// First `count` harmonics with their nearest pitches in `T`.
+    #[must_use]
+    pub fn partials<T: Temperament>(&self, count: u32) -> Vec<Partial<T>> {
+        (1..=count)
+            .filter_map(|number| {
+                let frequency = self.frequency(number);
+                let (nearest, cents) = Pitch::nearest_to_frequency(frequency)?;
+                Some(Partial { number, frequency, nearest, cents })
+            })
+            .collect()
+    }
+}
+
+/// Fundamental implied by a set of frequencies.
+#[derive(Debug, PartialEq)]
+pub struct VirtualPitch<T: Temperament> {
+    /// Estimated fundamental in Hz (least-squares fit over the matched harmonics).
+    pub frequency: f64,
+    /// Harmonic number assigned to each input frequency, in input order.
+    pub harmonics: Vec<u32>,
+    /// Nearest grid pitch to the fundamental.
+    pub nearest: Pitch<T>,
+    /// Deviation of the fundamental from [`VirtualPitch::nearest`].
+    pub cents: f64,
+    /// Largest deviation of an input from its assigned harmonic, in cents.
+    pub worst_fit: f64,
+}
+
+impl<T: Temperament> Clone for VirtualPitch<T> {
+    fn clone(&self) -> Self {
+        Self { harmonics: self.harmonics.clone(), ..*self }
+    }
+}
+
+/// Subharmonic matcher for virtual-pitch estimation (in the spirit of Terhardt).
+///
+/// The lowest input is tried as harmonic 1, 2, 3, ... of a candidate fundamental; the first
+/// (highest) candidate under which every input lies within `tolerance_cents` of a harmonic no
+/// higher than `max_harmonic` wins.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VirtualPitchEstimator {
+    pub max_harmonic: u32,
+    /// Allowed mistuning of each input against its harmonic.
+    pub tolerance_cents: f64,
+    /// Candidates below this frequency (Hz) are not considered.
+    pub min_frequency: f64,
+}
+
+impl Default for VirtualPitchEstimator {
+    fn default() -> Self {
+        Self { max_harmonic: 16, tolerance_cents: 20.0, min_frequency: 20.0 }
+    }
+}
+
+impl VirtualPitchEstimator {
+    /// Estimate the fundamental of `frequencies` (Hz), or `None` when no candidate fits.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // Ratios are small.
+    pub fn estimate<T: Temperament>(&self, frequencies: &[f64]) -> Option<VirtualPitch<T>> {
+        if frequencies.iter().any(|hz| !hz.is_finite() || *hz <= 0.0) {
+            return None;
+        }
+        let lowest = frequencies.iter().copied().reduce(f64::min)?;
+        (1..=self.max_harmonic).find_map(|number| {
+            let candidate = lowest / f64::from(number);
+            if candidate < self.min_frequency {
+                return None;
+            }
+            let mut harmonics = Vec::with_capacity(frequencies.len());
+            for hz in frequencies {
+                let harmonic = (hz / candidate).round().max(1.0) as u32;
+                let mistuning = cents_between(candidate * f64::from(harmonic), *hz);
+                if harmonic > self.max_harmonic || mistuning.abs() > self.tolerance_cents {
+                    return None;
+                }
+                harmonics.push(harmonic);
+            }
+            let (weighted, squares) =
+                frequencies.iter().zip(&harmonics).fold((0.0, 0.0), |(w, s), (hz, n)| {
+                    let n = f64::from(*n);
+                    (w + hz * n, s + n * n)
+                });
+            let frequency = weighted / squares;
+            let worst_fit = frequencies
+                .iter()
+                .zip(&harmonics)
+                .map(|(hz, n)| cents_between(frequency * f64::from(*n), *hz).abs())
+                .fold(0.0, f64::max);
+            let (nearest, cents) = Pitch::nearest_to_frequency(frequency)?;
+            Some(VirtualPitch { frequency, harmonics, nearest, cents, worst_fit })
+        })
+    }
+
+    /// Estimate the fundamental of sounding pitches.
+    #[must_use]
+    pub fn estimate_pitches<T: Temperament>(
+        &self,
+        pitches: &[Pitch<T>],
+    ) -> Option<VirtualPitch<T>> {
+        let frequencies: Vec<f64> = pitches.iter().map(Pitch::frequency).collect();
+        self.estimate(&frequencies)
+    }
+}
+
+/// Kinds of combination tone produced by two simultaneous frequencies `f1 < f2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CombinationKind {
+    /// `f2 - f1`.
+    Difference,
+    /// `2·f1 - f2`, the most audible distortion product.
+    CubicDifference,
+    /// `f1 + f2`.
+    Sum,
+}
+
+impl CombinationKind {
+    pub const ALL: [Self; 3] = [Self::Difference, Self::CubicDifference, Self::Sum];
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Difference => "difference",
+            Self::CubicDifference => "cubic difference",
+            Self::Sum => "sum",
+        }
+    }
+
+    /// Frequency produced by `a` and `b`, or `None` when it is not positive.
+    #[must_use]
+    pub fn frequency(self, a: f64, b: f64) -> Option<f64> {
+        let (low, high) = if a <= b { (a, b) } else { (b, a) };
+        let hz = match self {
+            Self::Difference => high - low,
+            Self::CubicDifference => 2.0 * low - high,
+            Self::Sum => low + high,
+        };
+        (hz > 0.0).then_some(hz)
+    }
+}
+
+/// A combination tone between two input frequencies.
+#[derive(Debug, PartialEq)]
+pub struct CombinationTone<T: Temperament> {
+    pub kind: CombinationKind,
+    /// Indices of the generating inputs.
+    pub pair: (usize, usize),
+    pub frequency: f64,
+    pub nearest: Pitch<T>,
+    pub cents: f64,
+}
+
+impl<T: Temperament> Clone for CombinationTone<T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+/// Combination tones of every pair of `frequencies`, pair by pair in [`CombinationKind::ALL`]
+/// order; vanishing tones (unisons, cubic differences at or beyond the octave) are skipped.
+#[must_use]
+pub fn combination_tones<T: Temperament>(frequencies: &[f64]) -> Vec<CombinationTone<T>> {
+    let mut tones = Vec::new();
+    for (i, a) in frequencies.iter().enumerate() {
+        for (j, b) in frequencies.iter().enumerate().skip(i + 1) {
+            for kind in CombinationKind::ALL {
+                let Some(frequency) = kind.frequency(*a, *b) else {
+                    continue;
+                };
+                if let Some((nearest, cents)) = Pitch::nearest_to_frequency(frequency) {
+                    tones.push(CombinationTone { kind, pair: (i, j), frequency, nearest, cents });
+                }
+            }
+        }
+    }
+    tones
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch::Pitch12;
+    use music_acoustic::T12;
+
+    fn pitch(name: &str) -> Pitch12 {
+        name.parse().expect("pitch")
+    }
+
+    #[test]
+    fn partials_land_on_the_grid_with_known_deviations() {
+        assert!((pitch("A4").frequency() - 440.0).abs() < 1e-9);
+        assert!((pitch("C4").frequency() - 261.625_565).abs() < 1e-5);
+        let partials = HarmonicSeries::of_pitch(&pitch("A2")).partials::<T12>(6);
+        let names: Vec<String> = partials.iter().map(|p| p.nearest.to_string()).collect();
+        assert_eq!(names, ["A2", "A3", "E4", "A4", "C#5", "E5"]);
+        // Just fifth +2 cents, just major third -14 cents.
+        assert!((partials[2].cents - 1.955).abs() < 0.01);
+        assert!((partials[4].cents + 13.686).abs() < 0.01);
+        assert_eq!(Pitch12::nearest_to_frequency(0.0), None);
+    }
+
+    #[test]
+    fn virtual_pitch_finds_the_implied_fundamental() {
+        let estimator = VirtualPitchEstimator::default();
+        let minor: Vec<Pitch12> = ["A3", "C4", "E4"].iter().map(|p| pitch(p)).collect();
+        let root = estimator.estimate_pitches(&minor).expect("fit");
+        assert_eq!(root.harmonics, [10, 12, 15]);
+        assert_eq!(root.nearest.to_string(), "F0");
+        let fifth = estimator.estimate::<T12>(&[200.0, 300.0]).expect("fit");
+        assert_eq!((fifth.frequency, fifth.harmonics.as_slice()), (100.0, &[2, 3][..]));
+        let strict = VirtualPitchEstimator { tolerance_cents: 1.0, ..estimator };
+        assert!(strict.estimate_pitches(&minor).is_none());
+    }
+
+    #[test]
+    fn combination_tones_of_a_fifth() {
+        let tones = combination_tones::<T12>(&[400.0, 600.0]);
+        let found: Vec<(CombinationKind, f64)> =
+            tones.iter().map(|tone| (tone.kind, tone.frequency)).collect();
+        assert_eq!(
+            found,
+            [
+                (CombinationKind::Difference, 200.0),
+                (CombinationKind::CubicDifference, 200.0),
+                (CombinationKind::Sum, 1000.0)
+            ]
+        );
+        assert_eq!(combination_tones::<T12>(&[300.0, 700.0]).len(), 2);
+        let unison = combination_tones::<T12>(&[440.0, 440.0]);
+        assert!(unison.iter().all(|tone| tone.kind != CombinationKind::Difference));
+    }
+}
@@ -0,0 +1,345 @@
+//! core/music-theory/src/harmonizer.rs
+//! Melody harmonization: choose one diatonic chord per harmonic-rhythm slot.
+//!
+//! [`MelodyHarmonizer`] cuts the melody into fixed slots (one bar by default) and scores every
+//! functional chord of the key (see [`Key12::functional_chords`]) in each slot on three criteria:
+//! - **coverage**: how much of the slot's melody, weighted by metric position through
+//!   [`Meter::metric_weight`], consists of chord tones;
+//! - **flow**: tonic → predominant → dominant → tonic motion is rewarded, retrogressions
+//!   (dominant → predominant) and static repetitions are penalized;
+//! - **cadence**: phrase ends favor I or V, the final slot favors an authentic cadence.
+//!
+//! A k-best Viterbi search returns the top alternatives, best first.
+//!
+//! ```
+//! use music_theory::{Key12, MelodyHarmonizer, MelodyNote, Pitch12, PitchClass12};
+//! use music_time::{Meter, TimePoint, TimeSpan};
+//! let note = |semitones, beat| {
+//!     let pitch = Pitch12::from_semitones_and_octave(semitones, 5);
+//!     MelodyNote::new(pitch, TimePoint::new(beat), TimeSpan::new(2.0))
+//! };
+//! // E–G | F–D | D–B | C (whole note) in C major.
+//! let mut melody = vec![note(4, 0.0), note(7, 2.0), note(5, 4.0), note(2, 6.0)];
+//! melody.extend([note(2, 8.0), note(11, 10.0), note(0, 12.0), note(0, 14.0)]);
+//! let key = Key12::major(PitchClass12::from_semitones(0));
+//! let results = MelodyHarmonizer::new(key, Meter::FOUR_FOUR).harmonize(&melody, 3);
+//! let best: Vec<String> = results[0].slots.iter().map(|s| s.roman.to_string()).collect();
+//! assert_eq!(best, ["I", "ii", "V", "I"]);
+//! assert_eq!(results.len(), 3);
+//! ```
+
+use music_acoustic::T12;
+use music_time::{Meter, TimePoint, TimeSpan};
+
+use crate::chord::Chord12;
+use crate::diatonic::{ChordDepth, DiatonicChord};
+use crate::key::Key12;
+use crate::melody::MelodyNote;
+use crate::roman::RomanNumeral;
+
+/// Weight of the chord-tone coverage score (0–1 before weighting).
+const COVERAGE_WEIGHT: f64 = 4.0;
+/// Penalty when the note sounding at the start of a slot is not a chord tone.
+const DOWNBEAT_CLASH: f64 = 1.5;
+/// Bonus for opening on the tonic.
+const TONIC_OPENING: f64 = 1.0;
+/// Bonus for ending the melody on the tonic.
+const FINAL_TONIC: f64 = 3.0;
+/// Bonus for I or V at an inner phrase end.
+const PHRASE_CADENCE: f64 = 1.0;
+/// Bonus for V (or vii) → I into a cadence slot.
+const AUTHENTIC_CADENCE: f64 = 1.5;
+
+/// Functional group of a scale-degree chord.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Group {
+    Tonic,
+    Predominant,
+    Dominant,
+}
+
+impl Group {
+    fn of(degree: u8) -> Self {
+        match degree {
+            2 | 4 => Self::Predominant,
+            5 | 7 => Self::Dominant,
+            _ => Self::Tonic,
+        }
+    }
+}
+
+/// Chord chosen for one harmonic-rhythm slot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HarmonizedSlot {
+    pub start: TimePoint,
+    pub span: TimeSpan,
+    /// Scale degree of the chord root (1–7).
+    pub degree: u8,
+    pub chord: Chord12,
+    pub roman: RomanNumeral,
+    /// Metrically weighted fraction of the slot's melody covered by chord tones (0–1).
+    pub coverage: f64,
+}
+
+/// One complete harmonization and its total score (higher is better).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Harmonization {
+    pub slots: Vec<HarmonizedSlot>,
+    pub score: f64,
+}
+
+/// Harmonizes melodies in a key and meter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MelodyHarmonizer {
+    pub key: Key12,
+    pub meter: Meter,
+    /// Harmonic rhythm: length of each chord slot (default one bar).
+    pub slot: TimeSpan,
+    /// Slots per phrase; the last slot of every phrase is a cadence point (default 4).
+    pub phrase_slots: usize,
+    /// Triads or seventh chords.
+    pub depth: ChordDepth,
+}
+
+impl MelodyHarmonizer {
+    /// One triad per bar with four-bar phrases.
+    #[must_use]
+    pub fn new(key: Key12, meter: Meter) -> Self {
+        Self { key, meter, slot: meter.bar_span(), phrase_slots: 4, depth: ChordDepth::Triad }
+    }
+
+    /// Up to `alternatives` harmonizations, best first; empty for an empty melody.
+    ///
+    /// Slots start at time zero (assumed to be a downbeat) and run until the last note ends.
+    #[must_use]
+    pub fn harmonize(&self, melody: &[MelodyNote], alternatives: usize) -> Vec<Harmonization> {
+        let end = melody.iter().map(MelodyNote::end).max().unwrap_or(TimePoint::zero());
+        if alternatives == 0 || melody.is_empty() || self.slot == TimeSpan::zero() {
+            return Vec::new();
+        }
+        let chords = self.key.functional_chords(self.depth);
+        let starts: Vec<TimePoint> = (0..slot_count(end, self.slot))
+            .map(|index| TimePoint::zero() + self.slot * index)
+            .collect();
+        let count = starts.len();
+        let coverage: Vec<Vec<(f64, bool)>> = starts
+            .iter()
+            .map(|start| chords.iter().map(|chord| self.coverage(melody, *start, chord)).collect())
+            .collect();
+
+        // paths[slot][chord] holds up to `alternatives` (score, previous chord, previous rank).
+        let mut paths: Vec<Vec<Vec<(f64, usize, usize)>>> = Vec::with_capacity(count);
+        for (index, slot_coverage) in coverage.iter().enumerate() {
+            let mut layer = Vec::with_capacity(chords.len());
+            for (current, chord) in chords.iter().enumerate() {
+                let local = self.local_score(index, count, chord, slot_coverage[current]);
+                let mut ranked: Vec<(f64, usize, usize)> = match index.checked_sub(1) {
+                    None => vec![(local, 0, 0)],
+                    Some(previous) => paths[previous]
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(from, ranks): (usize, &Vec<(f64, usize, usize)>)| {
+                            let flow = self.flow(index, count, &chords[from], chord);
+                            ranks
+                                .iter()
+                                .enumerate()
+                                .map(move |(rank, (score, ..))| (score + flow + local, from, rank))
+                        })
+                        .collect(),
+                };
+                ranked.sort_by(|a, b| b.0.total_cmp(&a.0));
+                ranked.truncate(alternatives);
+                layer.push(ranked);
+            }
+            paths.push(layer);
+        }
+
+        let Some(last) = paths.last() else {
+            return Vec::new();
+        };
+        let mut finals: Vec<(f64, usize, usize)> = last
+            .iter()
+            .enumerate()
+            .flat_map(|(state, ranks)| {
+                ranks.iter().enumerate().map(move |(rank, (score, ..))| (*score, state, rank))
+            })
+            .collect();
+        finals.sort_by(|a, b| b.0.total_cmp(&a.0));
+        finals.truncate(alternatives);
+        finals
+            .into_iter()
+            .map(|(score, mut state, mut rank)| {
+                let mut slots = Vec::with_capacity(count);
+                for index in (0..count).rev() {
+                    let chord = &chords[state];
+                    slots.push(HarmonizedSlot {
+                        start: starts[index],
+                        span: self.slot,
+                        degree: chord.degree,
+                        chord: chord.chord.clone(),
+                        roman: chord.roman,
+                        coverage: coverage[index][state].0,
+                    });
+                    let (_, previous, previous_rank) = paths[index][state][rank];
+                    (state, rank) = (previous, previous_rank);
+                }
+                slots.reverse();
+                Harmonization { slots, score }
+            })
+            .collect()
+    }
+
+    /// Weighted chord-tone coverage of a slot and whether its opening note clashes.
+    fn coverage(
+        &self,
+        melody: &[MelodyNote],
+        start: TimePoint,
+        chord: &DiatonicChord<T12>,
+    ) -> (f64, bool) {
+        let end = start + self.slot;
+        let (mut covered, mut total, mut clash) = (0.0, 0.0, false);
+        for note in melody {
+            let overlap = note.overlap(start, end);
+            if overlap == TimeSpan::zero() {
+                continue;
+            }
+            let weight = if note.onset >= start {
+                self.meter.metric_weight(note.onset - TimePoint::zero())
+            } else {
+                0.5
+            };
+            let overlap = overlap.as_beats();
+            let tone = chord.chord.contains(note.pitch_class());
+            total += overlap * weight;
+            if tone {
+                covered += overlap * weight;
+            } else if note.onset <= start {
+                clash = true;
+            }
+        }
+        if total > 0.0 { (covered / total, clash) } else { (0.5, false) }
+    }
+
+    /// Score of a chord in a slot, independent of its neighbors.
+    fn local_score(
+        &self,
+        index: usize,
+        count: usize,
+        chord: &DiatonicChord<T12>,
+        (coverage, clash): (f64, bool),
+    ) -> f64 {
+        let mut score = COVERAGE_WEIGHT * coverage;
+        if clash {
+            score -= DOWNBEAT_CLASH;
+        }
+        let group = Group::of(chord.degree);
+        if index == 0 && chord.degree == 1 {
+            score += TONIC_OPENING;
+        }
+        match chord.degree {
+            3 => score -= 0.25,
+            7 => score -= 0.5,
+            _ => {}
+        }
+        if index + 1 == count {
+            if chord.degree == 1 {
+                score += FINAL_TONIC;
+            }
+        } else if self.is_phrase_end(index) && (chord.degree == 1 || group == Group::Dominant) {
+            score += PHRASE_CADENCE;
+        }
+        score
+    }
+
+    /// Functional-flow score for moving between two chords into slot `index`.
+    fn flow(
+        &self,
+        index: usize,
+        count: usize,
+        from: &DiatonicChord<T12>,
+        to: &DiatonicChord<T12>,
+    ) -> f64 {
+        if from.degree == to.degree {
+            return -0.5;
+        }
+        let mut score = match (Group::of(from.degree), Group::of(to.degree)) {
+            (Group::Tonic, Group::Predominant)
+            | (Group::Predominant, Group::Dominant)
+            | (Group::Dominant, Group::Tonic) => 1.0,
+            (Group::Tonic, Group::Dominant | Group::Tonic) => 0.5,
+            (Group::Predominant, Group::Predominant | Group::Tonic) => 0.25,
+            (Group::Dominant, Group::Dominant) => 0.0,
+            (Group::Dominant, Group::Predominant) => -1.0,
+        };
+        let cadence = index + 1 == count || self.is_phrase_end(index);
+        if cadence && to.degree == 1 && Group::of(from.degree) == Group::Dominant {
+            score += AUTHENTIC_CADENCE;
+        }
+        score
+    }
+
+    fn is_phrase_end(&self, index: usize) -> bool {
+        self.phrase_slots > 0 && (index + 1).is_multiple_of(self.phrase_slots)
+    }
+}
+
+/// Number of slots needed to reach `end` (at least one); `slot` must be non-zero.
+fn slot_count(end: TimePoint, slot: TimeSpan) -> u32 {
+    let slots = (end.as_rational() / slot.as_rational()).ceil();
+    u32::try_from(slots.max(1)).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch::{Pitch12, PitchClass12};
+
+    fn note(semitones: u16, onset: f64, duration: f64) -> MelodyNote {
+        let pitch = Pitch12::from_semitones_and_octave(semitones, 5);
+        MelodyNote::new(pitch, TimePoint::new(onset), TimeSpan::new(duration))
+    }
+
+    fn romans(harmonization: &Harmonization) -> Vec<String> {
+        harmonization.slots.iter().map(|slot| slot.roman.to_string()).collect()
+    }
+
+    #[test]
+    fn strong_beats_choose_the_chord() {
+        // 3/4, A minor: the downbeat C pulls toward a chord containing C despite the passing B.
+        let key = Key12::minor(PitchClass12::from_semitones(9));
+        let melody = [
+            note(0, 0.0, 2.0),
+            note(11, 2.0, 1.0),
+            note(11, 3.0, 2.0),
+            note(4, 5.0, 1.0),
+            note(9, 6.0, 3.0),
+        ];
+        let results = MelodyHarmonizer::new(key, Meter::THREE_FOUR).harmonize(&melody, 4);
+        assert_eq!(romans(&results[0]), ["i", "V", "i"]);
+        assert!(results[0].slots.iter().all(|slot| slot.coverage > 0.5));
+        assert!(results.windows(2).all(|pair| pair[0].score >= pair[1].score));
+        assert_eq!(results.len(), 4);
+        assert_ne!(romans(&results[0]), romans(&results[1]));
+    }
+
+    #[test]
+    fn harmonic_rhythm_and_phrases_are_configurable() {
+        let key = Key12::major(PitchClass12::from_semitones(7));
+        let melody: Vec<MelodyNote> = [11, 9, 7, 6, 4, 2, 6, 7]
+            .into_iter()
+            .enumerate()
+            .map(|(index, semitones)| note(semitones, index as f64 * 2.0, 2.0))
+            .collect();
+        let harmonizer = MelodyHarmonizer {
+            slot: TimeSpan::new(2.0),
+            phrase_slots: 4,
+            ..MelodyHarmonizer::new(key, Meter::FOUR_FOUR)
+        };
+        let best = &harmonizer.harmonize(&melody, 1)[0];
+        assert_eq!(best.slots.len(), 8);
+        assert_eq!(best.slots[7].degree, 1);
+        assert!(matches!(best.slots[3].degree, 1 | 5 | 7), "{:?}", romans(best));
+        assert!((best.slots[5].start.as_beats() - 10.0).abs() < 1e-9);
+        assert!(harmonizer.harmonize(&[], 3).is_empty());
+    }
+}
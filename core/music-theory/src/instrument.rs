@@ -0,0 +1,384 @@
+//! core/music-theory/src/instrument.rs
+//! Instrument catalog: ranges, transpositions, clefs, and polyphony.
+//!
+//! Ranges are given in sounding (concert) pitch. A transposing instrument's
+//! [`Instrument::transposition`] is the interval from written to sounding pitch, so a B♭
+//! clarinet is `-2` (written D sounds C) and a horn in F is `-7`; octave-transposing
+//! instruments (guitar, double bass) are `-12`.
+//!
+//! ```
+//! use music_theory::{Instrument, Pitch12, RangeFit};
+//! let concert_c = Pitch12::from_semitones_and_octave(0, 4);
+//! let written = Instrument::AltoSax.to_written(concert_c);
+//! assert_eq!(written.to_string(), "A4");
+//! assert_eq!(Instrument::AltoSax.to_concert(written), concert_c);
+//! assert_eq!(Instrument::Flute.fit(concert_c), RangeFit::Extreme);
+//! ```
+
+use core::fmt;
+
+use crate::interval::Interval12;
+use crate::key::Key12;
+use crate::pitch::Pitch12;
+
+/// Staff clef an instrument is normally written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Clef {
+    Treble,
+    /// Treble clef sounding an octave lower (tenor voice). Octave-transposing instruments such
+    /// as guitar carry the octave in their transposition and use plain [`Clef::Treble`].
+    TrebleOctaveDown,
+    Alto,
+    Tenor,
+    Bass,
+    /// Treble and bass staves braced together (keyboards).
+    Grand,
+}
+
+impl Clef {
+    /// Lower-case clef name.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Treble => "treble",
+            Self::TrebleOctaveDown => "treble 8vb",
+            Self::Alto => "alto",
+            Self::Tenor => "tenor",
+            Self::Bass => "bass",
+            Self::Grand => "grand staff",
+        }
+    }
+}
+
+impl fmt::Display for Clef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// How well a pitch (or the worst pitch of a line or voicing) sits on an instrument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum RangeFit {
+    Comfortable,
+    /// Playable, but only at the edges of the instrument's range.
+    Extreme,
+    OutOfRange,
+}
+
+/// A standard orchestral, band, or vocal instrument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Instrument {
+    Piano,
+    Guitar,
+    Violin,
+    Viola,
+    Cello,
+    DoubleBass,
+    Flute,
+    Oboe,
+    /// Clarinet in B♭.
+    Clarinet,
+    Bassoon,
+    /// Alto saxophone in E♭.
+    AltoSax,
+    /// Tenor saxophone in B♭.
+    TenorSax,
+    /// Trumpet in B♭.
+    Trumpet,
+    /// Horn in F.
+    Horn,
+    Trombone,
+    Tuba,
+    Soprano,
+    Alto,
+    Tenor,
+    Bass,
+}
+
+impl Instrument {
+    pub const ALL: [Self; 20] = [
+        Self::Piano,
+        Self::Guitar,
+        Self::Violin,
+        Self::Viola,
+        Self::Cello,
+        Self::DoubleBass,
+        Self::Flute,
+        Self::Oboe,
+        Self::Clarinet,
+        Self::Bassoon,
+        Self::AltoSax,
+        Self::TenorSax,
+        Self::Trumpet,
+        Self::Horn,
+        Self::Trombone,
+        Self::Tuba,
+        Self::Soprano,
+        Self::Alto,
+        Self::Tenor,
+        Self::Bass,
+    ];
+
+    /// Display name.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Piano => "piano",
+            Self::Guitar => "guitar",
+            Self::Violin => "violin",
+            Self::Viola => "viola",
+            Self::Cello => "cello",
+            Self::DoubleBass => "double bass",
+            Self::Flute => "flute",
+            Self::Oboe => "oboe",
+            Self::Clarinet => "clarinet in Bb",
+            Self::Bassoon => "bassoon",
+            Self::AltoSax => "alto saxophone",
+            Self::TenorSax => "tenor saxophone",
+            Self::Trumpet => "trumpet in Bb",
+            Self::Horn => "horn in F",
+            Self::Trombone => "trombone",
+            Self::Tuba => "tuba",
+            Self::Soprano => "soprano",
+            Self::Alto => "alto",
+            Self::Tenor => "tenor",
+            Self::Bass => "bass",
+        }
+    }
+
+    /// Semitones from written to sounding pitch (`0` for concert-pitch instruments).
+    #[must_use]
+    pub const fn transposition_semitones(self) -> i32 {
+        match self {
+            Self::Clarinet | Self::Trumpet => -2,
+            Self::AltoSax => -9,
+            Self::TenorSax => -14,
+            Self::Horn => -7,
+            Self::Guitar | Self::DoubleBass => -12,
+            _ => 0,
+        }
+    }
+
+    /// Interval from written to sounding pitch.
+    #[must_use]
+    pub fn transposition(self) -> Interval12 {
+        Interval12::from_semitones(self.transposition_semitones())
+    }
+
+    /// Whether written and sounding pitch differ.
+    #[must_use]
+    pub const fn is_transposing(self) -> bool {
+        self.transposition_semitones() != 0
+    }
+
+    /// Full playable range `(lowest, highest)` in sounding pitch.
+    #[must_use]
+    pub fn range(self) -> (Pitch12, Pitch12) {
+        let pitch = Pitch12::from_semitones_and_octave;
+        match self {
+            Self::Piano => (pitch(9, 0), pitch(0, 8)),
+            Self::Guitar => (pitch(4, 2), pitch(11, 5)),
+            Self::Violin => (pitch(7, 3), pitch(9, 7)),
+            Self::Viola => (pitch(0, 3), pitch(4, 6)),
+            Self::Cello => (pitch(0, 2), pitch(9, 5)),
+            Self::DoubleBass => (pitch(4, 1), pitch(7, 4)),
+            Self::Flute => (pitch(0, 4), pitch(2, 7)),
+            Self::Oboe => (pitch(10, 3), pitch(9, 6)),
+            Self::Clarinet => (pitch(2, 3), pitch(10, 6)),
+            Self::Bassoon => (pitch(10, 1), pitch(4, 5)),
+            Self::AltoSax => (pitch(1, 3), pitch(9, 5)),
+            Self::TenorSax => (pitch(8, 2), pitch(4, 5)),
+            Self::Trumpet => (pitch(4, 3), pitch(0, 6)),
+            Self::Horn => (pitch(11, 1), pitch(5, 5)),
+            Self::Trombone => (pitch(4, 2), pitch(5, 5)),
+            Self::Tuba => (pitch(2, 1), pitch(5, 4)),
+            Self::Soprano => (pitch(11, 3), pitch(0, 6)),
+            Self::Alto => (pitch(5, 3), pitch(5, 5)),
+            Self::Tenor => (pitch(0, 3), pitch(0, 5)),
+            Self::Bass => (pitch(4, 2), pitch(4, 4)),
+        }
+    }
+
+    /// Comfortable range `(lowest, highest)` in sounding pitch.
+    ///
+    /// Vocal comfortable ranges match [`Voice::default_range`](crate::Voice::default_range).
+    #[must_use]
+    pub fn comfortable_range(self) -> (Pitch12, Pitch12) {
+        let pitch = Pitch12::from_semitones_and_octave;
+        match self {
+            Self::Piano => (pitch(0, 1), pitch(0, 7)),
+            Self::Guitar => (pitch(4, 2), pitch(4, 5)),
+            Self::Violin => (pitch(7, 3), pitch(4, 6)),
+            Self::Viola => (pitch(0, 3), pitch(9, 5)),
+            Self::Cello => (pitch(0, 2), pitch(9, 4)),
+            Self::DoubleBass => (pitch(4, 1), pitch(7, 3)),
+            Self::Flute => (pitch(2, 4), pitch(7, 6)),
+            Self::Oboe => (pitch(0, 4), pitch(4, 6)),
+            Self::Clarinet => (pitch(2, 3), pitch(10, 5)),
+            Self::Bassoon => (pitch(0, 2), pitch(10, 4)),
+            Self::AltoSax => (pitch(3, 3), pitch(3, 5)),
+            Self::TenorSax => (pitch(10, 2), pitch(10, 4)),
+            Self::Trumpet => (pitch(10, 3), pitch(5, 5)),
+            Self::Horn => (pitch(5, 3), pitch(0, 5)),
+            Self::Trombone => (pitch(10, 2), pitch(5, 4)),
+            Self::Tuba => (pitch(5, 1), pitch(5, 3)),
+            Self::Soprano => (pitch(0, 4), pitch(7, 5)),
+            Self::Alto => (pitch(7, 3), pitch(2, 5)),
+            Self::Tenor => (pitch(0, 3), pitch(7, 4)),
+            Self::Bass => (pitch(4, 2), pitch(0, 4)),
+        }
+    }
+
+    /// Clef the instrument's part is normally written in.
+    #[must_use]
+    pub const fn clef(self) -> Clef {
+        match self {
+            Self::Piano => Clef::Grand,
+            Self::Tenor => Clef::TrebleOctaveDown,
+            Self::Viola => Clef::Alto,
+            Self::Cello
+            | Self::DoubleBass
+            | Self::Bassoon
+            | Self::Trombone
+            | Self::Tuba
+            | Self::Bass => Clef::Bass,
+            _ => Clef::Treble,
+        }
+    }
+
+    /// Maximum simultaneous notes (double stops for strings, one for winds and voices).
+    #[must_use]
+    pub const fn polyphony(self) -> usize {
+        match self {
+            Self::Piano => 10,
+            Self::Guitar => 6,
+            Self::Violin | Self::Viola | Self::Cello | Self::DoubleBass => 2,
+            _ => 1,
+        }
+    }
+
+    /// Written pitch for a concert pitch.
+    #[must_use]
+    pub fn to_written(self, concert: Pitch12) -> Pitch12 {
+        Pitch12::from_absolute_steps(concert.absolute_steps() - self.transposition_semitones())
+    }
+
+    /// Concert (sounding) pitch for a written pitch.
+    #[must_use]
+    pub fn to_concert(self, written: Pitch12) -> Pitch12 {
+        Pitch12::from_absolute_steps(written.absolute_steps() + self.transposition_semitones())
+    }
+
+    /// Written key for a concert key (C major concert is D major for a B♭ clarinet).
+    #[must_use]
+    pub fn written_key(self, concert: Key12) -> Key12 {
+        Key12::new(
+            concert.tonic.transpose(Interval12::from_semitones(-self.transposition_semitones())),
+            concert.mode,
+        )
+    }
+
+    /// How well a concert pitch sits on the instrument.
+    #[must_use]
+    pub fn fit(self, concert: Pitch12) -> RangeFit {
+        let within = |(low, high): (Pitch12, Pitch12)| {
+            (low.absolute_steps()..=high.absolute_steps()).contains(&concert.absolute_steps())
+        };
+        if within(self.comfortable_range()) {
+            RangeFit::Comfortable
+        } else if within(self.range()) {
+            RangeFit::Extreme
+        } else {
+            RangeFit::OutOfRange
+        }
+    }
+
+    /// Worst fit over a melodic line in concert pitch (`Comfortable` for an empty line).
+    #[must_use]
+    pub fn fit_line(self, line: &[Pitch12]) -> RangeFit {
+        line.iter().map(|pitch| self.fit(*pitch)).max().unwrap_or(RangeFit::Comfortable)
+    }
+
+    /// Worst fit over a simultaneous voicing, or `None` if it needs more notes than the
+    /// instrument's [`polyphony`](Self::polyphony).
+    #[must_use]
+    pub fn fit_voicing(self, voicing: &[Pitch12]) -> Option<RangeFit> {
+        (voicing.len() <= self.polyphony()).then(|| self.fit_line(voicing))
+    }
+}
+
+impl fmt::Display for Instrument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch::PitchClass12;
+    use crate::satb::Voice;
+
+    fn p(semitones: u16, octave: i16) -> Pitch12 {
+        Pitch12::from_semitones_and_octave(semitones, octave)
+    }
+
+    #[test]
+    fn transposing_instruments_round_trip() {
+        let concert_c = p(0, 4);
+        assert_eq!(Instrument::Clarinet.to_written(concert_c), p(2, 4));
+        assert_eq!(Instrument::Horn.to_written(concert_c), p(7, 4));
+        assert_eq!(Instrument::TenorSax.to_written(concert_c), p(2, 5));
+        assert_eq!(Instrument::Guitar.to_written(p(4, 2)), p(4, 3));
+        for instrument in Instrument::ALL {
+            assert_eq!(instrument.to_concert(instrument.to_written(concert_c)), concert_c);
+        }
+        let concert = Key12::major(PitchClass12::from_semitones(3));
+        assert_eq!(Instrument::AltoSax.written_key(concert).tonic, PitchClass12::from_semitones(0));
+        assert!(!Instrument::Flute.is_transposing());
+    }
+
+    #[test]
+    fn ranges_are_consistent() {
+        for instrument in Instrument::ALL {
+            let (low, high) = instrument.range();
+            let (comfortable_low, comfortable_high) = instrument.comfortable_range();
+            assert!(low.absolute_steps() <= comfortable_low.absolute_steps(), "{instrument}");
+            assert!(comfortable_high.absolute_steps() <= high.absolute_steps(), "{instrument}");
+        }
+        assert_eq!(Instrument::Soprano.comfortable_range(), Voice::Soprano.default_range());
+        assert_eq!(Instrument::Bass.comfortable_range(), Voice::Bass.default_range());
+        assert_eq!(Instrument::Viola.clef(), Clef::Alto);
+    }
+
+    #[test]
+    fn clefs_and_transpositions_count_the_octave_once() {
+        // Guitar written E3 sounds E2: the octave is in the transposition, not the clef.
+        assert_eq!(Instrument::Guitar.clef(), Clef::Treble);
+        assert_eq!(Instrument::Guitar.to_concert(p(4, 3)), p(4, 2));
+        assert_eq!(Instrument::Tenor.clef(), Clef::TrebleOctaveDown);
+        assert!(!Instrument::Tenor.is_transposing());
+        for instrument in Instrument::ALL {
+            let octave_down = instrument.transposition_semitones() <= -12;
+            let clef_down = instrument.clef() == Clef::TrebleOctaveDown;
+            assert!(!(octave_down && clef_down), "{instrument}");
+        }
+    }
+
+    #[test]
+    fn lines_and_voicings_fit() {
+        assert_eq!(Instrument::Trumpet.fit(p(0, 5)), RangeFit::Comfortable);
+        assert_eq!(Instrument::Trumpet.fit(p(10, 5)), RangeFit::Extreme);
+        assert_eq!(Instrument::Violin.fit(p(0, 3)), RangeFit::OutOfRange);
+        assert_eq!(Instrument::Flute.fit_line(&[p(0, 5), p(0, 4)]), RangeFit::Extreme);
+        assert_eq!(Instrument::Cello.fit_voicing(&[p(0, 2), p(7, 2)]), Some(RangeFit::Comfortable));
+        assert_eq!(Instrument::Cello.fit_voicing(&[p(0, 2), p(7, 2), p(4, 3)]), None);
+        assert_eq!(Instrument::Piano.fit_voicing(&[]), Some(RangeFit::Comfortable));
+    }
+}
@@ -10,12 +10,20 @@ use core::ops::{Add, Sub};
 use music_acoustic::{T12, Temperament};
 
 /// Generic interval in a temperament: step distance.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct Interval<T: Temperament> {
     steps: i32,
     _marker: core::marker::PhantomData<T>,
 }
 
+impl<T: Temperament> Copy for Interval<T> {}
+
+impl<T: Temperament> Clone for Interval<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
 impl<T: Temperament> Interval<T> {
     /// Construct an interval from a step distance (signed).
     #[must_use]
@@ -0,0 +1,250 @@
+//! core/music-theory/src/inversion.rs
+//! Roots, bass notes, inversions, and slash-chord reinterpretation for [`Chord`].
+//!
+//! A chord stores its root, the sounding bass when it differs, and the resulting inversion
+//! index. A bass that is a chord tone yields an inversion (`C/E` is a first-inversion C major
+//! triad); a foreign bass yields a slash chord (`D/C`), which
+//! [`Chord::identify_inversion`] can reinterpret as a larger chord in inversion (`D7/C`).
+//!
+//! ```
+//! use music_theory::{Chord12, ChordKind, PitchClass12, SeventhKind};
+//! let pc = PitchClass12::from_semitones;
+//! let first_inversion = Chord12::major_triad(pc(0)).invert(1);
+//! assert_eq!(first_inversion.sounding_bass(), Some(pc(4)));
+//! assert_eq!(first_inversion.inversion(), Some(1));
+//!
+//! let d_over_c = Chord12::major_triad(pc(2)).with_bass(pc(0));
+//! assert_eq!(d_over_c.inversion(), None);
+//! let (d7, kind) = d_over_c.identify_inversion().unwrap();
+//! assert_eq!(kind, ChordKind::Seventh(SeventhKind::Dominant7));
+//! assert_eq!(d7.inversion(), Some(3));
+//! ```
+
+use music_acoustic::Temperament;
+
+use crate::chord::{Chord, ChordKind};
+use crate::pitch::{Pitch, PitchClass};
+
+impl<T: Temperament> Chord<T> {
+    /// Root of the chord (the first tone unless re-rooted).
+    #[must_use]
+    pub fn root(&self) -> Option<PitchClass<T>> {
+        self.root
+    }
+
+    /// Explicit bass when it differs from the root.
+    #[must_use]
+    pub fn bass(&self) -> Option<PitchClass<T>> {
+        self.bass
+    }
+
+    /// Tones starting from the root, the rest following in stored order.
+    #[must_use]
+    pub fn root_position(&self) -> Vec<PitchClass<T>> {
+        let start = self.root.and_then(|root| self.tones.iter().position(|tone| *tone == root));
+        let mut tones = self.tones.clone();
+        tones.rotate_left(start.unwrap_or(0));
+        tones
+    }
+
+    /// Lowest sounding pitch-class: the explicit bass, or the root in root position.
+    #[must_use]
+    pub fn sounding_bass(&self) -> Option<PitchClass<T>> {
+        self.bass.or_else(|| self.root())
+    }
+
+    /// Place `bass` under the chord; a bass equal to the root restores root position.
+    #[must_use]
+    pub fn with_bass(mut self, bass: PitchClass<T>) -> Self {
+        self.bass = (self.root != Some(bass)).then_some(bass);
+        self.sync_inversion();
+        self
+    }
+
+    /// Drop any explicit bass, returning the chord to root position.
+    #[must_use]
+    pub fn without_bass(mut self) -> Self {
+        self.bass = None;
+        self.sync_inversion();
+        self
+    }
+
+    /// Distinct pitch classes sounding in the chord, the sounding bass first.
+    pub(crate) fn pitch_class_set(&self) -> Vec<PitchClass<T>> {
+        let mut members: Vec<PitchClass<T>> = Vec::with_capacity(self.tones.len() + 1);
+        for tone in self.sounding_bass().into_iter().chain(self.tones.iter().copied()) {
+            if !members.contains(&tone) {
+                members.push(tone);
+            }
+        }
+        members
+    }
+
+    /// Whether both chords sound the same pitch classes, regardless of order or bass.
+    pub(crate) fn same_pitch_classes(&self, other: &Self) -> bool {
+        let (ours, theirs) = (self.pitch_class_set(), other.pitch_class_set());
+        ours.len() == theirs.len() && ours.iter().all(|tone| theirs.contains(tone))
+    }
+
+    /// Whether the sounding bass differs from the root.
+    #[must_use]
+    pub fn is_slash(&self) -> bool {
+        self.bass.is_some_and(|bass| self.root != Some(bass))
+    }
+
+    /// Inversion index: `0` for root position, `1` when the tone after the root is in the
+    /// bass, ...
+    ///
+    /// Returns `None` for empty chords and for slash chords whose bass is not a chord tone.
+    #[must_use]
+    pub fn inversion(&self) -> Option<usize> {
+        self.inversion
+    }
+
+    /// Put the root-position tone at `inversion` (wrapping around the tone count) in the bass.
+    #[must_use]
+    pub fn invert(self, inversion: usize) -> Self {
+        match self.root_position().get(inversion % self.tones.len().max(1)).copied() {
+            Some(bass) => self.with_bass(bass),
+            None => self,
+        }
+    }
+
+    /// Reinterpret the chord with another chord tone as root, keeping the sounding bass.
+    ///
+    /// `C6` re-rooted on A reads as `Am7/C` in first inversion; `tones` are left as they are.
+    /// Returns `None` when `root` is not a chord tone.
+    #[must_use]
+    pub fn reroot(&self, root: PitchClass<T>) -> Option<Self> {
+        if !self.contains(root) {
+            return None;
+        }
+        let bass = self.sounding_bass();
+        let mut chord = Self { root: Some(root), bass: None, ..self.clone() };
+        Some(match bass {
+            Some(bass) => chord.with_bass(bass),
+            None => {
+                chord.sync_inversion();
+                chord
+            }
+        })
+    }
+
+    /// Recompute the inversion index from the root and sounding bass.
+    fn sync_inversion(&mut self) {
+        self.inversion = self
+            .sounding_bass()
+            .and_then(|bass| self.root_position().iter().position(|tone| *tone == bass));
+    }
+
+    /// Close-position voicing with the root in `octave` and the upper tones stacked above it.
+    ///
+    /// A slash bass is placed directly below the root, doubling the chord tone for inversions.
+    #[must_use]
+    pub fn close_voicing(&self, octave: i16) -> Vec<Pitch<T>> {
+        let Some(root) = self.root() else {
+            return Vec::new();
+        };
+        let base = Pitch::new(root, octave).absolute_steps();
+        let steps = i32::from(T::STEPS_PER_OCTAVE);
+        let bass = self.bass.filter(|bass| *bass != root).map(|bass| {
+            let below = (i32::from(root.index()) - i32::from(bass.index())).rem_euclid(steps);
+            Pitch::from_absolute_steps(base - below)
+        });
+        bass.into_iter()
+            .chain(
+                self.stacked_intervals()
+                    .into_iter()
+                    .map(|offset| Pitch::from_absolute_steps(base + offset)),
+            )
+            .collect()
+    }
+
+    /// Explain the sonority (bass included) as a canonical [`ChordKind`] over some root.
+    ///
+    /// The sounding bass is tried as root first, so `C/A` reads as root-position `Am7` while
+    /// `D/C` becomes `D7` in third inversion. The returned chord is spelled in root-position
+    /// order with the original bass kept.
+    #[must_use]
+    pub fn identify_inversion(&self) -> Option<(Self, ChordKind)> {
+        self.pitch_class_set().iter().find_map(|root| {
+            ChordKind::ALL.into_iter().find_map(|kind| Some((self.spelled_as(*root, kind)?, kind)))
+        })
+    }
+
+    /// `kind` over `root` in root-position order with the sounding bass kept, if it spells
+    /// exactly the chord's pitch classes.
+    pub(crate) fn spelled_as(&self, root: PitchClass<T>, kind: ChordKind) -> Option<Self> {
+        let bass = self.sounding_bass()?;
+        let members = self.pitch_class_set();
+        let per_semitone = i32::from(T::STEPS_PER_OCTAVE) / 12;
+        if per_semitone == 0 || i32::from(T::STEPS_PER_OCTAVE) % 12 != 0 {
+            return None;
+        }
+        let steps: Vec<i32> = kind.intervals().iter().map(|s| s * per_semitone).collect();
+        let candidate = Self::from_intervals(root, &steps);
+        let same_set = candidate.tones.len() == members.len()
+            && members.iter().all(|tone| candidate.contains(*tone));
+        same_set.then(|| candidate.with_bass(bass))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::chord::{Chord12, ChordKind, SeventhKind, TriadKind};
+    use crate::pitch::{Pitch12, PitchClass12};
+
+    fn pc(semitones: u16) -> PitchClass12 {
+        PitchClass12::from_semitones(semitones)
+    }
+
+    #[test]
+    fn inversions_cycle_through_chord_tones() {
+        let g7 = Chord12::dominant_seventh(pc(7));
+        assert_eq!(g7.inversion(), Some(0));
+        assert!(!g7.is_slash());
+        let inversions: Vec<_> = (0..5).map(|n| g7.clone().invert(n).inversion()).collect();
+        assert_eq!(inversions, [Some(0), Some(1), Some(2), Some(3), Some(0)]);
+        assert_eq!(g7.clone().invert(3).sounding_bass(), Some(pc(5)));
+        assert_eq!(g7.clone().invert(2).without_bass(), g7);
+        assert_eq!(Chord12::major_triad(pc(0)).with_bass(pc(0)).bass, None);
+    }
+
+    #[test]
+    fn reroot_keeps_the_sounding_bass() {
+        let c6 = Chord12::from_intervals(pc(0), &[0, 4, 7, 9]);
+        let a_minor = c6.reroot(pc(9)).expect("A is a chord tone");
+        assert_eq!(a_minor.tones, c6.tones);
+        assert_eq!(a_minor.root(), Some(pc(9)));
+        assert_eq!(a_minor.root_position(), [pc(9), pc(0), pc(4), pc(7)]);
+        assert_eq!(a_minor.kind(), Some(ChordKind::Seventh(SeventhKind::Minor7)));
+        assert_eq!(a_minor.sounding_bass(), Some(pc(0)));
+        assert_eq!(a_minor.inversion(), Some(1));
+        assert!(c6.reroot(pc(1)).is_none());
+        let back = a_minor.reroot(pc(0)).expect("C is a chord tone");
+        assert_eq!((back.root(), back.bass(), back.inversion()), (Some(pc(0)), None, Some(0)));
+    }
+
+    #[test]
+    fn slash_chords_resolve_to_inversions() {
+        let c_over_e = Chord12::major_triad(pc(0)).with_bass(pc(4));
+        let (chord, kind) = c_over_e.identify_inversion().expect("C/E");
+        assert_eq!((chord.root(), kind), (Some(pc(0)), ChordKind::Triad(TriadKind::Major)));
+        assert_eq!(chord.inversion(), Some(1));
+
+        let c_over_a = Chord12::major_triad(pc(0)).with_bass(pc(9));
+        let (chord, kind) = c_over_a.identify_inversion().expect("Am7");
+        assert_eq!((chord.root(), kind), (Some(pc(9)), ChordKind::Seventh(SeventhKind::Minor7)));
+        assert!(!chord.is_slash());
+
+        let cluster = Chord12::from_intervals(pc(0), &[0, 1, 2]).with_bass(pc(6));
+        assert!(cluster.identify_inversion().is_none());
+    }
+
+    #[test]
+    fn close_voicing_stacks_upward_over_the_bass() {
+        let voiced = Chord12::dominant_seventh(pc(7)).invert(3).close_voicing(3);
+        let expected = [(5, 3), (7, 3), (11, 3), (2, 4), (5, 4)];
+        assert_eq!(voiced, expected.map(|(s, o)| Pitch12::from_semitones_and_octave(s, o)));
+    }
+}
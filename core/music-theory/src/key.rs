@@ -20,6 +20,8 @@ use music_acoustic::{T12, Temperament};
 
 /// Mode descriptor (major/minor and extensions later).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Mode {
     Major,
     Minor,
@@ -6,10 +6,52 @@
 //!   (12-TET, 24-TET, etc.) can share the same APIs.
 //! - [`Interval`] describes abstract step distances that drive transposition and scale construction.
 //! - [`Scale`] derives ordered pitch collections from step patterns and feeds degree lookups for
-//!   chords and keys.
-//! - [`Chord`] composes tertian/extended sonorities from root intervals.
+//!   chords and keys; [`ScaleDegree`] adds altered/compound degrees and scale-step transposition.
+//! - [`Chord`] composes tertian/extended sonorities and quartal/quintal stacks from root
+//!   intervals, with optional slash basses and inversions; [`CompoundChord`] names hybrid chords
+//!   (`F/G`) and polychords (`D|C`) and splits them into component chords.
 //! - [`Key`] and [`HarmonicFunction`] tie everything together by mapping scale degrees to tonal
 //!   functions (tonic, subdominant, dominant, ...).
+//! - [`Scale::harmonize`] and [`Key::diatonic_chords`] stack scale degrees into [`DiatonicChord`]s
+//!   labeled with [`RomanNumeral`]s.
+//! - [`Scale::mode_info`] finds a mode's [`ScaleFamily`] and parent collection,
+//!   [`Scale::parallel_modes`] orders sibling modes by brightness, and
+//!   [`Key::modal_interchange`] tabulates chords borrowed from parallel modes.
+//! - [`Axis`] reflects pitch classes, chords, and lines (negative harmony around a key's
+//!   tonic–dominant midpoint, or any axis) and re-identifies the mirrored chords.
+//! - [`ToneRow`] validates twelve-tone rows and derives their P/I/R/RI forms, serial matrix,
+//!   hexachordal combinatoriality, and trichordal (or other) derivation.
+//! - [`Progression`] catalogs named progressions (ii–V–I, rhythm changes, blues forms, ...) as
+//!   Roman-numeral templates, realizes them in any key, and finds them in chord sequences.
+//! - [`Chord::voice_leading_distance`], [`Chord12::tonal_pitch_space_distance`] (Lerdahl), and
+//!   [`ProgressionAlignment`] measure how far apart chords and whole progressions are.
+//! - [`ChordQuery`] searches the [`ChordKind`] tables for chords containing a pitch-class set.
+//! - [`SatbChord`] models four-voice textures and [`PartWritingChecker`] flags part-writing
+//!   errors (parallels, crossing, spacing, unresolved tendency tones, ...).
+//! - [`FourPartRealizer`] voices chords, Roman numerals, or a [`FiguredBass`] line in four parts,
+//!   choosing the smoothest rule-abiding voicings.
+//! - [`MelodyHarmonizer`] picks N-best chord progressions for a timed [`MelodyNote`] line using
+//!   `music_time` meters for metric weighting; [`NonChordToneAnalyzer`] labels each note as a
+//!   chord tone, passing tone, suspension, ... against the active chord.
+//! - [`Motif`] pairs scale steps or intervals with rhythms and supports inversion, retrograde,
+//!   augmentation, fragmentation, sequences, and metric displacement.
+//! - [`ExerciseGenerator`] produces seeded ear-training items (intervals, chord qualities,
+//!   scales and modes, progression and melodic dictation) with answer keys and MIDI rendering.
+//! - [`Fretboard`] maps pitches to string/fret positions, finds playable chord shapes, and
+//!   fingers melodic lines as tablature.
+//! - [`Instrument`] catalogs ranges, written/sounding transpositions, clefs, and polyphony,
+//!   and checks whether lines and voicings fit.
+//! - [`HarmonicSeries`] places partials on a temperament's grid with cents deviations;
+//!   [`VirtualPitchEstimator`] finds the fundamental implied by a chord's frequencies and
+//!   [`combination_tones`] lists difference, cubic difference, and sum tones.
+//! - [`TensionModel`] scores chords and registered voicings by roughness, Hindemith root strength,
+//!   and interval-class dissonance.
+//!
+//! 12-TET pitch classes, pitches, intervals, and keys implement `Display`/`FromStr` (`"Bb3"`,
+//! `"m3"`, `"F# minor"`); [`NoteFormat`] configures accidental spelling and octave numbering.
+//!
+//! Enable the `serde` feature for stable, human-readable encodings (`"C#4"`, `"m3"`,
+//! `"Cmaj7"`, `"A minor"`) of every theory type.
 //!
 //! # Example
 //! Build a I–V–I cadence in C major and classify the dominant function:
@@ -37,15 +79,73 @@
 //! ```
 
 pub mod chord;
+pub mod degree;
+pub mod diatonic;
+pub mod distance;
+pub mod exercise;
+pub mod figured;
+pub mod fretboard;
 pub mod function;
+pub mod harmonic;
+pub mod harmonizer;
+pub mod instrument;
 pub mod interval;
+pub mod inversion;
 pub mod key;
+pub mod melody;
+pub mod modal;
+pub mod motif;
+pub mod nonchord;
+pub mod partwriting;
 pub mod pitch;
+pub mod polychord;
+pub mod progression;
+pub mod realize;
+pub mod reflection;
+pub mod roman;
+pub mod row;
+pub mod satb;
 pub mod scale;
+pub mod search;
+#[cfg(feature = "serde")]
+mod serde_impls;
+pub mod tension;
+pub mod text;
 
-pub use chord::{Chord, Chord12, ChordKind, ExtendedKind, SeventhKind, TriadKind};
+pub use chord::{
+    Chord, Chord12, ChordKind, ExtendedKind, SeventhKind, StackedKind, SuspendedKind, TriadKind,
+};
+pub use degree::ScaleDegree;
+pub use diatonic::{ChordDepth, DiatonicChord, Stacking};
+pub use distance::{AlignedPairs, ProgressionAlignment};
+pub use exercise::{
+    Difficulty, Exercise, ExerciseGenerator, ExerciseKind, ExerciseSet, MIDI_TICKS_PER_BEAT,
+};
+pub use figured::{Figure, FiguredBass};
+pub use fretboard::{ChordShape, FretPosition, Fretboard, ShapeOptions};
 pub use function::{FunctionKind, HarmonicFunction};
-pub use interval::{GenericInterval, Interval};
+pub use harmonic::{
+    A4_HZ, CombinationKind, CombinationTone, HarmonicSeries, Partial, VirtualPitch,
+    VirtualPitchEstimator, cents_between, combination_tones,
+};
+pub use harmonizer::{Harmonization, HarmonizedSlot, MelodyHarmonizer};
+pub use instrument::{Clef, Instrument, RangeFit};
+pub use interval::{GenericInterval, Interval, Interval12};
 pub use key::{Key, Key12, Mode};
+pub use melody::MelodyNote;
+pub use modal::{BorrowedChord, ModeInfo, ScaleFamily};
+pub use motif::{DegreeStep, Motif, MotifPitches};
+pub use nonchord::{MetricLevel, NonChordToneAnalyzer, NoteAnalysis, NoteRole};
+pub use partwriting::{PartWritingChecker, PartWritingRule, Violation};
 pub use pitch::{Pitch, Pitch12, PitchClass, PitchClass12};
+pub use polychord::CompoundChord;
+pub use progression::{Progression, ProgressionMatch};
+pub use realize::{FourPartRealizer, Realization};
+pub use reflection::Axis;
+pub use roman::RomanNumeral;
+pub use row::{RowError, RowForm, RowLabel, ToneRow, ToneRow12};
+pub use satb::{SatbChord, Voice};
 pub use scale::{Scale, Scale12};
+pub use search::{ChordMatch, ChordQuery};
+pub use tension::{TensionBreakdown, TensionModel};
+pub use text::{NoteFormat, ParseError};
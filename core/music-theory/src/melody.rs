@@ -0,0 +1,44 @@
+//! core/music-theory/src/melody.rs
+//! Timed melodic notes: registered 12-TET pitches placed on the `music_time` beat axis.
+//!
+//! [`MelodyNote`] is the input unit for melody-driven analysis such as
+//! [`MelodyHarmonizer`](crate::MelodyHarmonizer).
+
+use music_time::{TimePoint, TimeSpan};
+
+use crate::pitch::{Pitch12, PitchClass12};
+
+/// A pitch sounding from `onset` for `duration` beats.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MelodyNote {
+    pub pitch: Pitch12,
+    pub onset: TimePoint,
+    pub duration: TimeSpan,
+}
+
+impl MelodyNote {
+    #[must_use]
+    pub fn new(pitch: Pitch12, onset: TimePoint, duration: TimeSpan) -> Self {
+        Self { pitch, onset, duration }
+    }
+
+    /// Point where the note stops sounding.
+    #[must_use]
+    pub fn end(&self) -> TimePoint {
+        self.onset + self.duration
+    }
+
+    #[must_use]
+    pub fn pitch_class(&self) -> PitchClass12 {
+        self.pitch.pitch_class
+    }
+
+    /// Length of the note that falls inside `[start, end)` (zero when they do not meet).
+    #[must_use]
+    pub fn overlap(&self, start: TimePoint, end: TimePoint) -> TimeSpan {
+        let from = self.onset.max(start);
+        let to = self.end().min(end);
+        if to > from { to - from } else { TimeSpan::zero() }
+    }
+}
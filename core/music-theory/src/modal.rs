@@ -0,0 +1,333 @@
+//! core/music-theory/src/modal.rs
+//! Parent collections, mode brightness, and modal interchange.
+//!
+//! [`Scale::mode_info`] finds the [`ScaleFamily`] a heptatonic scale belongs to, its mode
+//! number, and the parent scale (mode 1 of the same collection). [`Scale::brightness`] sums the
+//! degrees' distances above the tonic, so [`Scale::parallel_modes`] can order a family's modes
+//! on one tonic from brightest to darkest (lydian → locrian for the diatonic family).
+//! [`Key::modal_interchange`] lists the chords a key can borrow from its parallel modes.
+//!
+//! ```
+//! use music_theory::{ChordDepth, Key12, PitchClass12, Scale12, ScaleFamily};
+//! let d_dorian = Scale12::dorian(PitchClass12::from_semitones(2));
+//! let info = d_dorian.mode_info().unwrap();
+//! assert_eq!((info.family, info.mode, info.name()), (ScaleFamily::Diatonic, 2, "dorian"));
+//! assert_eq!(info.parent, Scale12::major(PitchClass12::from_semitones(0)));
+//! assert_eq!(d_dorian.brightness_rank(), Some(3));
+//!
+//! let borrowed = Key12::major(PitchClass12::from_semitones(0)).modal_interchange(ChordDepth::Triad);
+//! let flat_seven = borrowed.iter().find(|b| b.roman.to_string() == "bVII").unwrap();
+//! assert_eq!(flat_seven.chord.to_string(), "Bb");
+//! assert_eq!(flat_seven.sources, ["mixolydian", "dorian", "aeolian"]);
+//! ```
+
+use music_acoustic::Temperament;
+
+use crate::chord::Chord12;
+use crate::diatonic::{ChordDepth, Stacking};
+use crate::key::{Key, Key12, Mode};
+use crate::roman::RomanNumeral;
+use crate::scale::{Scale, Scale12};
+
+/// Heptatonic scale families identified by [`Scale::mode_info`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ScaleFamily {
+    /// Major scale and its church modes.
+    Diatonic,
+    /// Ascending melodic minor.
+    MelodicMinor,
+    HarmonicMinor,
+    HarmonicMajor,
+}
+
+impl ScaleFamily {
+    pub const ALL: [Self; 4] =
+        [Self::Diatonic, Self::MelodicMinor, Self::HarmonicMinor, Self::HarmonicMajor];
+
+    /// Semitone steps of mode 1.
+    #[must_use]
+    pub const fn step_pattern(self) -> &'static [i32; 7] {
+        match self {
+            Self::Diatonic => &[2, 2, 1, 2, 2, 2, 1],
+            Self::MelodicMinor => &[2, 1, 2, 2, 2, 2, 1],
+            Self::HarmonicMinor => &[2, 1, 2, 2, 1, 3, 1],
+            Self::HarmonicMajor => &[2, 2, 1, 2, 1, 3, 1],
+        }
+    }
+
+    /// Conventional names of modes 1–7.
+    #[must_use]
+    pub const fn mode_names(self) -> &'static [&'static str; 7] {
+        match self {
+            Self::Diatonic => {
+                &["ionian", "dorian", "phrygian", "lydian", "mixolydian", "aeolian", "locrian"]
+            }
+            Self::MelodicMinor => &[
+                "melodic minor",
+                "dorian b2",
+                "lydian augmented",
+                "lydian dominant",
+                "mixolydian b6",
+                "locrian #2",
+                "altered",
+            ],
+            Self::HarmonicMinor => &[
+                "harmonic minor",
+                "locrian #6",
+                "ionian #5",
+                "dorian #4",
+                "phrygian dominant",
+                "lydian #2",
+                "ultralocrian",
+            ],
+            Self::HarmonicMajor => &[
+                "harmonic major",
+                "dorian b5",
+                "phrygian b4",
+                "lydian b3",
+                "mixolydian b2",
+                "lydian augmented #2",
+                "locrian bb7",
+            ],
+        }
+    }
+}
+
+/// Where a scale sits within its family.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModeInfo<T: Temperament> {
+    pub family: ScaleFamily,
+    /// Mode number (1 = the family's parent scale).
+    pub mode: usize,
+    /// Mode 1 of the same pitch collection.
+    pub parent: Scale<T>,
+}
+
+impl<T: Temperament> ModeInfo<T> {
+    /// Conventional mode name (`"dorian"`, `"phrygian dominant"`, ...).
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.family.mode_names()[(self.mode - 1) % 7]
+    }
+
+    /// The described scale: the parent rotated to [`ModeInfo::mode`].
+    #[must_use]
+    pub fn scale(&self) -> Option<Scale<T>> {
+        self.parent.mode(self.mode)
+    }
+}
+
+impl<T: Temperament> Scale<T> {
+    /// Steps between successive degrees, closing back to the octave.
+    #[must_use]
+    pub fn step_pattern(&self) -> Vec<i32> {
+        let steps = i32::from(T::STEPS_PER_OCTAVE);
+        (0..self.degrees.len())
+            .map(|index| {
+                let from = i32::from(self.degrees[index].index());
+                let to = i32::from(self.degrees[(index + 1) % self.degrees.len()].index());
+                match (to - from).rem_euclid(steps) {
+                    0 => steps,
+                    step => step,
+                }
+            })
+            .collect()
+    }
+
+    /// Sum of every degree's distance above the first, in steps (higher is brighter).
+    #[must_use]
+    pub fn brightness(&self) -> i32 {
+        let Some(root) = self.degrees.first() else {
+            return 0;
+        };
+        let steps = i32::from(T::STEPS_PER_OCTAVE);
+        self.degrees
+            .iter()
+            .map(|degree| (i32::from(degree.index()) - i32::from(root.index())).rem_euclid(steps))
+            .sum()
+    }
+
+    /// Family, mode number, and parent scale, or `None` for scales outside [`ScaleFamily`].
+    #[must_use]
+    pub fn mode_info(&self) -> Option<ModeInfo<T>> {
+        let steps = i32::from(T::STEPS_PER_OCTAVE);
+        if self.len() != 7 || steps % 12 != 0 {
+            return None;
+        }
+        let pattern = self.step_pattern();
+        ScaleFamily::ALL.into_iter().find_map(|family| {
+            let family_steps: Vec<i32> =
+                family.step_pattern().iter().map(|step| step * (steps / 12)).collect();
+            (0..7).find_map(|rotation| {
+                let matches = (0..7).all(|i| pattern[i] == family_steps[(rotation + i) % 7]);
+                matches.then(|| {
+                    let parent_root = self.degrees[(7 - rotation) % 7];
+                    ModeInfo {
+                        family,
+                        mode: rotation + 1,
+                        parent: Self::from_step_pattern(parent_root, &family_steps),
+                    }
+                })
+            })
+        })
+    }
+
+    /// Every rotation of the scale's step pattern on its own tonic, brightest first.
+    ///
+    /// The scale itself is included; ties keep rotation order.
+    #[must_use]
+    pub fn parallel_modes(&self) -> Vec<Self> {
+        let Some(root) = self.degrees.first().copied() else {
+            return Vec::new();
+        };
+        let pattern = self.step_pattern();
+        let mut modes: Vec<Self> = (0..pattern.len())
+            .map(|rotation| {
+                let mut rotated = pattern[rotation..].to_vec();
+                rotated.extend_from_slice(&pattern[..rotation]);
+                Self::from_step_pattern(root, &rotated)
+            })
+            .collect();
+        modes.sort_by_key(|mode| core::cmp::Reverse(mode.brightness()));
+        modes
+    }
+
+    /// Position among [`Scale::parallel_modes`] (`0` = brightest).
+    #[must_use]
+    pub fn brightness_rank(&self) -> Option<usize> {
+        self.parallel_modes().iter().position(|mode| mode.degrees == self.degrees)
+    }
+}
+
+/// A chord a key can borrow from a parallel mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BorrowedChord {
+    pub chord: Chord12,
+    /// Numeral relative to the key's tonic (`bVII`, `iv`, `#iv°`).
+    pub roman: RomanNumeral,
+    /// Parallel modes containing the chord, brightest first.
+    pub sources: Vec<&'static str>,
+}
+
+impl<T: Temperament> Key<T> {
+    /// The key's scale as a mode of its family, if it belongs to one.
+    #[must_use]
+    pub fn mode_info(&self) -> Option<ModeInfo<T>> {
+        self.scale().mode_info()
+    }
+}
+
+impl Key12 {
+    /// Chords of the parallel diatonic modes (lydian through locrian on the same tonic) that
+    /// are not diatonic to the key, brightest source first.
+    ///
+    /// In minor keys the harmonic-minor V and vii° count as native. Chords found in several
+    /// modes are listed once with every source.
+    #[must_use]
+    pub fn modal_interchange(&self, depth: ChordDepth) -> Vec<BorrowedChord> {
+        let native: Vec<Chord12> = self
+            .diatonic_chords(depth)
+            .into_iter()
+            .chain(self.functional_chords(depth))
+            .map(|diatonic| diatonic.chord)
+            .collect();
+        let own = self.scale12();
+        let names = ScaleFamily::Diatonic.mode_names();
+        let mut table: Vec<BorrowedChord> = Vec::new();
+        for mode in Scale12::major(self.tonic).parallel_modes() {
+            if mode.degrees == own.degrees {
+                continue;
+            }
+            let Some(source) = mode.mode_info().map(|info| names[info.mode - 1]) else {
+                continue;
+            };
+            for diatonic in mode.harmonize(depth, Stacking::Thirds).unwrap_or_default() {
+                if native.iter().any(|chord| chord.same_pitch_classes(&diatonic.chord)) {
+                    continue;
+                }
+                if let Some(entry) =
+                    table.iter_mut().find(|b| b.chord.same_pitch_classes(&diatonic.chord))
+                {
+                    entry.sources.push(source);
+                    continue;
+                }
+                table.push(BorrowedChord {
+                    chord: diatonic.chord,
+                    roman: diatonic.roman,
+                    sources: vec![source],
+                });
+            }
+        }
+        table
+    }
+
+    /// Parallel key in the other mode (C major ↔ C minor).
+    #[must_use]
+    pub fn parallel(&self) -> Self {
+        let mode = match self.mode {
+            Mode::Major => Mode::Minor,
+            Mode::Minor => Mode::Major,
+        };
+        Self::new(self.tonic, mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch::PitchClass12;
+
+    fn pc(semitones: u16) -> PitchClass12 {
+        PitchClass12::from_semitones(semitones)
+    }
+
+    #[test]
+    fn modes_find_their_parent_collection() {
+        let e_phrygian_dominant = Scale12::from_step_pattern(pc(4), &[1, 3, 1, 2, 1, 2, 2]);
+        let info = e_phrygian_dominant.mode_info().expect("harmonic minor mode");
+        assert_eq!(
+            (info.family, info.mode, info.name()),
+            (ScaleFamily::HarmonicMinor, 5, "phrygian dominant")
+        );
+        assert_eq!(info.parent.degrees[0], pc(9));
+        assert_eq!(info.scale(), Some(e_phrygian_dominant));
+        let altered = Scale12::from_step_pattern(pc(7), &[1, 2, 1, 2, 2, 2, 2]);
+        assert_eq!(altered.mode_info().map(|info| info.name()), Some("altered"));
+        let pentatonic = Scale12::from_step_pattern(pc(0), &[2, 2, 3, 2, 3]);
+        assert!(pentatonic.mode_info().is_none());
+        assert_eq!(Key12::minor(pc(9)).mode_info().map(|info| info.name()), Some("aeolian"));
+    }
+
+    #[test]
+    fn parallel_modes_run_from_lydian_to_locrian() {
+        let names: Vec<&str> = Scale12::major(pc(0))
+            .parallel_modes()
+            .iter()
+            .filter_map(|mode| mode.mode_info().map(|info| info.name()))
+            .collect();
+        assert_eq!(
+            names,
+            ["lydian", "ionian", "mixolydian", "dorian", "aeolian", "phrygian", "locrian"]
+        );
+        assert_eq!(Scale12::lydian(pc(5)).brightness_rank(), Some(0));
+        assert_eq!(Scale12::locrian(pc(11)).brightness_rank(), Some(6));
+        assert!(Scale12::lydian(pc(0)).brightness() > Scale12::major(pc(0)).brightness());
+    }
+
+    #[test]
+    fn interchange_table_lists_borrowed_chords() {
+        let table = Key12::major(pc(0)).modal_interchange(ChordDepth::Triad);
+        let romans: Vec<String> = table.iter().map(|b| b.roman.to_string()).collect();
+        for expected in ["II", "#iv°", "vii", "i", "bIII", "iv", "bVI", "bVII", "bII", "bV"] {
+            assert!(romans.contains(&expected.to_string()), "{expected} missing from {romans:?}");
+        }
+        assert!(!romans.contains(&"IV".to_string()));
+        let minor = Key12::minor(pc(9)).modal_interchange(ChordDepth::Triad);
+        let romans: Vec<String> = minor.iter().map(|b| b.roman.to_string()).collect();
+        assert!(romans.contains(&"IV".to_string()) && romans.contains(&"I".to_string()));
+        assert!(!romans.contains(&"V".to_string()));
+        assert_eq!(Key12::minor(pc(9)).parallel(), Key12::major(pc(9)));
+    }
+}
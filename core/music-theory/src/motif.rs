@@ -0,0 +1,436 @@
+//! core/music-theory/src/motif.rs
+//! Melodic motifs (pitch contour plus rhythm) and their classical transformations.
+//!
+//! A [`Motif`] stores its pitches either as scale steps ([`MotifPitches::Degrees`]) or as
+//! chromatic [`Interval`]s above a reference pitch ([`MotifPitches::Intervals`]), together with
+//! one [`TimeSpan`] per note and the offset of its first onset from the downbeat. Pitch
+//! transformations take the [`Scale`] that relates the two representations:
+//!
+//! - [`Motif::invert_real`] mirrors chromatic distances around the first note (intervals out);
+//! - [`Motif::invert_tonal`] mirrors scale steps around the first note (degrees out);
+//! - [`Motif::sequence_real`] / [`Motif::sequence_diatonic`] chain transposed copies.
+//!
+//! Rhythm transformations ([`Motif::augment`], [`Motif::diminish`], [`Motif::displace`]),
+//! [`Motif::retrograde`], and [`Motif::fragment`] work on either representation.
+//!
+//! ```
+//! use music_theory::{DegreeStep, Motif, Pitch12, PitchClass12, Scale12};
+//! use music_time::TimeSpan;
+//! let scale = Scale12::major(PitchClass12::from_semitones(0));
+//! let quarter = TimeSpan::new(1.0);
+//! // 1–2–3–1 in quarters.
+//! let steps = [0, 1, 2, 0].map(|step| DegreeStep::new(step, 0));
+//! let motif = Motif::from_degrees(&steps, &[quarter; 4]);
+//! let inverted = motif.invert_tonal(&scale).unwrap();
+//! let tonic = Pitch12::from_semitones_and_octave(0, 4);
+//! let pitches: Vec<String> =
+//!     inverted.realize(&scale, tonic).unwrap().iter().map(|(p, _)| p.to_string()).collect();
+//! assert_eq!(pitches, ["C4", "B3", "A3", "C4"]);
+//! ```
+
+use core::ops::Range;
+
+use music_acoustic::Temperament;
+use music_time::{Meter, Rational, TimeSpan};
+
+use crate::degree::ScaleDegree;
+use crate::interval::Interval;
+use crate::pitch::Pitch;
+use crate::scale::Scale;
+
+/// Scale-step position relative to the tonic (`0` = degree 1, `-1` = degree 7 an octave below).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DegreeStep {
+    /// Signed scale steps from the tonic; multiples of the scale length are octaves.
+    pub steps: i32,
+    /// Chromatic alteration in temperament steps.
+    pub alteration: i8,
+}
+
+impl DegreeStep {
+    #[must_use]
+    pub fn new(steps: i32, alteration: i8) -> Self {
+        Self { steps, alteration }
+    }
+}
+
+impl From<ScaleDegree> for DegreeStep {
+    fn from(degree: ScaleDegree) -> Self {
+        Self::new(i32::from(degree.degree) - 1, degree.alteration)
+    }
+}
+
+/// Pitch content of a motif.
+#[derive(Debug, PartialEq, Eq)]
+pub enum MotifPitches<T: Temperament> {
+    /// Scale steps from the tonic of the scale the motif is realized in.
+    Degrees(Vec<DegreeStep>),
+    /// Chromatic intervals above the reference pitch the motif is realized from.
+    Intervals(Vec<Interval<T>>),
+}
+
+impl<T: Temperament> Clone for MotifPitches<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Degrees(degrees) => Self::Degrees(degrees.clone()),
+            Self::Intervals(intervals) => Self::Intervals(intervals.clone()),
+        }
+    }
+}
+
+impl<T: Temperament> MotifPitches<T> {
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Degrees(degrees) => degrees.len(),
+            Self::Intervals(intervals) => intervals.len(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A short melodic idea: pitches, durations, and a metric offset.
+#[derive(Debug, PartialEq)]
+pub struct Motif<T: Temperament> {
+    pub pitches: MotifPitches<T>,
+    /// Duration of each note, parallel to `pitches`.
+    pub rhythm: Vec<TimeSpan>,
+    /// Distance of the first onset after the downbeat.
+    pub offset: TimeSpan,
+}
+
+impl<T: Temperament> Clone for Motif<T> {
+    fn clone(&self) -> Self {
+        Self { pitches: self.pitches.clone(), rhythm: self.rhythm.clone(), offset: self.offset }
+    }
+}
+
+impl<T: Temperament> Motif<T> {
+    /// Motif from scale steps; notes without a duration are dropped.
+    #[must_use]
+    pub fn from_degrees(degrees: &[DegreeStep], rhythm: &[TimeSpan]) -> Self {
+        let len = degrees.len().min(rhythm.len());
+        Self {
+            pitches: MotifPitches::Degrees(degrees[..len].to_vec()),
+            rhythm: rhythm[..len].to_vec(),
+            offset: TimeSpan::zero(),
+        }
+    }
+
+    /// Motif from chromatic intervals; notes without a duration are dropped.
+    #[must_use]
+    pub fn from_intervals(intervals: &[Interval<T>], rhythm: &[TimeSpan]) -> Self {
+        let len = intervals.len().min(rhythm.len());
+        Self {
+            pitches: MotifPitches::Intervals(intervals[..len].to_vec()),
+            rhythm: rhythm[..len].to_vec(),
+            offset: TimeSpan::zero(),
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.rhythm.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rhythm.is_empty()
+    }
+
+    /// Sum of the note durations.
+    #[must_use]
+    pub fn duration(&self) -> TimeSpan {
+        self.rhythm.iter().fold(TimeSpan::zero(), |total, span| total + *span)
+    }
+
+    /// Same motif with chromatic intervals measured from the scale's tonic.
+    #[must_use]
+    pub fn to_intervals(&self, scale: &Scale<T>) -> Option<Self> {
+        let intervals = match &self.pitches {
+            MotifPitches::Intervals(intervals) => intervals.clone(),
+            MotifPitches::Degrees(degrees) => degrees
+                .iter()
+                .map(|degree| degree_interval(scale, *degree))
+                .collect::<Option<Vec<_>>>()?,
+        };
+        Some(self.with_pitches(MotifPitches::Intervals(intervals)))
+    }
+
+    /// Same motif spelled as the nearest scale steps (chromatic notes become altered steps).
+    #[must_use]
+    pub fn to_degrees(&self, scale: &Scale<T>) -> Option<Self> {
+        let degrees = match &self.pitches {
+            MotifPitches::Degrees(degrees) => degrees.clone(),
+            MotifPitches::Intervals(intervals) => intervals
+                .iter()
+                .map(|interval| nearest_step(scale, *interval))
+                .collect::<Option<Vec<_>>>()?,
+        };
+        Some(self.with_pitches(MotifPitches::Degrees(degrees)))
+    }
+
+    /// Real (chromatic) inversion around the first note.
+    #[must_use]
+    pub fn invert_real(&self, scale: &Scale<T>) -> Option<Self> {
+        let MotifPitches::Intervals(intervals) = self.to_intervals(scale)?.pitches else {
+            return None;
+        };
+        let axis = intervals.first().map_or(0, Interval::steps);
+        let mirrored = intervals.iter().map(|i| Interval::new(2 * axis - i.steps())).collect();
+        Some(self.with_pitches(MotifPitches::Intervals(mirrored)))
+    }
+
+    /// Tonal (diatonic) inversion around the first note; alterations flip direction.
+    #[must_use]
+    pub fn invert_tonal(&self, scale: &Scale<T>) -> Option<Self> {
+        let MotifPitches::Degrees(degrees) = self.to_degrees(scale)?.pitches else {
+            return None;
+        };
+        let axis = degrees.first().map_or(0, |degree| degree.steps);
+        let mirrored = degrees
+            .iter()
+            .map(|degree| DegreeStep::new(2 * axis - degree.steps, -degree.alteration))
+            .collect();
+        Some(self.with_pitches(MotifPitches::Degrees(mirrored)))
+    }
+
+    /// Notes (pitches and durations) in reverse order.
+    #[must_use]
+    pub fn retrograde(&self) -> Self {
+        let pitches = match &self.pitches {
+            MotifPitches::Degrees(degrees) => {
+                MotifPitches::Degrees(degrees.iter().rev().copied().collect())
+            }
+            MotifPitches::Intervals(intervals) => {
+                MotifPitches::Intervals(intervals.iter().rev().copied().collect())
+            }
+        };
+        Self { pitches, rhythm: self.rhythm.iter().rev().copied().collect(), offset: self.offset }
+    }
+
+    /// Durations multiplied by `factor` (`2` = doubled note values, `3/2` = dotted).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is negative.
+    #[must_use]
+    pub fn augment(&self, factor: Rational) -> Self {
+        let scale = |span: TimeSpan| TimeSpan::from_rational(span.as_rational() * factor);
+        Self {
+            pitches: self.pitches.clone(),
+            rhythm: self.rhythm.iter().copied().map(scale).collect(),
+            offset: scale(self.offset),
+        }
+    }
+
+    /// Durations divided by `factor` (`2` = halved note values, `3/2` = triplets).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is not positive.
+    #[must_use]
+    pub fn diminish(&self, factor: Rational) -> Self {
+        assert!(factor > Rational::ZERO, "diminution factor must be positive");
+        self.augment(factor.recip().unwrap_or_default())
+    }
+
+    /// Contiguous fragment of the motif; its offset moves to where the fragment starts.
+    #[must_use]
+    pub fn fragment(&self, notes: Range<usize>) -> Option<Self> {
+        if notes.is_empty() || notes.end > self.len() {
+            return None;
+        }
+        let skipped =
+            self.rhythm[..notes.start].iter().fold(self.offset, |total, span| total + *span);
+        let pitches = match &self.pitches {
+            MotifPitches::Degrees(degrees) => {
+                MotifPitches::Degrees(degrees[notes.clone()].to_vec())
+            }
+            MotifPitches::Intervals(intervals) => {
+                MotifPitches::Intervals(intervals[notes.clone()].to_vec())
+            }
+        };
+        Some(Self { pitches, rhythm: self.rhythm[notes].to_vec(), offset: skipped })
+    }
+
+    /// The motif followed by `count` copies, each a further `interval` higher (real sequence).
+    #[must_use]
+    pub fn sequence_real(
+        &self,
+        interval: Interval<T>,
+        count: usize,
+        scale: &Scale<T>,
+    ) -> Option<Self> {
+        let MotifPitches::Intervals(intervals) = self.to_intervals(scale)?.pitches else {
+            return None;
+        };
+        let mut chained = Vec::with_capacity(intervals.len() * (count + 1));
+        for repetition in 0..=count {
+            let shift = interval.steps() * i32::try_from(repetition).ok()?;
+            chained.extend(intervals.iter().map(|i| Interval::new(i.steps() + shift)));
+        }
+        Some(self.repeat_rhythm(MotifPitches::Intervals(chained), count))
+    }
+
+    /// The motif followed by `count` copies, each `steps` scale steps higher (diatonic sequence).
+    #[must_use]
+    pub fn sequence_diatonic(&self, steps: i32, count: usize, scale: &Scale<T>) -> Option<Self> {
+        let MotifPitches::Degrees(degrees) = self.to_degrees(scale)?.pitches else {
+            return None;
+        };
+        let mut chained = Vec::with_capacity(degrees.len() * (count + 1));
+        for repetition in 0..=count {
+            let shift = steps * i32::try_from(repetition).ok()?;
+            chained.extend(degrees.iter().map(|d| DegreeStep::new(d.steps + shift, d.alteration)));
+        }
+        Some(self.repeat_rhythm(MotifPitches::Degrees(chained), count))
+    }
+
+    /// Shift the motif later by `shift` against `meter`, wrapping the offset within the bar.
+    #[must_use]
+    pub fn displace(&self, shift: TimeSpan, meter: Meter) -> Self {
+        let shifted = (self.offset + shift).as_rational();
+        let offset = shifted.rem_euclid(meter.bar_span().as_rational()).unwrap_or_default();
+        Self { offset: TimeSpan::from_rational(offset), ..self.clone() }
+    }
+
+    /// Metric weight of every onset (see [`Meter::metric_weight`]).
+    #[must_use]
+    pub fn accents(&self, meter: Meter) -> Vec<f64> {
+        let mut onset = self.offset;
+        self.rhythm
+            .iter()
+            .map(|span| {
+                let weight = meter.metric_weight(onset);
+                onset += *span;
+                weight
+            })
+            .collect()
+    }
+
+    /// Registered pitches and onsets (from the downbeat), with `tonic` as the scale's tonic
+    /// or the interval reference pitch.
+    #[must_use]
+    pub fn realize(&self, scale: &Scale<T>, tonic: Pitch<T>) -> Option<Vec<(Pitch<T>, TimeSpan)>> {
+        let MotifPitches::Intervals(intervals) = self.to_intervals(scale)?.pitches else {
+            return None;
+        };
+        let mut onset = self.offset;
+        let notes = intervals
+            .iter()
+            .zip(&self.rhythm)
+            .map(|(interval, span)| {
+                let note = (tonic.transpose(*interval), onset);
+                onset += *span;
+                note
+            })
+            .collect();
+        Some(notes)
+    }
+
+    fn with_pitches(&self, pitches: MotifPitches<T>) -> Self {
+        Self { pitches, rhythm: self.rhythm.clone(), offset: self.offset }
+    }
+
+    fn repeat_rhythm(&self, pitches: MotifPitches<T>, count: usize) -> Self {
+        let rhythm = self.rhythm.iter().copied().cycle().take(self.len() * (count + 1)).collect();
+        Self { pitches, rhythm, offset: self.offset }
+    }
+}
+
+/// Chromatic interval from the tonic to a (possibly negative or compound) scale step.
+fn degree_interval<T: Temperament>(scale: &Scale<T>, degree: DegreeStep) -> Option<Interval<T>> {
+    let len = i32::try_from(scale.len()).ok().filter(|len| *len > 0)?;
+    let octave = degree.steps.div_euclid(len);
+    let simple = u8::try_from(degree.steps.rem_euclid(len) + 1).ok()?;
+    let within = scale.interval_of(ScaleDegree::new(simple, degree.alteration))?;
+    Some(Interval::new(within.steps() + octave * i32::from(T::STEPS_PER_OCTAVE)))
+}
+
+/// Nearest scale step for a chromatic interval above the tonic.
+fn nearest_step<T: Temperament>(scale: &Scale<T>, interval: Interval<T>) -> Option<DegreeStep> {
+    let steps = i32::from(T::STEPS_PER_OCTAVE);
+    let len = i32::try_from(scale.len()).ok()?;
+    let octave = interval.steps().div_euclid(steps);
+    let degree = scale.degree_for_interval(Interval::new(interval.steps().rem_euclid(steps)))?;
+    let step = DegreeStep::from(degree);
+    Some(DegreeStep::new(step.steps + octave * len, step.alteration))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::Interval12;
+    use crate::pitch::{Pitch12, PitchClass12};
+    use crate::scale::Scale12;
+
+    fn c_major() -> Scale12 {
+        Scale12::major(PitchClass12::from_semitones(0))
+    }
+
+    fn spans(beats: &[f64]) -> Vec<TimeSpan> {
+        beats.iter().copied().map(TimeSpan::new).collect()
+    }
+
+    fn names(motif: &Motif<music_acoustic::T12>) -> Vec<String> {
+        let tonic = Pitch12::from_semitones_and_octave(0, 4);
+        let notes = motif.realize(&c_major(), tonic).expect("realizable");
+        notes.iter().map(|(pitch, _)| pitch.to_string()).collect()
+    }
+
+    #[test]
+    fn inversions_differ_between_real_and_tonal() {
+        // E–F–G: real inversion mirrors semitones, tonal inversion mirrors scale steps.
+        let steps = [2, 3, 4].map(|step| DegreeStep::new(step, 0));
+        let motif = Motif::from_degrees(&steps, &spans(&[1.0, 1.0, 2.0]));
+        let real = motif.invert_real(&c_major()).expect("real");
+        assert_eq!(names(&real), ["E4", "Eb4", "C#4"]);
+        let tonal = motif.invert_tonal(&c_major()).expect("tonal");
+        assert_eq!(names(&tonal), ["E4", "D4", "C4"]);
+        assert_eq!(names(&motif.retrograde()), ["G4", "F4", "E4"]);
+        assert_eq!(motif.retrograde().rhythm, spans(&[2.0, 1.0, 1.0]));
+        // Interval motifs convert to degrees for tonal work.
+        let intervals = [0, 2, -1].map(Interval12::from_semitones);
+        let chromatic = Motif::from_intervals(&intervals, &spans(&[1.0; 3]));
+        assert_eq!(names(&chromatic.invert_tonal(&c_major()).expect("tonal")), ["C4", "B3", "D4"]);
+    }
+
+    #[test]
+    fn sequences_and_fragments() {
+        let steps = [0, 1, 2].map(|step| DegreeStep::new(step, 0));
+        let motif = Motif::from_degrees(&steps, &spans(&[0.5, 0.5, 1.0]));
+        let diatonic = motif.sequence_diatonic(1, 1, &c_major()).expect("diatonic");
+        assert_eq!(names(&diatonic), ["C4", "D4", "E4", "D4", "E4", "F4"]);
+        let real = motif.sequence_real(Interval12::from_semitones(2), 1, &c_major()).expect("real");
+        assert_eq!(names(&real), ["C4", "D4", "E4", "D4", "E4", "F#4"]);
+        assert_eq!(real.duration().as_beats(), 4.0);
+
+        let tail = motif.fragment(1..3).expect("fragment");
+        assert_eq!(names(&tail), ["D4", "E4"]);
+        assert_eq!(tail.offset.as_beats(), 0.5);
+        assert!(motif.fragment(2..5).is_none());
+    }
+
+    #[test]
+    fn rhythm_transformations_respect_the_meter() {
+        let steps = [0, 4].map(|step| DegreeStep::new(step, 0));
+        let motif: Motif<music_acoustic::T12> = Motif::from_degrees(&steps, &spans(&[1.0, 1.0]));
+        assert_eq!(motif.augment(Rational::from_integer(2)).rhythm, spans(&[2.0, 2.0]));
+        assert_eq!(motif.diminish(Rational::from_integer(2)).rhythm, spans(&[0.5, 0.5]));
+        let triplets = motif.diminish(Rational::new(3, 2)).rhythm;
+        assert_eq!(triplets, [TimeSpan::exact(2, 3), TimeSpan::exact(2, 3)]);
+
+        assert_eq!(motif.accents(Meter::THREE_FOUR), [1.0, 0.5]);
+        let displaced = motif.displace(TimeSpan::new(2.5), Meter::THREE_FOUR);
+        assert_eq!(displaced.offset.as_beats(), 2.5);
+        assert_eq!(displaced.accents(Meter::THREE_FOUR), [0.25, 0.25]);
+        let wrapped = displaced.displace(TimeSpan::new(0.5), Meter::THREE_FOUR);
+        assert_eq!(wrapped.accents(Meter::THREE_FOUR), [1.0, 0.5]);
+    }
+}
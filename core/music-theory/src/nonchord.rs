@@ -0,0 +1,423 @@
+//! core/music-theory/src/nonchord.rs
+//! Non-chord-tone classification for a melody over a chord progression.
+//!
+//! [`NonChordToneAnalyzer`] labels every [`MelodyNote`] against the last chord to sound under
+//! it, so a note tied over a change of harmony is read against the new chord.
+//! Notes outside the chord are classified by their melodic approach and departure (step = one or
+//! two semitones, leap = more) and by their metric position, which is read from a
+//! [`TimeGrid`]: a note is accented when it sits on a stronger grid level than the note after it.
+//!
+//! ```
+//! use music_theory::{Chord12, Key12, MelodyNote, NonChordToneAnalyzer, Pitch12, PitchClass12};
+//! use music_time::{GridConfig, Meter, TimePoint, TimeSpan};
+//! let pc = PitchClass12::from_semitones;
+//! let note = |semitones, beat| {
+//!     let pitch = Pitch12::from_semitones_and_octave(semitones, 5);
+//!     MelodyNote::new(pitch, TimePoint::new(beat), TimeSpan::new(1.0))
+//! };
+//! // C major: C–D–E over a C triad, D is a passing tone.
+//! let melody = [note(0, 0.0), note(2, 1.0), note(4, 2.0)];
+//! let grid = GridConfig::new(TimePoint::new(0.0), Meter::FOUR_FOUR).build();
+//! let analyzer = NonChordToneAnalyzer::new(Key12::major(pc(0)), grid);
+//! let chords = [(TimePoint::new(0.0), Chord12::major_triad(pc(0)))];
+//! let labels: Vec<String> =
+//!     analyzer.analyze(&melody, &chords).iter().map(|a| a.role.to_string()).collect();
+//! assert_eq!(labels, ["chord tone", "passing tone", "chord tone"]);
+//! ```
+
+use core::fmt;
+
+use music_time::{TimeGrid, TimePoint};
+
+use crate::chord::Chord12;
+use crate::key::Key12;
+use crate::melody::MelodyNote;
+
+/// Strength of a metric position on a [`TimeGrid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum MetricLevel {
+    /// Between grid subdivisions.
+    Offbeat,
+    Subdivision,
+    Beat,
+    Downbeat,
+}
+
+impl MetricLevel {
+    /// Strongest grid level the point falls on, or `None` before the grid's first barline or
+    /// after its last.
+    #[must_use]
+    pub fn of(grid: &TimeGrid, point: TimePoint) -> Option<Self> {
+        let (first, last) = (grid.measures().first()?, grid.measures().last()?);
+        if point < *first || point > *last {
+            return None;
+        }
+        let on = |points: &[TimePoint]| points.contains(&point);
+        Some(if on(grid.measures()) {
+            Self::Downbeat
+        } else if on(grid.beats()) {
+            Self::Beat
+        } else if on(grid.subdivisions()) {
+            Self::Subdivision
+        } else {
+            Self::Offbeat
+        })
+    }
+}
+
+/// Role of a melody note relative to the active chord.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum NoteRole {
+    ChordTone,
+    /// Stepwise between two notes, continuing in the same direction.
+    Passing,
+    /// Steps away from a pitch and back to it.
+    Neighbor,
+    /// Accented, approached by leap, resolved by step.
+    Appoggiatura,
+    /// Unaccented, approached by step, left by leap in the opposite direction.
+    EscapeTone,
+    /// Held or repeated from a chord tone of the previous harmony, resolved by step. A note
+    /// tied over the change of harmony is its own preparation.
+    Suspension {
+        /// Index of the preparing note.
+        preparation: usize,
+        /// Index of the resolving note.
+        resolution: usize,
+    },
+    /// Unaccented arrival of a tone of the following chord.
+    Anticipation,
+    /// Pitch sustained or repeated through a change of harmony.
+    Pedal,
+    /// Chromatic (out-of-key) note a semitone from the chord tone it leads to.
+    ChromaticApproach,
+    /// Non-chord tone matching none of the patterns (or no active chord).
+    Unclassified,
+}
+
+impl NoteRole {
+    /// Whether the note belongs to the active chord.
+    #[must_use]
+    pub fn is_chord_tone(self) -> bool {
+        self == Self::ChordTone
+    }
+}
+
+impl fmt::Display for NoteRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ChordTone => f.write_str("chord tone"),
+            Self::Passing => f.write_str("passing tone"),
+            Self::Neighbor => f.write_str("neighbor tone"),
+            Self::Appoggiatura => f.write_str("appoggiatura"),
+            Self::EscapeTone => f.write_str("escape tone"),
+            Self::Suspension { preparation, resolution } => write!(
+                f,
+                "suspension (prepared by note {}, resolves to note {})",
+                preparation + 1,
+                resolution + 1
+            ),
+            Self::Anticipation => f.write_str("anticipation"),
+            Self::Pedal => f.write_str("pedal"),
+            Self::ChromaticApproach => f.write_str("chromatic approach"),
+            Self::Unclassified => f.write_str("unclassified"),
+        }
+    }
+}
+
+/// Classification of one melody note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteAnalysis {
+    /// Index of the note in the melody.
+    pub index: usize,
+    pub role: NoteRole,
+    /// Index of the chord the note is read against (the last one to sound under it), if any.
+    pub chord: Option<usize>,
+    /// Metric level of the onset; `None` when it lies outside the grid.
+    pub metric: Option<MetricLevel>,
+}
+
+/// Labels melody notes as chord tones or embellishments.
+#[derive(Debug, Clone)]
+pub struct NonChordToneAnalyzer {
+    /// Key used to recognize chromatic notes.
+    pub key: Key12,
+    /// Grid supplying metric positions.
+    pub grid: TimeGrid,
+}
+
+/// Melodic context of the note being classified.
+struct Context {
+    /// Signed semitone motion from the previous note.
+    approach: Option<i32>,
+    /// Signed semitone motion to the next note.
+    departure: Option<i32>,
+    accented: bool,
+    next_is_chord_tone: bool,
+    /// Harmony changes at the note's onset, while it sounds, or at the next note.
+    harmony_moves: bool,
+    /// The note belongs to the chord at its onset and is held into a harmony it does not.
+    held_into_dissonance: bool,
+}
+
+impl NonChordToneAnalyzer {
+    #[must_use]
+    pub fn new(key: Key12, grid: TimeGrid) -> Self {
+        Self { key, grid }
+    }
+
+    /// Classify every note against `chords`, given as `(onset, chord)` changes in time order.
+    #[must_use]
+    pub fn analyze(
+        &self,
+        melody: &[MelodyNote],
+        chords: &[(TimePoint, Chord12)],
+    ) -> Vec<NoteAnalysis> {
+        let onset_chords: Vec<Option<usize>> =
+            melody.iter().map(|note| active_chord(chords, note.onset)).collect();
+        let active: Vec<Option<usize>> = melody
+            .iter()
+            .zip(&onset_chords)
+            .map(|(note, onset)| changed_chord(chords, note).or(*onset))
+            .collect();
+        let is_chord_tone = |index: usize| {
+            active[index].is_some_and(|chord| chords[chord].1.contains(melody[index].pitch_class()))
+        };
+        let levels: Vec<Option<MetricLevel>> =
+            melody.iter().map(|note| MetricLevel::of(&self.grid, note.onset)).collect();
+        (0..melody.len())
+            .map(|index| {
+                let role = if is_chord_tone(index) {
+                    NoteRole::ChordTone
+                } else if active[index].is_none() {
+                    NoteRole::Unclassified
+                } else {
+                    let next = index + 1;
+                    let context = Context {
+                        approach: index.checked_sub(1).map(|prev| motion(melody, prev, index)),
+                        departure: (next < melody.len()).then(|| motion(melody, index, next)),
+                        accented: levels[index] == Some(MetricLevel::Downbeat)
+                            || levels.get(next).is_none_or(|level| levels[index] > *level),
+                        next_is_chord_tone: next < melody.len() && is_chord_tone(next),
+                        harmony_moves: index
+                            .checked_sub(1)
+                            .is_some_and(|prev| active[prev] != active[index])
+                            || active.get(next).is_some_and(|chord| *chord != active[index])
+                            || changed_chord(chords, &melody[index]).is_some(),
+                        held_into_dissonance: onset_chords[index] != active[index]
+                            && onset_chords[index].is_some_and(|chord| {
+                                chords[chord].1.contains(melody[index].pitch_class())
+                            }),
+                    };
+                    self.classify(melody, &active, index, &context, is_chord_tone)
+                };
+                NoteAnalysis { index, role, chord: active[index], metric: levels[index] }
+            })
+            .collect()
+    }
+
+    fn classify(
+        &self,
+        melody: &[MelodyNote],
+        active: &[Option<usize>],
+        index: usize,
+        context: &Context,
+        is_chord_tone: impl Fn(usize) -> bool,
+    ) -> NoteRole {
+        let step = |motion: i32| matches!(motion.abs(), 1 | 2);
+        let leap = |motion: i32| motion.abs() > 2;
+        let (approach, departure) = (context.approach, context.departure);
+        let harmony_changed =
+            index.checked_sub(1).is_some_and(|previous| active[previous] != active[index]);
+
+        if let (Some(0), Some(resolve)) = (approach, departure)
+            && harmony_changed
+            && context.accented
+            && step(resolve)
+            && is_chord_tone(index - 1)
+            && context.next_is_chord_tone
+        {
+            return NoteRole::Suspension { preparation: index - 1, resolution: index + 1 };
+        }
+        if context.held_into_dissonance && departure.is_some_and(step) && context.next_is_chord_tone
+        {
+            return NoteRole::Suspension { preparation: index, resolution: index + 1 };
+        }
+        if approach == Some(0) && departure == Some(0) && context.harmony_moves {
+            return NoteRole::Pedal;
+        }
+        if departure == Some(0)
+            && !context.accented
+            && context.next_is_chord_tone
+            && active.get(index + 1).is_some_and(|next| *next != active[index])
+        {
+            return NoteRole::Anticipation;
+        }
+        let diatonic = self.key.scale12().degrees.contains(&melody[index].pitch_class());
+        match (approach, departure) {
+            (Some(into), Some(out)) if step(into) && step(out) && into.signum() == out.signum() => {
+                return NoteRole::Passing;
+            }
+            (Some(into), Some(out)) if step(into) && into + out == 0 => return NoteRole::Neighbor,
+            _ => {}
+        }
+        if !diatonic && departure.is_some_and(|out| out.abs() == 1) && context.next_is_chord_tone {
+            return NoteRole::ChromaticApproach;
+        }
+        match (approach, departure) {
+            (Some(into), Some(out)) if leap(into) && step(out) && context.accented => {
+                NoteRole::Appoggiatura
+            }
+            (Some(into), Some(out))
+                if step(into)
+                    && leap(out)
+                    && into.signum() != out.signum()
+                    && !context.accented =>
+            {
+                NoteRole::EscapeTone
+            }
+            _ => NoteRole::Unclassified,
+        }
+    }
+}
+
+/// Index of the last chord change at or before `onset`.
+fn active_chord(chords: &[(TimePoint, Chord12)], onset: TimePoint) -> Option<usize> {
+    chords.iter().rposition(|(start, _)| *start <= onset)
+}
+
+/// Index of the last chord change while `note` sounds (after its onset, before its end).
+fn changed_chord(chords: &[(TimePoint, Chord12)], note: &MelodyNote) -> Option<usize> {
+    chords.iter().rposition(|(start, _)| *start > note.onset && *start < note.end())
+}
+
+fn motion(melody: &[MelodyNote], from: usize, to: usize) -> i32 {
+    melody[to].pitch.absolute_steps() - melody[from].pitch.absolute_steps()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch::{Pitch12, PitchClass12};
+    use music_time::{GridConfig, Meter, TimeSpan};
+
+    fn pc(semitones: u16) -> PitchClass12 {
+        PitchClass12::from_semitones(semitones)
+    }
+
+    fn note(semitones: u16, octave: i16, onset: f64, duration: f64) -> MelodyNote {
+        let pitch = Pitch12::from_semitones_and_octave(semitones, octave);
+        MelodyNote::new(pitch, TimePoint::new(onset), TimeSpan::new(duration))
+    }
+
+    fn analyzer(bars: u32) -> NonChordToneAnalyzer {
+        let grid = GridConfig::new(TimePoint::new(0.0), Meter::FOUR_FOUR)
+            .bars(bars)
+            .subdivisions_per_beat(2)
+            .build();
+        NonChordToneAnalyzer::new(Key12::major(pc(0)), grid)
+    }
+
+    fn roles(melody: &[MelodyNote], chords: &[(TimePoint, Chord12)]) -> Vec<NoteRole> {
+        analyzer(4).analyze(melody, chords).iter().map(|analysis| analysis.role).collect()
+    }
+
+    fn at(beat: f64, chord: Chord12) -> (TimePoint, Chord12) {
+        (TimePoint::new(beat), chord)
+    }
+
+    #[test]
+    fn neighbors_appoggiaturas_escapes_and_chromatic_approaches() {
+        use NoteRole::{Appoggiatura, ChordTone, Neighbor};
+        let c = [at(0.0, Chord12::major_triad(pc(0)))];
+        // E–F–E neighbor, then leap to A resolving down to G (appoggiatura on beat 3).
+        let melody = [
+            note(4, 5, 0.0, 1.0),
+            note(5, 5, 1.0, 1.0),
+            note(4, 5, 1.5, 0.5),
+            note(9, 5, 2.0, 1.0),
+            note(7, 5, 2.5, 1.0),
+        ];
+        assert_eq!(roles(&melody, &c), [ChordTone, Neighbor, ChordTone, Appoggiatura, ChordTone]);
+
+        // G–A (offbeat) leaping down to E: escape tone. Then C leaps to D#, leading into E.
+        let melody = [
+            note(7, 5, 0.0, 1.0),
+            note(9, 5, 1.5, 0.5),
+            note(4, 5, 2.0, 1.0),
+            note(0, 5, 3.0, 0.5),
+            note(3, 5, 3.5, 0.5),
+            note(4, 5, 4.0, 1.0),
+        ];
+        let found = roles(&melody, &c);
+        assert_eq!(found[1], NoteRole::EscapeTone);
+        assert_eq!(found[4], NoteRole::ChromaticApproach);
+    }
+
+    #[test]
+    fn suspensions_anticipations_and_pedals_follow_the_harmony() {
+        // C held from C major into G major resolves down to B; the offbeat D over A minor
+        // anticipates G major; a repeated G through C–F–C is a pedal.
+        let chords = [
+            at(0.0, Chord12::major_triad(pc(0))),
+            at(4.0, Chord12::major_triad(pc(7))),
+            at(8.0, Chord12::minor_triad(pc(9))),
+            at(12.0, Chord12::major_triad(pc(7))),
+        ];
+        let melody = [
+            note(0, 5, 0.0, 4.0),
+            note(0, 5, 4.0, 2.0),
+            note(11, 4, 6.0, 2.0),
+            note(0, 5, 8.0, 3.5),
+            note(2, 5, 11.5, 0.5),
+            note(2, 5, 12.0, 2.0),
+        ];
+        let analysis = analyzer(4).analyze(&melody, &chords);
+        assert_eq!(analysis[1].role, NoteRole::Suspension { preparation: 0, resolution: 2 });
+        assert_eq!(analysis[1].metric, Some(MetricLevel::Downbeat));
+        assert_eq!(
+            analysis[1].role.to_string(),
+            "suspension (prepared by note 1, resolves to note 3)"
+        );
+        assert_eq!(analysis[4].role, NoteRole::Anticipation);
+        assert_eq!(analysis[4].chord, Some(2));
+
+        let pedal = [note(7, 4, 0.0, 2.0), note(7, 4, 2.0, 2.0), note(7, 4, 4.0, 2.0)];
+        let changes = [
+            at(0.0, Chord12::major_triad(pc(0))),
+            at(2.0, Chord12::major_triad(pc(5))),
+            at(4.0, Chord12::major_triad(pc(0))),
+        ];
+        assert_eq!(roles(&pedal, &changes)[1], NoteRole::Pedal);
+        let static_harmony = [at(0.0, Chord12::minor_triad(pc(2)))];
+        assert_ne!(roles(&pedal, &static_harmony)[1], NoteRole::Pedal);
+    }
+
+    #[test]
+    fn notes_before_the_first_chord_are_unclassified() {
+        let chords = [at(1.0, Chord12::major_triad(pc(0)))];
+        let melody = [note(0, 5, 0.0, 1.0), note(7, 5, 1.0, 0.5), note(5, 5, 1.5, 0.5)];
+        let found = roles(&melody, &chords);
+        assert_eq!(found[0], NoteRole::Unclassified);
+        assert!(found[1].is_chord_tone());
+        let grid = analyzer(1).grid;
+        assert_eq!(MetricLevel::of(&grid, TimePoint::new(1.25)), Some(MetricLevel::Offbeat));
+        assert_eq!(MetricLevel::of(&grid, TimePoint::new(4.0)), Some(MetricLevel::Downbeat));
+        assert_eq!(MetricLevel::of(&grid, TimePoint::new(5.0)), None);
+    }
+
+    #[test]
+    fn tied_suspensions_are_read_against_the_new_chord() {
+        // C tied from beat 3 of a C major bar over the change to G major, resolving down to B.
+        let chords = [at(0.0, Chord12::major_triad(pc(0))), at(4.0, Chord12::major_triad(pc(7)))];
+        let melody = [note(4, 5, 0.0, 2.0), note(0, 5, 2.0, 4.0), note(11, 4, 6.0, 2.0)];
+        let analysis = analyzer(2).analyze(&melody, &chords);
+        assert_eq!(analysis[1].role, NoteRole::Suspension { preparation: 1, resolution: 2 });
+        assert_eq!(analysis[1].chord, Some(1));
+        assert!(analysis[2].role.is_chord_tone());
+    }
+}
@@ -0,0 +1,346 @@
+//! core/music-theory/src/partwriting.rs
+//! Part-writing rule checker for sequences of four-voice [`SatbChord`]s.
+//!
+//! [`PartWritingChecker::check`] reports every [`Violation`] with its [`PartWritingRule`] id,
+//! the index of the chord where it occurs, and the voices involved. Motion rules (parallels,
+//! hidden fifths, overlap, resolutions) point at the second chord of the offending pair.
+//! Leading-tone and seventh rules need a key; the remaining rules are key-independent.
+//!
+//! ```
+//! use music_theory::{
+//!     Key12, PartWritingChecker, PartWritingRule, Pitch12, PitchClass12, SatbChord,
+//! };
+//! let p = Pitch12::from_semitones_and_octave;
+//! // C major to D minor with every voice moving up a step: parallel fifths and octaves.
+//! let progression = [
+//!     SatbChord::new(p(0, 5), p(7, 4), p(4, 4), p(0, 3)),
+//!     SatbChord::new(p(2, 5), p(9, 4), p(5, 4), p(2, 3)),
+//! ];
+//! let checker = PartWritingChecker::new(Some(Key12::major(PitchClass12::from_semitones(0))));
+//! let rules: Vec<_> = checker.check(&progression).iter().map(|v| v.rule).collect();
+//! assert!(rules.contains(&PartWritingRule::ParallelFifths));
+//! assert!(rules.contains(&PartWritingRule::ParallelOctaves));
+//! ```
+
+use core::fmt;
+
+use crate::chord::{Chord12, ChordKind};
+use crate::interval::Interval12;
+use crate::key::Key12;
+use crate::pitch::{Pitch12, PitchClass12};
+use crate::satb::{SatbChord, Voice};
+
+/// Part-writing rules checked by [`PartWritingChecker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum PartWritingRule {
+    /// Two voices a perfect fifth apart both move to another perfect fifth.
+    ParallelFifths,
+    /// Two voices an octave or unison apart both move to another octave or unison.
+    ParallelOctaves,
+    /// Outer voices approach a perfect fifth in similar motion with a soprano leap.
+    HiddenFifths,
+    /// Outer voices approach an octave in similar motion with a soprano leap.
+    HiddenOctaves,
+    /// A voice sounds above the voice written over it (or below the one under it).
+    VoiceCrossing,
+    /// A voice moves past the previous pitch of an adjacent voice.
+    VoiceOverlap,
+    /// More than an octave between soprano–alto or alto–tenor.
+    Spacing,
+    /// A voice lies outside its range.
+    Range,
+    /// The leading tone of a dominant chord fails to rise to the tonic.
+    UnresolvedLeadingTone,
+    /// A chordal seventh fails to resolve down by step.
+    UnresolvedSeventh,
+    /// The leading tone appears in more than one voice.
+    DoubledLeadingTone,
+}
+
+impl PartWritingRule {
+    /// Stable kebab-case rule id (`"parallel-fifths"`).
+    #[must_use]
+    pub const fn id(self) -> &'static str {
+        match self {
+            Self::ParallelFifths => "parallel-fifths",
+            Self::ParallelOctaves => "parallel-octaves",
+            Self::HiddenFifths => "hidden-fifths",
+            Self::HiddenOctaves => "hidden-octaves",
+            Self::VoiceCrossing => "voice-crossing",
+            Self::VoiceOverlap => "voice-overlap",
+            Self::Spacing => "spacing",
+            Self::Range => "range",
+            Self::UnresolvedLeadingTone => "unresolved-leading-tone",
+            Self::UnresolvedSeventh => "unresolved-seventh",
+            Self::DoubledLeadingTone => "doubled-leading-tone",
+        }
+    }
+}
+
+/// A rule violation located at a chord index and set of voices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub rule: PartWritingRule,
+    /// Index of the chord where the violation is heard.
+    pub chord: usize,
+    /// Voices involved, top to bottom.
+    pub voices: Vec<Voice>,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let voices: Vec<&str> = self.voices.iter().map(|voice| voice.name()).collect();
+        write!(f, "chord {} ({}): {}", self.chord + 1, voices.join("/"), self.rule.id())
+    }
+}
+
+/// Configurable SATB rule checker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartWritingChecker {
+    /// Key used for leading-tone and seventh rules; `None` skips them.
+    pub key: Option<Key12>,
+    /// Allowed `(lowest, highest)` pitch per voice, soprano first.
+    pub ranges: [(Pitch12, Pitch12); 4],
+}
+
+impl PartWritingChecker {
+    /// Checker with textbook ranges (see [`Voice::default_range`]).
+    #[must_use]
+    pub fn new(key: Option<Key12>) -> Self {
+        Self { key, ranges: Voice::ALL.map(Voice::default_range) }
+    }
+
+    /// Every violation in the progression, ordered by chord index.
+    #[must_use]
+    pub fn check(&self, chords: &[SatbChord]) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        for (index, chord) in chords.iter().enumerate() {
+            self.check_chord(index, chord, &mut violations);
+            if let Some(previous) = index.checked_sub(1).map(|i| &chords[i]) {
+                self.check_motion(index, previous, chord, &mut violations);
+            }
+        }
+        violations.sort_by_key(|violation| violation.chord);
+        violations
+    }
+
+    /// Whether the progression breaks no rules.
+    #[must_use]
+    pub fn is_valid(&self, chords: &[SatbChord]) -> bool {
+        self.check(chords).is_empty()
+    }
+
+    /// Violations heard within a single chord (range, crossing, spacing, doubling).
+    pub(crate) fn chord_violations(&self, chord: &SatbChord) -> usize {
+        let mut violations = Vec::new();
+        self.check_chord(0, chord, &mut violations);
+        violations.len()
+    }
+
+    /// Violations produced by moving from one chord to the next.
+    pub(crate) fn motion_violations(&self, from: &SatbChord, to: &SatbChord) -> usize {
+        let mut violations = Vec::new();
+        self.check_motion(1, from, to, &mut violations);
+        violations.len()
+    }
+
+    fn check_chord(&self, index: usize, chord: &SatbChord, out: &mut Vec<Violation>) {
+        let push = |out: &mut Vec<Violation>, rule, voices: &[Voice]| {
+            out.push(Violation { rule, chord: index, voices: voices.to_vec() });
+        };
+        for voice in Voice::ALL {
+            let (low, high) = self.ranges[voice.index()];
+            let pitch = chord.voice(voice);
+            if pitch < low || pitch > high {
+                push(out, PartWritingRule::Range, &[voice]);
+            }
+        }
+        for pair in Voice::ALL.windows(2) {
+            let (upper, lower) = (pair[0], pair[1]);
+            let gap = distance(chord.voice(lower), chord.voice(upper));
+            if gap < 0 {
+                push(out, PartWritingRule::VoiceCrossing, pair);
+            } else if gap > 12 && lower != Voice::Bass {
+                push(out, PartWritingRule::Spacing, pair);
+            }
+        }
+        if let Some(leading) = self.leading_tone() {
+            let doubled = chord.voices_with(leading);
+            if doubled.len() > 1 {
+                push(out, PartWritingRule::DoubledLeadingTone, &doubled);
+            }
+        }
+    }
+
+    fn check_motion(
+        &self,
+        index: usize,
+        from: &SatbChord,
+        to: &SatbChord,
+        out: &mut Vec<Violation>,
+    ) {
+        let mut push =
+            |rule, voices: Vec<Voice>| out.push(Violation { rule, chord: index, voices });
+        for (position, upper) in Voice::ALL.into_iter().enumerate() {
+            for lower in Voice::ALL.into_iter().skip(position + 1) {
+                let before = distance(from.voice(lower), from.voice(upper));
+                let after = distance(to.voice(lower), to.voice(upper));
+                let both_move =
+                    from.voice(upper) != to.voice(upper) && from.voice(lower) != to.voice(lower);
+                if !both_move {
+                    continue;
+                }
+                let class = |steps: i32| steps.rem_euclid(12);
+                if class(before) == 7 && class(after) == 7 {
+                    push(PartWritingRule::ParallelFifths, vec![upper, lower]);
+                } else if class(before) == 0 && class(after) == 0 {
+                    push(PartWritingRule::ParallelOctaves, vec![upper, lower]);
+                }
+            }
+        }
+
+        let soprano_motion = distance(from.soprano, to.soprano);
+        let bass_motion = distance(from.bass, to.bass);
+        let similar = soprano_motion.signum() == bass_motion.signum() && soprano_motion != 0;
+        let outer = distance(to.bass, to.soprano).rem_euclid(12);
+        let before = distance(from.bass, from.soprano).rem_euclid(12);
+        if similar && soprano_motion.abs() > 2 && outer != before {
+            match outer {
+                7 => push(PartWritingRule::HiddenFifths, vec![Voice::Soprano, Voice::Bass]),
+                0 => push(PartWritingRule::HiddenOctaves, vec![Voice::Soprano, Voice::Bass]),
+                _ => {}
+            }
+        }
+
+        for pair in Voice::ALL.windows(2) {
+            let (upper, lower) = (pair[0], pair[1]);
+            if to.voice(lower) > from.voice(upper) || to.voice(upper) < from.voice(lower) {
+                push(PartWritingRule::VoiceOverlap, pair.to_vec());
+            }
+        }
+
+        for (rule, voice) in self.resolutions(from, to) {
+            push(rule, vec![voice]);
+        }
+    }
+
+    /// Unresolved leading tones and sevenths between two chords.
+    fn resolutions(&self, from: &SatbChord, to: &SatbChord) -> Vec<(PartWritingRule, Voice)> {
+        let mut found = Vec::new();
+        let Some(key) = self.key else {
+            return found;
+        };
+        let harmony = analyze(from);
+        if harmony == analyze(to) {
+            return found;
+        }
+        let tonic_follows = to.pitches().iter().any(|pitch| pitch.pitch_class == key.tonic);
+        let dominant = harmony.is_some_and(|(root, _)| {
+            let fifth = key.tonic.transpose(Interval12::from_semitones(7));
+            root == fifth || Some(root) == self.leading_tone()
+        });
+        for voice in Voice::ALL {
+            let (start, end) = (from.voice(voice), to.voice(voice));
+            let motion = distance(start, end);
+            if dominant && tonic_follows && Some(start.pitch_class) == self.leading_tone() {
+                let inner_drop = matches!(voice, Voice::Alto | Voice::Tenor) && motion == -4;
+                if motion != 1 && !inner_drop {
+                    found.push((PartWritingRule::UnresolvedLeadingTone, voice));
+                }
+            }
+            if let Some((root, ChordKind::Seventh(kind))) = harmony {
+                let seventh = root
+                    .transpose(Interval12::from_semitones(ChordKind::Seventh(kind).intervals()[3]));
+                if start.pitch_class == seventh && !matches!(motion, -2 | -1) {
+                    found.push((PartWritingRule::UnresolvedSeventh, voice));
+                }
+            }
+        }
+        found
+    }
+
+    fn leading_tone(&self) -> Option<PitchClass12> {
+        self.key.map(|key| key.tonic.transpose(Interval12::from_semitones(-1)))
+    }
+}
+
+/// Root and kind of the sonority, if it spells a known chord.
+fn analyze(chord: &SatbChord) -> Option<(PitchClass12, ChordKind)> {
+    let mut tones: Vec<PitchClass12> = Vec::with_capacity(4);
+    for pitch in chord.pitches().iter().rev() {
+        if !tones.contains(&pitch.pitch_class) {
+            tones.push(pitch.pitch_class);
+        }
+    }
+    let (chord, kind) = Chord12::new(tones).identify_inversion()?;
+    Some((chord.root()?, kind))
+}
+
+/// Signed semitone distance from `low` up to `high`.
+fn distance(low: Pitch12, high: Pitch12) -> i32 {
+    high.absolute_steps() - low.absolute_steps()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(semitones: u16, octave: i16) -> Pitch12 {
+        Pitch12::from_semitones_and_octave(semitones, octave)
+    }
+
+    fn c_major() -> PartWritingChecker {
+        PartWritingChecker::new(Some(Key12::major(PitchClass12::from_semitones(0))))
+    }
+
+    fn rules(violations: &[Violation]) -> Vec<&'static str> {
+        violations.iter().map(|violation| violation.rule.id()).collect()
+    }
+
+    #[test]
+    fn textbook_cadence_is_clean() {
+        // I – IV – V7 – I in C major.
+        let progression = [
+            SatbChord::new(p(4, 5), p(7, 4), p(0, 4), p(0, 3)),
+            SatbChord::new(p(5, 5), p(9, 4), p(0, 4), p(5, 2)),
+            SatbChord::new(p(2, 5), p(5, 4), p(11, 3), p(7, 2)),
+            SatbChord::new(p(0, 5), p(4, 4), p(0, 4), p(0, 3)),
+        ];
+        let violations = c_major().check(&progression);
+        assert!(violations.is_empty(), "{violations:?}");
+    }
+
+    #[test]
+    fn chord_rules_report_voices() {
+        // Bass too low, alto above soprano, tenor far below alto, doubled leading tone.
+        let chord = SatbChord::new(p(7, 4), p(11, 4), p(2, 3), p(11, 1));
+        let violations = c_major().check(&[chord]);
+        assert_eq!(
+            rules(&violations),
+            ["range", "voice-crossing", "spacing", "doubled-leading-tone"]
+        );
+        assert_eq!(violations[1].voices, [Voice::Soprano, Voice::Alto]);
+        assert_eq!(violations[3].to_string(), "chord 1 (alto/bass): doubled-leading-tone");
+    }
+
+    #[test]
+    fn motion_rules_catch_hidden_fifths_overlap_and_resolutions() {
+        // V7 -> I with the leading tone leaping away and the seventh rising; soprano leaps
+        // into a fifth with the bass in similar motion.
+        let progression = [
+            SatbChord::new(p(11, 4), p(5, 4), p(2, 4), p(7, 2)),
+            SatbChord::new(p(7, 5), p(7, 4), p(4, 4), p(0, 3)),
+        ];
+        let found = rules(&c_major().check(&progression));
+        for expected in ["hidden-fifths", "unresolved-leading-tone", "unresolved-seventh"] {
+            assert!(found.contains(&expected), "{expected} missing from {found:?}");
+        }
+        let overlap = [
+            SatbChord::new(p(0, 5), p(7, 4), p(4, 4), p(0, 3)),
+            SatbChord::new(p(0, 5), p(4, 4), p(9, 4), p(5, 3)),
+        ];
+        assert!(rules(&c_major().check(&overlap)).contains(&"voice-overlap"));
+    }
+}
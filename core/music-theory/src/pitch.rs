@@ -77,6 +77,26 @@ impl<T: Temperament> Pitch<T> {
         Self { pitch_class, octave }
     }
 
+    /// Pitch at an absolute step count above pitch-class 0 of octave 0.
+    #[must_use]
+    pub fn from_absolute_steps(steps: i32) -> Self {
+        let steps_per_octave = i32::from(T::STEPS_PER_OCTAVE);
+        let index = u16::try_from(steps.rem_euclid(steps_per_octave)).unwrap_or_default();
+        let octave = i16::try_from(steps.div_euclid(steps_per_octave)).unwrap_or(if steps < 0 {
+            i16::MIN
+        } else {
+            i16::MAX
+        });
+        Self::new(PitchClass::new(index), octave)
+    }
+
+    /// Absolute step count above pitch-class 0 of octave 0 (`C0` in 12-TET).
+    #[must_use]
+    pub fn absolute_steps(&self) -> i32 {
+        i32::from(self.octave) * i32::from(T::STEPS_PER_OCTAVE)
+            + i32::from(self.pitch_class.index())
+    }
+
     /// Transpose the pitch by a temperament-specific interval.
     #[must_use]
     pub fn transpose(self, interval: Interval<T>) -> Self {
@@ -212,6 +232,14 @@ mod tests {
         assert_eq!(back.octave, 4);
     }
 
+    #[test]
+    fn absolute_steps_round_trip() {
+        for steps in [-13, -1, 0, 11, 12, 60] {
+            assert_eq!(Pitch12::from_absolute_steps(steps).absolute_steps(), steps);
+        }
+        assert_eq!(Pitch12::from_absolute_steps(-1), Pitch12::from_semitones_and_octave(11, -1));
+    }
+
     #[test]
     fn octave_shifts_and_ordering() {
         let g4 = Pitch12::from_semitones_and_octave(7, 4);
@@ -0,0 +1,229 @@
+//! core/music-theory/src/polychord.rs
+//! Compound chord structures: hybrid chords (triad over a bass) and polychords.
+//!
+//! A [`CompoundChord`] keeps its component chords apart: a hybrid chord is a triad or seventh
+//! chord over a foreign bass note (`F/G`), a polychord an upper triad or seventh chord over a
+//! lower one (`D|C`, written with a bar to distinguish it from a slash bass).
+//! [`Chord::identify_compound`] recovers the structure from a flat [`Chord`], and
+//! [`CompoundChord::to_chord`] flattens it back.
+//!
+//! ```
+//! use music_theory::{Chord12, CompoundChord};
+//! // D over C7: the "upper structure" voicing of C13#11.
+//! let poly: CompoundChord<_> = "D|C7".parse().unwrap();
+//! let flat = poly.to_chord();
+//! assert_eq!(flat.len(), 7);
+//! assert_eq!(flat.identify_compound(), Some(poly));
+//!
+//! let hybrid: Chord12 = "F/G".parse().unwrap();
+//! assert_eq!(hybrid.identify_compound().unwrap().to_string(), "F/G");
+//! ```
+
+use core::fmt;
+use core::str::FromStr;
+
+use music_acoustic::{T12, Temperament};
+
+use crate::chord::{Chord, Chord12, ChordKind};
+use crate::pitch::PitchClass;
+use crate::text::ParseError;
+
+/// A chord built from separately named components.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CompoundChord<T: Temperament> {
+    /// Triad or seventh chord over a bass note that is not one of its tones (`F/G`).
+    Hybrid { upper: Chord<T>, bass: PitchClass<T> },
+    /// Upper chord stacked over a lower chord (`D|C`).
+    Polychord { upper: Chord<T>, lower: Chord<T> },
+}
+
+impl<T: Temperament> Clone for CompoundChord<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Hybrid { upper, bass } => Self::Hybrid { upper: upper.clone(), bass: *bass },
+            Self::Polychord { upper, lower } => {
+                Self::Polychord { upper: upper.clone(), lower: lower.clone() }
+            }
+        }
+    }
+}
+
+impl<T: Temperament> CompoundChord<T> {
+    /// Component chords from the bottom up; a hybrid's bass is a one-tone chord.
+    #[must_use]
+    pub fn components(&self) -> Vec<Chord<T>> {
+        match self {
+            Self::Hybrid { upper, bass } => vec![Chord::new(vec![*bass]), upper.clone()],
+            Self::Polychord { upper, lower } => vec![lower.clone(), upper.clone()],
+        }
+    }
+
+    /// Flatten into a single chord: lower tones first, sounding bass kept.
+    #[must_use]
+    pub fn to_chord(&self) -> Chord<T> {
+        match self {
+            Self::Hybrid { upper, bass } => upper.clone().with_bass(*bass),
+            Self::Polychord { upper, lower } => {
+                let mut tones = lower.tones.clone();
+                tones.extend(upper.tones.iter().filter(|tone| !lower.contains(**tone)));
+                let chord = Chord::new(tones);
+                match lower.sounding_bass() {
+                    Some(bass) => chord.with_bass(bass),
+                    None => chord,
+                }
+            }
+        }
+    }
+}
+
+impl<T: Temperament> Chord<T> {
+    /// Read the chord as a hybrid chord or polychord.
+    ///
+    /// An explicit bass outside a triad or seventh chord gives a hybrid (`D/C` reads as D over
+    /// a C bass, not as `D7/C`). Otherwise chords that [`Chord::kind`] names in root position
+    /// return `None`; the rest are split into a lower triad or seventh chord rooted on the bass
+    /// and an upper one contributing at least two new tones. The split is tried even when
+    /// [`Chord::identify_inversion`] finds a slash reading, so a flattened `D|C` stays `D|C`
+    /// rather than `D11/C`.
+    #[must_use]
+    pub fn identify_compound(&self) -> Option<CompoundChord<T>> {
+        if let Some(bass) = self.bass.filter(|bass| !self.contains(*bass)) {
+            let upper = Self::new(self.tones.clone());
+            if upper.kind().is_some_and(is_component) {
+                return Some(CompoundChord::Hybrid { upper, bass });
+            }
+        }
+        if self.kind().is_some() {
+            return None;
+        }
+        self.split_polychord()
+    }
+
+    fn split_polychord(&self) -> Option<CompoundChord<T>> {
+        let bass = self.sounding_bass()?;
+        let members = self.pitch_class_set();
+        let steps = i32::from(T::STEPS_PER_OCTAVE);
+        if members.len() < 5 || steps % 12 != 0 {
+            return None;
+        }
+        let build = |root: PitchClass<T>, kind: ChordKind| {
+            let intervals: Vec<i32> = kind.intervals().iter().map(|s| s * (steps / 12)).collect();
+            Self::from_intervals(root, &intervals)
+        };
+        let subset = |chord: &Self| chord.tones.iter().all(|tone| members.contains(tone));
+        let components: Vec<ChordKind> =
+            ChordKind::ALL.into_iter().filter(|k| is_component(*k)).collect();
+        components.iter().find_map(|lower_kind| {
+            let lower = build(bass, *lower_kind);
+            if !subset(&lower) {
+                return None;
+            }
+            let remaining: Vec<PitchClass<T>> =
+                members.iter().copied().filter(|tone| !lower.contains(*tone)).collect();
+            remaining.iter().find_map(|root| {
+                components.iter().find_map(|upper_kind| {
+                    let upper = build(*root, *upper_kind);
+                    let new_tones = upper.tones.iter().filter(|tone| !lower.contains(**tone));
+                    let fits = subset(&upper)
+                        && remaining.iter().all(|tone| upper.contains(*tone))
+                        && new_tones.count() >= 2;
+                    fits.then(|| CompoundChord::Polychord { upper, lower: lower.clone() })
+                })
+            })
+        })
+    }
+}
+
+/// Triads and seventh chords may serve as polychord and hybrid components.
+fn is_component(kind: ChordKind) -> bool {
+    matches!(kind, ChordKind::Triad(_) | ChordKind::Seventh(_))
+}
+
+impl fmt::Display for CompoundChord<T12> {
+    /// `"F/G"` for hybrids, `"D|C"` (upper over lower) for polychords.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hybrid { .. } => write!(f, "{}", self.to_chord()),
+            Self::Polychord { upper, lower } => write!(f, "{upper}|{lower}"),
+        }
+    }
+}
+
+impl FromStr for CompoundChord<T12> {
+    type Err = ParseError;
+
+    /// Accepts `"D|C"` / `"Eb|Cm7"` polychords and `"F/G"` hybrids (the slash bass must lie
+    /// outside the upper chord).
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if let Some((upper, lower)) = input.split_once('|') {
+            return Ok(Self::Polychord { upper: upper.parse()?, lower: lower.parse()? });
+        }
+        let chord: Chord12 = input.parse()?;
+        match chord.bass.filter(|bass| !chord.contains(*bass)) {
+            Some(bass) => Ok(Self::Hybrid { upper: chord.without_bass(), bass }),
+            None => Err(ParseError::InvalidChordSymbol(input.trim().to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chord::StackedKind;
+    use crate::pitch::PitchClass12;
+
+    fn pc(semitones: u16) -> PitchClass12 {
+        PitchClass12::from_semitones(semitones)
+    }
+
+    #[test]
+    fn hybrids_and_polychords_decompose() {
+        let hybrid: Chord12 = "F/G".parse().expect("slash chord");
+        let Some(CompoundChord::Hybrid { upper, bass }) = hybrid.identify_compound() else {
+            panic!("F/G should be a hybrid chord");
+        };
+        assert_eq!((upper.to_string(), bass), ("F".to_string(), pc(7)));
+        let poly: CompoundChord<T12> = "D|C7".parse().expect("polychord");
+        let names: Vec<String> = poly.components().iter().map(ToString::to_string).collect();
+        assert_eq!(names, ["C7", "D"]);
+        assert_eq!(poly.to_chord().identify_compound(), Some(poly));
+        assert!("C/E".parse::<CompoundChord<T12>>().is_err());
+    }
+
+    #[test]
+    fn flattened_polychords_round_trip() {
+        let poly: CompoundChord<T12> = "D|C".parse().expect("polychord");
+        let flat = poly.to_chord();
+        assert_eq!(flat.identify_compound(), Some(poly));
+        assert_eq!(flat.to_string(), "D|C");
+        assert_eq!("D|C".parse::<Chord12>(), Ok(flat));
+    }
+
+    #[test]
+    fn tertian_chords_are_not_split() {
+        let cmaj9: Chord12 = "Cmaj9".parse().expect("chord");
+        assert_eq!(cmaj9.identify_compound(), None);
+        let upper_structure = Chord12::new(vec![pc(0), pc(4), pc(7), pc(10), pc(2), pc(6), pc(9)]);
+        // C13#11 is not in the kind tables, so it reads as D over C7.
+        let Some(CompoundChord::Polychord { upper, lower }) = upper_structure.identify_compound()
+        else {
+            panic!("expected polychord");
+        };
+        assert_eq!((upper.to_string(), lower.to_string()), ("D".to_string(), "C7".to_string()));
+    }
+
+    #[test]
+    fn quartal_and_quintal_stacks_are_named() {
+        let so_what = Chord12::stacked(pc(4), StackedKind::Quartal4);
+        assert_eq!(so_what.to_string(), "Equartal4");
+        assert_eq!(so_what.kind(), Some(ChordKind::Stacked(StackedKind::Quartal4)));
+        let parsed: Chord12 = "Dquartal".parse().expect("quartal");
+        assert_eq!(parsed.tones, vec![pc(2), pc(7), pc(0)]);
+        let sus2 = Chord12::new(vec![pc(0), pc(7), pc(2)]);
+        assert_eq!(sus2.to_string(), "Csus2");
+        let fifths = Chord12::new(vec![pc(0), pc(7), pc(2), pc(9)]);
+        assert_eq!(fifths.to_string(), "Cquintal4");
+        assert_eq!(StackedKind::of(true, 4), Some(StackedKind::Quintal4));
+        assert_eq!(parsed.identify_compound(), None);
+    }
+}
@@ -0,0 +1,266 @@
+//! core/music-theory/src/progression.rs
+//! Named chord progressions as Roman-numeral templates, with realization and matching.
+//!
+//! Each [`Progression`] in [`Progression::CATALOG`] is a whitespace-separated numeral string
+//! read against the major scale on the tonic (`"i bVII bVI V"` for the Andalusian cadence).
+//! [`Progression::realize`] spells it in a [`Key12`]; [`Progression::find_all`] scans an
+//! analyzed chord sequence for every catalog entry in every transposition.
+//!
+//! ```
+//! use music_theory::{Chord12, Progression};
+//! let chords: Vec<Chord12> =
+//!     ["Dm7", "G7", "Cmaj7", "Fm7", "Bb7", "Ebmaj7"].iter().map(|c| c.parse().unwrap()).collect();
+//! let found: Vec<String> = Progression::find_all(&chords)
+//!     .iter()
+//!     .filter(|m| m.progression.name == "ii-V-I")
+//!     .map(ToString::to_string)
+//!     .collect();
+//! assert_eq!(found, ["ii-V-I in C major (chords 1-3)", "ii-V-I in Eb major (chords 4-6)"]);
+//!
+//! let backdoor = Progression::by_name("backdoor").unwrap();
+//! let in_g: Vec<String> = backdoor.realize("G major".parse().unwrap()).unwrap()
+//!     .iter()
+//!     .map(ToString::to_string)
+//!     .collect();
+//! assert_eq!(in_g, ["Cm7", "F7", "Gmaj7"]);
+//! ```
+
+use core::fmt;
+
+use crate::chord::Chord12;
+use crate::interval::Interval12;
+use crate::key::{Key12, Mode};
+use crate::roman::RomanNumeral;
+use crate::text::ParseError;
+
+/// A named progression template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Progression {
+    pub name: &'static str,
+    /// Mode of the key the progression is usually heard in (reported by matches).
+    pub mode: Mode,
+    /// Whitespace-separated Roman numerals, one chord per entry.
+    pub numerals: &'static str,
+}
+
+impl Progression {
+    /// Built-in progressions; blues forms list one chord per bar.
+    pub const CATALOG: [Self; 15] = [
+        Self::major("ii-V-I", "ii7 V7 Imaj7"),
+        Self::minor("minor ii-V-i", "iiø7 V7 i"),
+        Self::major("I-vi-ii-V", "Imaj7 vi7 ii7 V7"),
+        Self::major(
+            "rhythm changes A",
+            "I VI7 ii7 V7 iii7 VI7 ii7 V7 I I7 IV #iv°7 iii7 VI7 ii7 V7",
+        ),
+        Self::major("rhythm changes B", "III7 VI7 II7 V7"),
+        Self::major("12-bar blues", "I7 I7 I7 I7 IV7 IV7 I7 I7 V7 IV7 I7 V7"),
+        Self::major("quick-change blues", "I7 IV7 I7 I7 IV7 IV7 I7 I7 V7 IV7 I7 V7"),
+        Self::major("jazz blues", "I7 IV7 I7 v7 I7 IV7 #iv°7 I7 VI7 ii7 V7 I7 VI7 ii7 V7"),
+        Self::minor("minor blues", "i7 i7 i7 i7 iv7 iv7 i7 i7 bVI7 V7 i7 V7"),
+        Self::minor("Andalusian cadence", "i bVII bVI V"),
+        Self::major("Pachelbel", "I V vi iii IV I IV V"),
+        Self::major("Axis", "I V vi IV"),
+        Self::major("backdoor", "iv7 bVII7 Imaj7"),
+        Self::major("Coltrane cycle", "Imaj7 bIII7 bVImaj7 VII7 IIImaj7 V7 Imaj7"),
+        Self::major("gospel 1-4-1", "I IV I"),
+    ];
+
+    const fn major(name: &'static str, numerals: &'static str) -> Self {
+        Self { name, mode: Mode::Major, numerals }
+    }
+
+    const fn minor(name: &'static str, numerals: &'static str) -> Self {
+        Self { name, mode: Mode::Minor, numerals }
+    }
+
+    /// Catalog entry with the given name (case-insensitive).
+    #[must_use]
+    pub fn by_name(name: &str) -> Option<&'static Self> {
+        Self::CATALOG.iter().find(|progression| progression.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Parsed numerals of the template.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first numeral's [`ParseError`] if the template does not parse (never for
+    /// catalog entries).
+    pub fn roman_numerals(&self) -> Result<Vec<RomanNumeral>, ParseError> {
+        self.numerals.split_whitespace().map(str::parse).collect()
+    }
+
+    /// Chords of the progression with `key.tonic` as tonic.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the template does not parse.
+    pub fn realize(&self, key: Key12) -> Result<Vec<Chord12>, ParseError> {
+        let numerals = self.roman_numerals()?;
+        Ok(numerals.iter().filter_map(|numeral| numeral.realize(key)).collect())
+    }
+
+    /// Every occurrence of the progression in `chords`, in any transposition.
+    ///
+    /// Chord roots must match the template exactly; qualities match exactly or, for a looser
+    /// hit, share their underlying triad (`Dm G C` is a ii-V-I without sevenths). A template
+    /// that does not parse matches nothing.
+    #[must_use]
+    pub fn find_in(&self, chords: &[Chord12]) -> Vec<ProgressionMatch> {
+        let numerals = self.roman_numerals().unwrap_or_default();
+        let Some(first) = numerals.first() else {
+            return Vec::new();
+        };
+        chords
+            .windows(numerals.len())
+            .enumerate()
+            .filter_map(|(start, window)| {
+                let root = window.first()?.root()?;
+                let tonic =
+                    root.transpose(Interval12::from_semitones(-first.semitones_above_tonic()));
+                let mut exact = true;
+                for (chord, numeral) in window.iter().zip(&numerals) {
+                    let expected = tonic
+                        .transpose(Interval12::from_semitones(numeral.semitones_above_tonic()));
+                    if chord.root() != Some(expected) {
+                        return None;
+                    }
+                    match (chord.kind(), numeral.kind) {
+                        (Some(found), Some(wanted)) if found == wanted => {}
+                        (Some(found), Some(wanted))
+                            if found.triad().is_some() && found.triad() == wanted.triad() =>
+                        {
+                            exact = false
+                        }
+                        _ => return None,
+                    }
+                }
+                Some(ProgressionMatch {
+                    progression: *self,
+                    start,
+                    key: Key12::new(tonic, self.mode),
+                    exact,
+                })
+            })
+            .collect()
+    }
+
+    /// Occurrences of every catalog progression, ordered by position then longest first.
+    #[must_use]
+    pub fn find_all(chords: &[Chord12]) -> Vec<ProgressionMatch> {
+        let mut found: Vec<ProgressionMatch> =
+            Self::CATALOG.iter().flat_map(|progression| progression.find_in(chords)).collect();
+        found.sort_by_key(|m| (m.start, core::cmp::Reverse(m.len())));
+        found
+    }
+}
+
+/// A progression found in a chord sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgressionMatch {
+    pub progression: Progression,
+    /// Index of the first matched chord.
+    pub start: usize,
+    /// Key the template was matched in.
+    pub key: Key12,
+    /// Whether every chord quality matched exactly (not just its triad).
+    pub exact: bool,
+}
+
+impl ProgressionMatch {
+    /// Number of chords covered.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.progression.numerals.split_whitespace().count()
+    }
+
+    /// Whether the match covers no chords (never true for catalog entries).
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Index one past the last matched chord.
+    #[must_use]
+    pub fn end(&self) -> usize {
+        self.start + self.len()
+    }
+}
+
+impl fmt::Display for ProgressionMatch {
+    /// `"ii-V-I in Eb major (chords 4-6)"`, 1-indexed.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} in {} (chords {}-{})",
+            self.progression.name,
+            self.key,
+            self.start + 1,
+            self.end()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch::PitchClass12;
+
+    fn chords(symbols: &[&str]) -> Vec<Chord12> {
+        symbols.iter().filter_map(|symbol| symbol.parse().ok()).collect()
+    }
+
+    #[test]
+    fn catalog_templates_parse_and_realize() {
+        for progression in &Progression::CATALOG {
+            let count = progression.numerals.split_whitespace().count();
+            let numerals = progression.roman_numerals().map(|numerals| numerals.len());
+            assert_eq!(numerals, Ok(count), "{}", progression.name);
+        }
+        let key = Key12::minor(PitchClass12::from_semitones(9));
+        let andalusian =
+            Progression::by_name("andalusian cadence").and_then(|p| p.realize(key).ok());
+        let names: Vec<String> =
+            andalusian.unwrap_or_default().iter().map(ToString::to_string).collect();
+        assert_eq!(names, ["Am", "G", "F", "E"]);
+        let giant = Progression::by_name("Coltrane cycle")
+            .and_then(|p| p.realize(Key12::major(PitchClass12::from_semitones(11))).ok());
+        let names: Vec<String> =
+            giant.unwrap_or_default().iter().map(ToString::to_string).collect();
+        assert_eq!(names, ["Bmaj7", "D7", "Gmaj7", "Bb7", "Ebmaj7", "F#7", "Bmaj7"]);
+    }
+
+    #[test]
+    fn matcher_finds_transposed_and_loose_occurrences() {
+        let tune = chords(&["G", "D", "Em", "C", "Am", "D7", "Gmaj7"]);
+        let found = Progression::find_all(&tune);
+        let labels: Vec<String> = found.iter().map(ToString::to_string).collect();
+        assert!(labels.contains(&"Axis in G major (chords 1-4)".to_string()), "{labels:?}");
+        let two_five = found.iter().find(|m| m.progression.name == "ii-V-I").expect("ii-V-I");
+        assert_eq!((two_five.start, two_five.exact), (4, false));
+        assert_eq!(two_five.key.to_string(), "G major");
+    }
+
+    #[test]
+    fn blues_form_matches_exactly() {
+        let key = Key12::major(PitchClass12::from_semitones(10));
+        let blues = Progression::by_name("jazz blues")
+            .and_then(|p| p.realize(key).ok())
+            .unwrap_or_default();
+        let found = Progression::find_all(&blues);
+        assert_eq!(found[0].progression.name, "jazz blues");
+        assert!(found[0].exact);
+        assert_eq!((found[0].start, found[0].end()), (0, blues.len()));
+        // The turnaround's ii7-V7-I7 is only a loose ii-V-I (I7 for Imaj7).
+        let turnarounds = found.iter().filter(|m| m.progression.name == "ii-V-I");
+        assert!(turnarounds.clone().count() > 0 && turnarounds.clone().all(|m| !m.exact));
+
+        let custom = Progression { name: "plagal", mode: Mode::Major, numerals: "IV I" };
+        let amen = custom.realize(key).unwrap_or_default();
+        let found = custom.find_in(&amen);
+        assert_eq!((found.len(), found[0].progression), (1, custom));
+        let typo = Progression { numerals: "IV Q", ..custom };
+        assert_eq!(typo.roman_numerals(), Err(ParseError::InvalidRomanNumeral("Q".into())));
+        assert!(typo.find_in(&blues).is_empty());
+    }
+}
@@ -0,0 +1,316 @@
+//! core/music-theory/src/realize.rs
+//! Four-part (SATB) realization of chord progressions, Roman numerals, and figured bass.
+//!
+//! [`FourPartRealizer`] enumerates every voicing of each chord inside the checker's voice ranges
+//! (no crossing, upper voices within an octave of each other, every chord tone present except an
+//! optional fifth), then picks the cheapest path through them with dynamic programming. Voicings
+//! pay for doubling anything other than the root or bass and for omitting the fifth; transitions
+//! pay for voice-leading distance and heavily for every [`PartWritingChecker`] motion violation.
+//!
+//! ```
+//! use music_theory::{FiguredBass, FourPartRealizer, Key12, PitchClass12, RomanNumeral};
+//! let key = Key12::major(PitchClass12::from_semitones(0));
+//! let numeral = |roman: &str, figures: &str| (roman.parse().unwrap(), figures.parse().unwrap());
+//! let progression: Vec<(RomanNumeral, FiguredBass)> =
+//!     vec![numeral("I", ""), numeral("IV", ""), numeral("V7", "7"), numeral("I", "")];
+//! let realization = FourPartRealizer::new(key).realize_roman(&progression).unwrap();
+//! assert_eq!(realization.chords.len(), 4);
+//! assert!(realization.violations.is_empty());
+//! ```
+
+use crate::chord::Chord12;
+use crate::figured::FiguredBass;
+use crate::interval::Interval12;
+use crate::key::Key12;
+use crate::partwriting::{PartWritingChecker, Violation};
+use crate::pitch::{Pitch12, PitchClass12};
+use crate::roman::RomanNumeral;
+use crate::satb::{SatbChord, Voice};
+
+/// Cost added per part-writing violation.
+const VIOLATION_COST: f64 = 10.0;
+/// Cost per semitone of upper-voice motion.
+const UPPER_MOTION_COST: f64 = 0.25;
+/// Cost per semitone of bass motion when the bass register is free.
+const BASS_MOTION_COST: f64 = 0.1;
+/// Cost of leaving out the chord fifth.
+const OMITTED_FIFTH_COST: f64 = 2.0;
+/// Cost of doubling the bass when it is not the root.
+const BASS_DOUBLING_COST: f64 = 0.5;
+/// Cost of doubling any other non-root tone.
+const OTHER_DOUBLING_COST: f64 = 2.0;
+
+/// Best-scoring four-part realization of a progression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Realization {
+    pub chords: Vec<SatbChord>,
+    /// Total voicing and voice-leading cost (lower is better).
+    pub cost: f64,
+    /// Rules the realization still breaks, if no clean path exists.
+    pub violations: Vec<Violation>,
+}
+
+/// Searches for smooth, rule-abiding SATB voicings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FourPartRealizer {
+    pub key: Key12,
+    /// Checker supplying the voice ranges and rules to respect.
+    pub checker: PartWritingChecker,
+}
+
+/// Harmony to voice at one position of the progression.
+struct Slot {
+    tones: Vec<PitchClass12>,
+    root: PitchClass12,
+    bass: PitchClass12,
+    /// Registered bass given by a figured-bass line.
+    fixed_bass: Option<Pitch12>,
+}
+
+impl FourPartRealizer {
+    /// Realizer for `key` with the textbook ranges and rules.
+    #[must_use]
+    pub fn new(key: Key12) -> Self {
+        Self { key, checker: PartWritingChecker::new(Some(key)) }
+    }
+
+    /// Chord spelled by a Roman numeral and figures in the realizer's key.
+    ///
+    /// The chord is [`RomanNumeral::realize`]: the root is read against the major scale with
+    /// the numeral's alteration applied (`bVII` in C is Bb), so in minor write `V` or `V7` for
+    /// the raised leading tone. The figures choose the inversion. Returns `None` for an
+    /// unidentified numeral or figures asking for an inversion the chord does not have.
+    #[must_use]
+    pub fn roman_chord(&self, numeral: RomanNumeral, figures: &FiguredBass) -> Option<Chord12> {
+        let chord = numeral.realize(self.key)?;
+        let inversion = figures.inversion();
+        (inversion < chord.len()).then(|| chord.invert(inversion))
+    }
+
+    /// Voice a chord progression; slash basses and inversions fix the bass pitch class.
+    #[must_use]
+    pub fn realize_chords(&self, chords: &[Chord12]) -> Option<Realization> {
+        let slots = chords.iter().map(Self::chord_slot).collect::<Option<Vec<_>>>()?;
+        self.realize(&slots)
+    }
+
+    /// Voice a Roman-numeral progression (see [`FourPartRealizer::roman_chord`]).
+    #[must_use]
+    pub fn realize_roman(&self, numerals: &[(RomanNumeral, FiguredBass)]) -> Option<Realization> {
+        let chords = numerals
+            .iter()
+            .map(|(numeral, figures)| self.roman_chord(*numeral, figures))
+            .collect::<Option<Vec<_>>>()?;
+        self.realize_chords(&chords)
+    }
+
+    /// Realize the upper voices above a figured bass line; the bass pitches are kept as given.
+    #[must_use]
+    pub fn realize_figured_bass(&self, line: &[(Pitch12, FiguredBass)]) -> Option<Realization> {
+        let slots = line
+            .iter()
+            .map(|(bass, figures)| {
+                let tones = figures.pitch_classes(self.key, bass.pitch_class);
+                let root = Chord12::new(tones.clone())
+                    .identify_inversion()
+                    .and_then(|(chord, _)| chord.root())
+                    .unwrap_or(bass.pitch_class);
+                Slot { tones, root, bass: bass.pitch_class, fixed_bass: Some(*bass) }
+            })
+            .collect::<Vec<_>>();
+        self.realize(&slots)
+    }
+
+    fn chord_slot(chord: &Chord12) -> Option<Slot> {
+        let root = chord.root()?;
+        let bass = chord.sounding_bass()?;
+        let mut tones = chord.tones.clone();
+        if !tones.contains(&bass) {
+            tones.push(bass);
+        }
+        Some(Slot { tones, root, bass, fixed_bass: None })
+    }
+
+    /// Cheapest path through every slot's candidate voicings.
+    fn realize(&self, slots: &[Slot]) -> Option<Realization> {
+        let candidates: Vec<Vec<(SatbChord, f64)>> =
+            slots.iter().map(|slot| self.candidates(slot)).collect();
+        if candidates.iter().any(Vec::is_empty) {
+            return None;
+        }
+        let first = candidates.first()?;
+        let mut costs: Vec<f64> = first.iter().map(|(_, cost)| *cost).collect();
+        let mut back: Vec<Vec<usize>> = Vec::with_capacity(candidates.len());
+        for (window, slot) in candidates.windows(2).zip(slots.iter().skip(1)) {
+            let (previous, current) = (&window[0], &window[1]);
+            let mut next_costs = Vec::with_capacity(current.len());
+            let mut pointers = Vec::with_capacity(current.len());
+            for (to, static_cost) in current {
+                let (best, cost) = previous
+                    .iter()
+                    .zip(&costs)
+                    .enumerate()
+                    .map(|(index, ((from, _), cost))| {
+                        (index, cost + self.transition(from, to, slot.fixed_bass.is_none()))
+                    })
+                    .min_by(|a, b| a.1.total_cmp(&b.1))?;
+                next_costs.push(cost + static_cost);
+                pointers.push(best);
+            }
+            costs = next_costs;
+            back.push(pointers);
+        }
+        let (mut index, cost) =
+            costs.iter().copied().enumerate().min_by(|a, b| a.1.total_cmp(&b.1))?;
+        let mut chords = vec![candidates[candidates.len() - 1][index].0];
+        for (position, pointers) in back.iter().enumerate().rev() {
+            index = pointers[index];
+            chords.push(candidates[position][index].0);
+        }
+        chords.reverse();
+        let violations = self.checker.check(&chords);
+        Some(Realization { chords, cost, violations })
+    }
+
+    fn transition(&self, from: &SatbChord, to: &SatbChord, free_bass: bool) -> f64 {
+        let moved = |voice| {
+            let steps = to.voice(voice).absolute_steps() - from.voice(voice).absolute_steps();
+            f64::from(steps.abs())
+        };
+        let upper: f64 =
+            [Voice::Soprano, Voice::Alto, Voice::Tenor].into_iter().map(moved).sum::<f64>();
+        let bass = if free_bass { moved(Voice::Bass) * BASS_MOTION_COST } else { 0.0 };
+        let violations = self.checker.motion_violations(from, to);
+        upper * UPPER_MOTION_COST + bass + VIOLATION_COST * violations as f64
+    }
+
+    /// Every admissible voicing of a slot with its static cost.
+    fn candidates(&self, slot: &Slot) -> Vec<(SatbChord, f64)> {
+        let pitches = |voice: Voice| -> Vec<Pitch12> {
+            let (low, high) = self.checker.ranges[voice.index()];
+            (low.absolute_steps()..=high.absolute_steps())
+                .map(Pitch12::from_absolute_steps)
+                .filter(|pitch| slot.tones.contains(&pitch.pitch_class))
+                .collect()
+        };
+        let basses = match slot.fixed_bass {
+            Some(bass) => vec![bass],
+            None => pitches(Voice::Bass)
+                .into_iter()
+                .filter(|pitch| pitch.pitch_class == slot.bass)
+                .collect(),
+        };
+        let (sopranos, altos, tenors) =
+            (pitches(Voice::Soprano), pitches(Voice::Alto), pitches(Voice::Tenor));
+        let mut found = Vec::new();
+        for bass in &basses {
+            for tenor in tenors.iter().filter(|tenor| gap(*bass, **tenor) >= 0) {
+                for alto in altos.iter().filter(|alto| (0..=12).contains(&gap(*tenor, **alto))) {
+                    for soprano in sopranos.iter().filter(|s| (0..=12).contains(&gap(*alto, **s))) {
+                        let chord = SatbChord::new(*soprano, *alto, *tenor, *bass);
+                        if let Some(cost) = self.voicing_cost(slot, &chord) {
+                            found.push((chord, cost));
+                        }
+                    }
+                }
+            }
+        }
+        found
+    }
+
+    /// Doubling and omission cost, or `None` when a required tone is missing.
+    fn voicing_cost(&self, slot: &Slot, chord: &SatbChord) -> Option<f64> {
+        let fifth = slot.root.transpose(Interval12::from_semitones(7));
+        let mut cost = 0.0;
+        for tone in &slot.tones {
+            let count = chord.voices_with(*tone).len();
+            if count == 0 {
+                if *tone != fifth || slot.tones.len() < 3 {
+                    return None;
+                }
+                cost += OMITTED_FIFTH_COST;
+            }
+            let extra = count.saturating_sub(1) as f64;
+            cost += extra
+                * if *tone == slot.root {
+                    0.0
+                } else if *tone == slot.bass {
+                    BASS_DOUBLING_COST
+                } else {
+                    OTHER_DOUBLING_COST
+                };
+        }
+        Some(cost + VIOLATION_COST * self.checker.chord_violations(chord) as f64)
+    }
+}
+
+fn gap(low: Pitch12, high: Pitch12) -> i32 {
+    high.absolute_steps() - low.absolute_steps()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pc(semitones: u16) -> PitchClass12 {
+        PitchClass12::from_semitones(semitones)
+    }
+
+    fn p(semitones: u16, octave: i16) -> Pitch12 {
+        Pitch12::from_semitones_and_octave(semitones, octave)
+    }
+
+    #[test]
+    fn dominant_to_tonic_resolves_cleanly() {
+        let realizer = FourPartRealizer::new(Key12::major(pc(0)));
+        let chords = [Chord12::dominant_seventh(pc(7)), Chord12::major_triad(pc(0))];
+        let realization = realizer.realize_chords(&chords).expect("voicings exist");
+        assert!(realization.violations.is_empty(), "{:?}", realization.violations);
+        assert!(realizer.checker.is_valid(&realization.chords));
+        let [dominant, tonic] = [realization.chords[0], realization.chords[1]];
+        assert_eq!(dominant.bass.pitch_class, pc(7));
+        assert_eq!(tonic.bass.pitch_class, pc(0));
+        for chord in [dominant, tonic] {
+            assert!(chord.voices_with(pc(11)).len() <= 1);
+        }
+    }
+
+    #[test]
+    fn figured_bass_keeps_the_given_line() {
+        // A minor: i – V6 – i over A–G#–A.
+        let key = Key12::minor(pc(9));
+        let figures = |text: &str| text.parse::<FiguredBass>().expect(text);
+        let line = [(p(9, 2), figures("")), (p(8, 2), figures("6")), (p(9, 2), figures(""))];
+        let realization =
+            FourPartRealizer::new(key).realize_figured_bass(&line).expect("voicings exist");
+        let basses: Vec<Pitch12> = realization.chords.iter().map(|chord| chord.bass).collect();
+        assert_eq!(basses, [p(9, 2), p(8, 2), p(9, 2)]);
+        assert!(realization.violations.is_empty(), "{:?}", realization.violations);
+        let dominant = realization.chords[1];
+        for tone in [pc(4), pc(11), pc(8)] {
+            assert!(!dominant.voices_with(tone).is_empty());
+        }
+    }
+
+    #[test]
+    fn roman_numerals_in_minor_raise_the_leading_tone() {
+        let realizer = FourPartRealizer::new(Key12::minor(pc(9)));
+        let seventh: FiguredBass = "6/5".parse().expect("figures");
+        let dominant: RomanNumeral = "V7".parse().expect("numeral");
+        let chord = realizer.roman_chord(dominant, &seventh).expect("seventh chord");
+        assert_eq!(chord.tones, [pc(4), pc(8), pc(11), pc(2)]);
+        assert_eq!(chord.sounding_bass(), Some(pc(8)));
+        assert!(realizer.roman_chord(RomanNumeral::new(5, None), &seventh).is_none());
+        let triad: RomanNumeral = "V".parse().expect("numeral");
+        assert!(realizer.roman_chord(triad, &"4/2".parse().expect("figures")).is_none());
+    }
+
+    #[test]
+    fn altered_numerals_lower_the_root() {
+        let realizer = FourPartRealizer::new(Key12::major(pc(0)));
+        let subtonic: RomanNumeral = "bVII".parse().expect("numeral");
+        let chord = realizer.roman_chord(subtonic, &FiguredBass::default()).expect("triad");
+        assert_eq!(Some(chord.clone()), subtonic.realize(realizer.key));
+        assert_eq!(chord.tones, [pc(10), pc(2), pc(5)]);
+    }
+}
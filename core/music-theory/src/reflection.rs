@@ -0,0 +1,207 @@
+//! core/music-theory/src/reflection.rs
+//! Axis reflection and negative harmony for pitch classes, chords, and melodic lines.
+//!
+//! An [`Axis`] pairs pitch classes whose indices sum to a constant: `x` and `y` are mirror
+//! images when `x + y ≡ sum (mod steps)`. Negative harmony reflects around the midpoint of a
+//! key's tonic and dominant (`E/Eb` in C), so C↔G, E↔Eb, and B↔Ab. Reflected chords are
+//! re-identified by the simplest [`ChordKind`](crate::ChordKind) any of their tones roots.
+//!
+//! ```
+//! use music_theory::{Axis, Chord12, Key12, PitchClass12};
+//! let c = PitchClass12::from_semitones(0);
+//! let axis = Axis::negative_harmony(&Key12::major(c));
+//! assert_eq!(Chord12::major_seventh(c).reflect(axis).to_string(), "Abmaj7");
+//! let g7 = Chord12::dominant_seventh(PitchClass12::from_semitones(7));
+//! assert_eq!(g7.reflect(axis).to_string(), "Dm7b5");
+//! ```
+
+use core::marker::PhantomData;
+
+use music_acoustic::{T12, Temperament};
+
+use crate::chord::{Chord, ChordKind};
+use crate::key::Key;
+use crate::melody::MelodyNote;
+use crate::pitch::{Pitch, PitchClass};
+
+/// Mirror axis in a temperament, stored as the index sum of each mirrored pair.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Axis<T: Temperament> {
+    sum: u16,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Temperament> Copy for Axis<T> {}
+
+impl<T: Temperament> Clone for Axis<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Temperament> Axis<T> {
+    /// Axis mapping index `x` to `sum - x`.
+    #[must_use]
+    pub fn new(sum: u16) -> Self {
+        Self { sum: sum % T::STEPS_PER_OCTAVE, _marker: PhantomData }
+    }
+
+    /// Axis exchanging `a` and `b`.
+    #[must_use]
+    pub fn between(a: PitchClass<T>, b: PitchClass<T>) -> Self {
+        Self::new(a.index() + b.index())
+    }
+
+    /// Axis through `pitch_class` (it and its tritone partner stay fixed).
+    #[must_use]
+    pub fn through(pitch_class: PitchClass<T>) -> Self {
+        Self::between(pitch_class, pitch_class)
+    }
+
+    /// Negative-harmony axis of a key: the tonic–dominant midpoint.
+    #[must_use]
+    pub fn negative_harmony(key: &Key<T>) -> Self {
+        Self::between(key.tonic, key.degree_pitch_class(5).unwrap_or(key.tonic))
+    }
+
+    /// Index sum of every mirrored pair.
+    #[must_use]
+    pub fn sum(&self) -> u16 {
+        self.sum
+    }
+
+    /// Pitch classes the axis maps to themselves (none when it falls between steps).
+    #[must_use]
+    pub fn fixed_points(&self) -> Vec<PitchClass<T>> {
+        (0..T::STEPS_PER_OCTAVE)
+            .map(PitchClass::new)
+            .filter(|pitch_class| self.reflect(*pitch_class) == *pitch_class)
+            .collect()
+    }
+
+    /// Mirror image of a pitch class.
+    #[must_use]
+    pub fn reflect(&self, pitch_class: PitchClass<T>) -> PitchClass<T> {
+        let steps = T::STEPS_PER_OCTAVE;
+        PitchClass::new((self.sum + steps - pitch_class.index()) % steps)
+    }
+
+    /// Mirror a chord and re-identify it.
+    ///
+    /// The reflected tones are named by the first kind in [`ChordKind::ALL`] order that any of
+    /// them roots (tones tried in reflected order), so triads and seventh chords win over add
+    /// and extended readings, and returned in root position; an explicit bass is reflected too.
+    /// Unnamed results keep the reflected tone order.
+    #[must_use]
+    pub fn reflect_chord(&self, chord: &Chord<T>) -> Chord<T> {
+        let tones: Vec<PitchClass<T>> =
+            chord.tones.iter().map(|tone| self.reflect(*tone)).collect();
+        let mirrored = Chord::new(tones.clone());
+        let named = ChordKind::ALL
+            .into_iter()
+            .find_map(|kind| tones.iter().find_map(|root| mirrored.spelled_as(*root, kind)))
+            .map_or(mirrored, Chord::without_bass);
+        match chord.bass {
+            Some(bass) => named.with_bass(self.reflect(bass)),
+            None => named,
+        }
+    }
+
+    /// Mirror a registered line, inverting its contour around the first note's reflection.
+    ///
+    /// The first note lands on the nearest register of its mirrored pitch class; later notes
+    /// keep their (inverted) distances from it.
+    #[must_use]
+    pub fn reflect_line(&self, line: &[Pitch<T>]) -> Vec<Pitch<T>> {
+        let Some(first) = line.first() else {
+            return Vec::new();
+        };
+        let steps = i32::from(T::STEPS_PER_OCTAVE);
+        let start = first.absolute_steps();
+        let target = i32::from(self.reflect(first.pitch_class).index());
+        let up = (target - start).rem_euclid(steps);
+        let mirrored = if up * 2 <= steps { start + up } else { start + up - steps };
+        let total = start + mirrored;
+        line.iter()
+            .map(|pitch| Pitch::from_absolute_steps(total - pitch.absolute_steps()))
+            .collect()
+    }
+}
+
+impl Axis<T12> {
+    /// Mirror a timed melody, keeping onsets and durations.
+    #[must_use]
+    pub fn reflect_melody(&self, melody: &[MelodyNote]) -> Vec<MelodyNote> {
+        let pitches: Vec<Pitch<T12>> = melody.iter().map(|note| note.pitch).collect();
+        self.reflect_line(&pitches)
+            .into_iter()
+            .zip(melody)
+            .map(|(pitch, note)| MelodyNote { pitch, ..*note })
+            .collect()
+    }
+}
+
+impl<T: Temperament> PitchClass<T> {
+    /// Mirror image around `axis`.
+    #[must_use]
+    pub fn reflect(self, axis: Axis<T>) -> Self {
+        axis.reflect(self)
+    }
+}
+
+impl<T: Temperament> Chord<T> {
+    /// Mirror around `axis` and re-identify (see [`Axis::reflect_chord`]).
+    #[must_use]
+    pub fn reflect(&self, axis: Axis<T>) -> Self {
+        axis.reflect_chord(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chord::Chord12;
+    use crate::pitch::{Pitch12, PitchClass12};
+
+    fn pc(semitones: u16) -> PitchClass12 {
+        PitchClass12::from_semitones(semitones)
+    }
+
+    #[test]
+    fn negative_harmony_axis_swaps_tonic_and_dominant() {
+        let axis = Axis::negative_harmony(&Key::major(pc(0)));
+        assert_eq!(axis.sum(), 7);
+        assert_eq!(pc(0).reflect(axis), pc(7));
+        assert_eq!(pc(4).reflect(axis), pc(3));
+        assert!(axis.fixed_points().is_empty());
+        assert_eq!(Axis::<T12>::through(pc(2)).fixed_points(), vec![pc(2), pc(8)]);
+        let a_minor = Axis::negative_harmony(&Key::minor(pc(9)));
+        assert_eq!(a_minor, Axis::between(pc(9), pc(4)));
+    }
+
+    #[test]
+    fn reflected_chords_are_renamed() {
+        let axis = Axis::negative_harmony(&Key::major(pc(0)));
+        let name = |chord: Chord12| chord.reflect(axis).to_string();
+        assert_eq!(name(Chord12::major_triad(pc(0))), "Cm");
+        // Dm7 mirrors to F D Bb G: Gm7 rather than Bb6 (add13), a later kind in the tables.
+        assert_eq!(name(Chord12::minor_seventh(pc(2))), "Gm7");
+        assert_eq!(name(Chord12::dominant_seventh(pc(7))), "Dm7b5");
+        // C E G# B has no kind of its own; its mirror is Abm(maj7).
+        assert_eq!(name(Chord12::new(vec![pc(0), pc(4), pc(8), pc(11)])), "Abm(maj7)");
+        assert_eq!(name(Chord12::major_triad(pc(0)).with_bass(pc(4))), "Cm/Eb");
+        assert_eq!(Chord12::major_triad(pc(0)).reflect(Axis::through(pc(2))).to_string(), "Am");
+    }
+
+    #[test]
+    fn lines_invert_their_contour() {
+        let axis = Axis::negative_harmony(&Key::major(pc(0)));
+        let line: Vec<Pitch12> =
+            ["C4", "D4", "E4", "G4"].iter().filter_map(|p| p.parse().ok()).collect();
+        let mirrored: Vec<String> =
+            axis.reflect_line(&line).iter().map(ToString::to_string).collect();
+        assert_eq!(mirrored, ["G3", "F3", "Eb3", "C3"]);
+        let back: Vec<Pitch12> = axis.reflect_line(&axis.reflect_line(&line));
+        assert_eq!(back, line);
+    }
+}
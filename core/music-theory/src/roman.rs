@@ -0,0 +1,239 @@
+//! core/music-theory/src/roman.rs
+//! Roman-numeral labels for chords built on scale degrees.
+//!
+//! A [`RomanNumeral`] pairs a 1-indexed scale degree with the detected [`ChordKind`]. Case follows
+//! the underlying triad (upper for major/augmented, lower for minor/diminished) and the suffix
+//! follows the chord kind, so diatonic harmonizations render as `I`, `ii7`, `viiø7`, `V9`, ...
+//! Chromatic roots carry an alteration prefix (`bVII7`, `#iv°7`), and numerals parse back from
+//! the same notation.
+//!
+//! ```
+//! use music_theory::RomanNumeral;
+//! let borrowed: RomanNumeral = "bVII7".parse().unwrap();
+//! assert_eq!((borrowed.degree, borrowed.alteration), (7, -1));
+//! assert_eq!(borrowed.semitones_above_tonic(), 10);
+//! assert_eq!(borrowed.to_string(), "bVII7");
+//! ```
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::chord::{Chord12, ChordKind, ExtendedKind, SeventhKind, TriadKind};
+use crate::interval::Interval12;
+use crate::key::Key12;
+use crate::text::ParseError;
+
+const NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+const NUMERALS_LOWER: [&str; 7] = ["i", "ii", "iii", "iv", "v", "vi", "vii"];
+/// Semitones above the tonic of each major-scale degree.
+const MAJOR_DEGREE_SEMITONES: [i32; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// Roman-numeral description of a chord relative to a scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RomanNumeral {
+    /// Scale degree of the chord root (1–7).
+    pub degree: u8,
+    /// Detected chord kind; `None` (unidentified) renders as a lower-case numeral with a `?`
+    /// suffix (`"ii?"`) so it never reads as a quality.
+    pub kind: Option<ChordKind>,
+    /// Chromatic alteration of the root in semitones (`-1` for `bVII`, `1` for `#iv`).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub alteration: i8,
+}
+
+impl RomanNumeral {
+    #[must_use]
+    pub fn new(degree: u8, kind: Option<ChordKind>) -> Self {
+        Self { degree, kind, alteration: 0 }
+    }
+
+    /// Same numeral with its root raised (`alteration > 0`) or lowered by semitones.
+    #[must_use]
+    pub fn altered(self, alteration: i8) -> Self {
+        Self { alteration, ..self }
+    }
+
+    /// Numeral for `degree` whose root lies `semitones` above the tonic, altered against the
+    /// major-scale degree (`bIII` for a root a minor third up).
+    #[must_use]
+    pub fn on_root(degree: u8, kind: Option<ChordKind>, semitones: i32) -> Self {
+        let plain = Self::new(degree, kind);
+        let alteration = (semitones - plain.semitones_above_tonic() + 6).rem_euclid(12) - 6;
+        plain.altered(i8::try_from(alteration).unwrap_or(0))
+    }
+
+    /// Root distance above the tonic, reading the degree against the major scale (so minor-key
+    /// numerals spell their lowered degrees: `bIII`, `bVI`, `bVII`).
+    #[must_use]
+    pub fn semitones_above_tonic(&self) -> i32 {
+        let index = usize::from(self.degree.saturating_sub(1) % 7);
+        let octaves = i32::from(self.degree.saturating_sub(1) / 7) * 12;
+        MAJOR_DEGREE_SEMITONES[index] + octaves + i32::from(self.alteration)
+    }
+
+    /// Whether the numeral is written in upper case: a major or augmented triad base, or a
+    /// suspended chord (`V7sus4`). Quartal/quintal stacks and unidentified chords are lower case.
+    #[must_use]
+    pub fn is_upper_case(&self) -> bool {
+        self.kind.is_some_and(|kind| match kind.triad() {
+            Some(triad) => matches!(triad, TriadKind::Major | TriadKind::Augmented),
+            None => matches!(kind, ChordKind::Suspended(_)),
+        })
+    }
+
+    /// Numeral letters without quality suffix (`"ii"`, `"V"`), or `None` for degrees outside 1–7.
+    #[must_use]
+    pub fn numeral(&self) -> Option<&'static str> {
+        let index = usize::from(self.degree.checked_sub(1)?);
+        let table = if self.is_upper_case() { &NUMERALS } else { &NUMERALS_LOWER };
+        table.get(index).copied()
+    }
+
+    /// Quality suffix appended to the numeral (`"°"`, `"7"`, `"maj9"`, ...; `"?"` without a kind).
+    #[must_use]
+    pub fn suffix(&self) -> &'static str {
+        let Some(kind) = self.kind else {
+            return "?";
+        };
+        match kind {
+            ChordKind::Triad(TriadKind::Major | TriadKind::Minor) => "",
+            ChordKind::Triad(TriadKind::Diminished) => "°",
+            ChordKind::Triad(TriadKind::Augmented) => "+",
+            ChordKind::Seventh(SeventhKind::Major7 | SeventhKind::MinorMajor7) => "maj7",
+            ChordKind::Seventh(SeventhKind::Dominant7 | SeventhKind::Minor7) => "7",
+            ChordKind::Seventh(SeventhKind::HalfDiminished7) => "ø7",
+            ChordKind::Seventh(SeventhKind::Diminished7) => "°7",
+            ChordKind::Extended(kind) => match kind {
+                ExtendedKind::Add9 => "add9",
+                ExtendedKind::Add11 => "add11",
+                ExtendedKind::Add13 => "add13",
+                ExtendedKind::SixNine => "6/9",
+                ExtendedKind::Major9 => "maj9",
+                ExtendedKind::Dominant9 | ExtendedKind::Minor9 => "9",
+                ExtendedKind::Major11 => "maj11",
+                ExtendedKind::Dominant11 | ExtendedKind::Minor11 => "11",
+                ExtendedKind::Major13 => "maj13",
+                ExtendedKind::Dominant13 | ExtendedKind::Minor13 => "13",
+            },
+            ChordKind::Suspended(_) | ChordKind::Stacked(_) => kind.symbol(),
+        }
+    }
+
+    /// Chord spelled on this numeral with `key.tonic` as tonic (`None` without a kind).
+    #[must_use]
+    pub fn realize(&self, key: Key12) -> Option<Chord12> {
+        let root = key.tonic.transpose(Interval12::from_semitones(self.semitones_above_tonic()));
+        self.kind.map(|kind| Chord12::from_kind(root, kind))
+    }
+}
+
+impl fmt::Display for RomanNumeral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let accidental = if self.alteration < 0 { "b" } else { "#" };
+        f.write_str(&accidental.repeat(usize::from(self.alteration.unsigned_abs())))?;
+        match self.numeral() {
+            Some(numeral) => write!(f, "{numeral}{}", self.suffix()),
+            None => write!(f, "?{}", self.suffix()),
+        }
+    }
+}
+
+impl FromStr for RomanNumeral {
+    type Err = ParseError;
+
+    /// Accepts an optional `b`/`#` prefix, an upper- or lower-case numeral, and a suffix in
+    /// [`RomanNumeral::suffix`] form (plus `o`, `o7`, `h7`, `M7` aliases): `"bVII7"`, `"#iv°7"`,
+    /// `"iiø7"`. A bare numeral is a triad whose quality follows the case, and a `?` suffix
+    /// (`"ii?"`) leaves the kind unidentified.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let trimmed = input.trim();
+        let invalid = || ParseError::InvalidRomanNumeral(trimmed.to_string());
+        if trimmed.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let body = trimmed.trim_start_matches(['b', '#', '♭', '♯']);
+        let mut alteration: i8 = 0;
+        for ch in trimmed[..trimmed.len() - body.len()].chars() {
+            let step = if matches!(ch, 'b' | '♭') { -1 } else { 1 };
+            alteration = alteration.checked_add(step).ok_or_else(invalid)?;
+        }
+        let (degree, upper, suffix) = (0..7)
+            .rev()
+            .find_map(|index| {
+                let degree = u8::try_from(index + 1).ok()?;
+                [(NUMERALS[index], true), (NUMERALS_LOWER[index], false)].into_iter().find_map(
+                    |(numeral, upper)| body.strip_prefix(numeral).map(|rest| (degree, upper, rest)),
+                )
+            })
+            .ok_or_else(invalid)?;
+        let suffix = match suffix {
+            "o" => "°",
+            "o7" => "°7",
+            "h7" | "m7b5" => "ø7",
+            "M7" | "Δ7" => "maj7",
+            other => other,
+        };
+        if suffix == "?" {
+            return Ok(Self { degree, kind: None, alteration });
+        }
+        let kind = ChordKind::ALL
+            .into_iter()
+            .map(|kind| Self::new(degree, Some(kind)))
+            .find(|numeral| numeral.is_upper_case() == upper && numeral.suffix() == suffix)
+            .and_then(|numeral| numeral.kind)
+            .ok_or_else(invalid)?;
+        Ok(Self { degree, kind: Some(kind), alteration })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label(degree: u8, kind: ChordKind) -> String {
+        RomanNumeral::new(degree, Some(kind)).to_string()
+    }
+
+    #[test]
+    fn triads_follow_case_and_symbols() {
+        assert_eq!(label(1, ChordKind::Triad(TriadKind::Major)), "I");
+        assert_eq!(label(2, ChordKind::Triad(TriadKind::Minor)), "ii");
+        assert_eq!(label(7, ChordKind::Triad(TriadKind::Diminished)), "vii°");
+        assert_eq!(label(3, ChordKind::Triad(TriadKind::Augmented)), "III+");
+    }
+
+    #[test]
+    fn sevenths_and_extensions_append_suffixes() {
+        assert_eq!(label(1, ChordKind::Seventh(SeventhKind::Major7)), "Imaj7");
+        assert_eq!(label(5, ChordKind::Seventh(SeventhKind::Dominant7)), "V7");
+        assert_eq!(label(7, ChordKind::Seventh(SeventhKind::HalfDiminished7)), "viiø7");
+        assert_eq!(label(2, ChordKind::Extended(ExtendedKind::Minor9)), "ii9");
+        let sus = ChordKind::Suspended(crate::chord::SuspendedKind::Dominant7Sus4);
+        assert_eq!(label(5, sus), "V7sus4");
+        assert_eq!(label(2, ChordKind::Stacked(crate::chord::StackedKind::Quartal4)), "iiquartal4");
+        assert_eq!(RomanNumeral::new(2, None).to_string(), "ii?");
+        assert_eq!(RomanNumeral::new(9, None).to_string(), "??");
+        assert_eq!("bvii?".parse(), Ok(RomanNumeral::new(7, None).altered(-1)));
+    }
+
+    #[test]
+    fn altered_numerals_round_trip_through_text() {
+        for text in ["bVII7", "#iv°7", "iiø7", "bIIImaj7", "V", "vi", "bbVII", "III+", "Vsus4"] {
+            let numeral: RomanNumeral = text.parse().expect(text);
+            assert_eq!(numeral.to_string(), text);
+        }
+        let sharp_four: RomanNumeral = "#ivo7".parse().expect("alias");
+        assert_eq!(sharp_four.kind, Some(ChordKind::Seventh(SeventhKind::Diminished7)));
+        assert_eq!(sharp_four.semitones_above_tonic(), 6);
+        let minor_major: RomanNumeral = "imaj7".parse().expect("minor-major seventh");
+        assert_eq!(minor_major.kind, Some(ChordKind::Seventh(SeventhKind::MinorMajor7)));
+        assert_eq!(
+            "iiadd9".parse::<RomanNumeral>(),
+            Err(ParseError::InvalidRomanNumeral("iiadd9".into()))
+        );
+        assert!("X".parse::<RomanNumeral>().is_err());
+        let flats = format!("{}I", "b".repeat(130));
+        assert_eq!(flats.parse::<RomanNumeral>(), Err(ParseError::InvalidRomanNumeral(flats)));
+    }
+}
@@ -0,0 +1,392 @@
+//! core/music-theory/src/row.rs
+//! Twelve-tone (serial) rows: row forms, the serial matrix, combinatoriality, and derivation.
+//!
+//! A [`ToneRow`] is an ordering of every pitch class of its temperament exactly once (all 12 in
+//! 12-TET). Forms are labeled relative to the row itself: `P0` is the row as given, `Pn` its
+//! transposition up `n` steps, `In` the inversion starting on the same pitch class as `Pn`, and
+//! `Rn` / `RIn` the retrogrades of `Pn` / `In`.
+//!
+//! ```
+//! use music_theory::{RowForm, RowLabel, ToneRow12};
+//! // Webern, Concerto op. 24: derived from a single trichord.
+//! let row: ToneRow12 = "B Bb D Eb G F# Ab E F C C# A".parse().unwrap();
+//! assert!(row.derivation(3).is_some());
+//! let i0 = row.form(RowLabel::new(RowForm::Inversion, 0));
+//! assert_eq!(i0.to_string(), "B C Ab G Eb E D F# F Bb A C#");
+//! assert_eq!(row.matrix()[0], row.pitch_classes());
+//! ```
+
+use core::fmt;
+use core::str::FromStr;
+
+use music_acoustic::{T12, Temperament};
+
+use crate::pitch::PitchClass;
+
+/// The four serial operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum RowForm {
+    Prime,
+    Inversion,
+    Retrograde,
+    RetrogradeInversion,
+}
+
+impl RowForm {
+    pub const ALL: [Self; 4] =
+        [Self::Prime, Self::Inversion, Self::Retrograde, Self::RetrogradeInversion];
+
+    /// Conventional prefix (`"P"`, `"I"`, `"R"`, `"RI"`).
+    #[must_use]
+    pub const fn prefix(self) -> &'static str {
+        match self {
+            Self::Prime => "P",
+            Self::Inversion => "I",
+            Self::Retrograde => "R",
+            Self::RetrogradeInversion => "RI",
+        }
+    }
+
+    const fn is_inverted(self) -> bool {
+        matches!(self, Self::Inversion | Self::RetrogradeInversion)
+    }
+
+    const fn is_retrograde(self) -> bool {
+        matches!(self, Self::Retrograde | Self::RetrogradeInversion)
+    }
+}
+
+/// A row form at a transposition level (`P0`, `RI7`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RowLabel {
+    pub form: RowForm,
+    /// Transposition in steps relative to the row's first pitch class.
+    pub transposition: u16,
+}
+
+impl RowLabel {
+    #[must_use]
+    pub fn new(form: RowForm, transposition: u16) -> Self {
+        Self { form, transposition }
+    }
+}
+
+impl fmt::Display for RowLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.form.prefix(), self.transposition)
+    }
+}
+
+/// Why a pitch-class sequence is not a tone row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RowError {
+    /// The row must hold every pitch class of the temperament.
+    WrongLength { expected: usize, found: usize },
+    /// A pitch class (by index) appears more than once.
+    Repeated(u16),
+    /// A token is neither a note name nor a pitch-class number.
+    InvalidToken(String),
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongLength { expected, found } => {
+                write!(f, "a tone row needs {expected} pitch classes, found {found}")
+            }
+            Self::Repeated(index) => write!(f, "pitch class {index} appears more than once"),
+            Self::InvalidToken(token) => write!(f, "invalid row element '{token}'"),
+        }
+    }
+}
+
+impl std::error::Error for RowError {}
+
+/// Ordering of every pitch class of the temperament.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ToneRow<T: Temperament> {
+    pitch_classes: Vec<PitchClass<T>>,
+}
+
+impl<T: Temperament> Clone for ToneRow<T> {
+    fn clone(&self) -> Self {
+        Self { pitch_classes: self.pitch_classes.clone() }
+    }
+}
+
+/// Convenience alias for twelve-tone rows.
+pub type ToneRow12 = ToneRow<T12>;
+
+impl<T: Temperament> ToneRow<T> {
+    /// Validate `pitch_classes` as a permutation of the temperament's pitch classes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RowError`] when the length is wrong or a pitch class repeats.
+    pub fn new(pitch_classes: Vec<PitchClass<T>>) -> Result<Self, RowError> {
+        let expected = usize::from(T::STEPS_PER_OCTAVE);
+        if pitch_classes.len() != expected {
+            return Err(RowError::WrongLength { expected, found: pitch_classes.len() });
+        }
+        let mut seen = vec![false; expected];
+        for pitch_class in &pitch_classes {
+            let slot = &mut seen[usize::from(pitch_class.index())];
+            if *slot {
+                return Err(RowError::Repeated(pitch_class.index()));
+            }
+            *slot = true;
+        }
+        Ok(Self { pitch_classes })
+    }
+
+    #[must_use]
+    pub fn pitch_classes(&self) -> Vec<PitchClass<T>> {
+        self.pitch_classes.clone()
+    }
+
+    /// The row in the requested form.
+    #[must_use]
+    pub fn form(&self, label: RowLabel) -> Self {
+        let steps = i32::from(T::STEPS_PER_OCTAVE);
+        let first = i32::from(self.pitch_classes[0].index());
+        let shift = i32::from(label.transposition);
+        let mut pitch_classes: Vec<PitchClass<T>> = self
+            .pitch_classes
+            .iter()
+            .map(|pc| {
+                let index = i32::from(pc.index());
+                let moved = if label.form.is_inverted() { 2 * first - index } else { index };
+                pitch_class::<T>((moved + shift).rem_euclid(steps))
+            })
+            .collect();
+        if label.form.is_retrograde() {
+            pitch_classes.reverse();
+        }
+        Self { pitch_classes }
+    }
+
+    /// Every form at every transposition, `P` forms first.
+    #[must_use]
+    pub fn forms(&self) -> Vec<(RowLabel, Self)> {
+        RowForm::ALL
+            .into_iter()
+            .flat_map(|form| {
+                (0..T::STEPS_PER_OCTAVE)
+                    .map(move |transposition| RowLabel::new(form, transposition))
+            })
+            .map(|label| (label, self.form(label)))
+            .collect()
+    }
+
+    /// Serial matrix: row `i` is the prime form beginning on the `i`-th note of `I0`, so rows
+    /// read as `P` forms left to right and columns as `I` forms top to bottom.
+    #[must_use]
+    pub fn matrix(&self) -> Vec<Vec<PitchClass<T>>> {
+        let inversion = self.form(RowLabel::new(RowForm::Inversion, 0));
+        let first = self.pitch_classes[0];
+        inversion
+            .pitch_classes
+            .iter()
+            .map(|start| {
+                let steps = i32::from(start.index()) - i32::from(first.index());
+                let shift = u16::try_from(steps.rem_euclid(i32::from(T::STEPS_PER_OCTAVE)));
+                self.form(RowLabel::new(RowForm::Prime, shift.unwrap_or_default())).pitch_classes
+            })
+            .collect()
+    }
+
+    /// Transpositions of `form` whose first hexachord is the complement of this row's first
+    /// hexachord (hexachordal combinatoriality). `R0` always qualifies trivially.
+    #[must_use]
+    pub fn combinatorial_transpositions(&self, form: RowForm) -> Vec<u16> {
+        let half = self.pitch_classes.len() / 2;
+        let mut opening = vec![false; usize::from(T::STEPS_PER_OCTAVE)];
+        for pc in &self.pitch_classes[..half] {
+            opening[usize::from(pc.index())] = true;
+        }
+        (0..T::STEPS_PER_OCTAVE)
+            .filter(|transposition| {
+                let other = self.form(RowLabel::new(form, *transposition));
+                other.pitch_classes[..half].iter().all(|pc| !opening[usize::from(pc.index())])
+            })
+            .collect()
+    }
+
+    /// Whether the first hexachord is combinatorial under prime, inversion, and
+    /// retrograde-inversion forms (retrograde combinatoriality always holds).
+    #[must_use]
+    pub fn is_all_combinatorial(&self) -> bool {
+        [RowForm::Prime, RowForm::Inversion, RowForm::RetrogradeInversion]
+            .into_iter()
+            .all(|form| !self.combinatorial_transpositions(form).is_empty())
+    }
+
+    /// If the row is derived from its first `segment` notes, the operation producing each
+    /// segment from that generator (the first entry is always `P0`).
+    ///
+    /// Labels are relative to the generator, as [`ToneRow::form`] labels are to the row: `In`
+    /// is the generator's inversion starting on the same pitch class as its `Pn`.
+    #[must_use]
+    pub fn derivation(&self, segment: usize) -> Option<Vec<RowLabel>> {
+        let len = self.pitch_classes.len();
+        if segment < 2 || segment >= len || !len.is_multiple_of(segment) {
+            return None;
+        }
+        let generator = &self.pitch_classes[..segment];
+        self.pitch_classes
+            .chunks(segment)
+            .map(|chunk| segment_operation(generator, chunk))
+            .collect()
+    }
+}
+
+/// Operation mapping the ordered `generator` onto `target`, if any.
+fn segment_operation<T: Temperament>(
+    generator: &[PitchClass<T>],
+    target: &[PitchClass<T>],
+) -> Option<RowLabel> {
+    let steps = i32::from(T::STEPS_PER_OCTAVE);
+    let first = i32::from(generator[0].index());
+    RowForm::ALL.into_iter().find_map(|form| {
+        let mut shaped: Vec<i32> = generator
+            .iter()
+            .map(|pc| {
+                let index = i32::from(pc.index());
+                if form.is_inverted() { 2 * first - index } else { index }
+            })
+            .collect();
+        if form.is_retrograde() {
+            shaped.reverse();
+        }
+        let transposition = (i32::from(target[0].index()) - shaped[0]).rem_euclid(steps);
+        let matches = shaped
+            .iter()
+            .zip(target)
+            .all(|(index, pc)| (index + transposition).rem_euclid(steps) == i32::from(pc.index()));
+        matches.then(|| RowLabel::new(form, u16::try_from(transposition).unwrap_or_default()))
+    })
+}
+
+fn pitch_class<T: Temperament>(index: i32) -> PitchClass<T> {
+    PitchClass::new(u16::try_from(index).unwrap_or_default())
+}
+
+impl FromStr for ToneRow12 {
+    type Err = RowError;
+
+    /// Parses whitespace- or comma-separated note names (`"C Db G"`) or pitch-class numbers
+    /// (`"0 1 7"`, with `t`/`e` or `A`/`B` for 10 and 11 once any token is numeric).
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<&str> = input
+            .split(|ch: char| ch.is_whitespace() || ch == ',')
+            .filter(|token| !token.is_empty())
+            .collect();
+        let numeric = tokens.iter().any(|token| token.chars().all(|ch| ch.is_ascii_digit()));
+        let pitch_classes = tokens
+            .iter()
+            .map(|token| {
+                let invalid = || RowError::InvalidToken((*token).to_string());
+                if !numeric {
+                    return token.parse().map_err(|_| invalid());
+                }
+                let index = match *token {
+                    "t" | "T" | "a" | "A" => 10,
+                    "e" | "E" | "b" | "B" => 11,
+                    _ => {
+                        token.parse::<u16>().ok().filter(|index| *index < 12).ok_or_else(invalid)?
+                    }
+                };
+                Ok(PitchClass::new(index))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(pitch_classes)
+    }
+}
+
+impl fmt::Display for ToneRow12 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let labels: Vec<String> = self.pitch_classes.iter().map(ToString::to_string).collect();
+        f.write_str(&labels.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch::PitchClass12;
+
+    fn row(indices: [u16; 12]) -> ToneRow12 {
+        ToneRow::new(indices.map(PitchClass12::from_semitones).to_vec()).expect("valid row")
+    }
+
+    fn indices(row: &ToneRow12) -> Vec<u16> {
+        row.pitch_classes().iter().map(|pc| pc.index()).collect()
+    }
+
+    #[test]
+    fn validation_and_parsing() {
+        let short = ToneRow12::new(vec![PitchClass12::from_semitones(0); 3]);
+        assert_eq!(short, Err(RowError::WrongLength { expected: 12, found: 3 }));
+        let repeated = "0 1 2 3 4 5 6 7 8 9 t t".parse::<ToneRow12>();
+        assert_eq!(repeated, Err(RowError::Repeated(10)));
+        let numeric: ToneRow12 = "0 e 7 8 3 1 2 t 6 5 4 9".parse().expect("numeric row");
+        assert_eq!(indices(&numeric), [0, 11, 7, 8, 3, 1, 2, 10, 6, 5, 4, 9]);
+        let named: ToneRow12 = "C, B, G, Ab, Eb, C#, D, Bb, F#, F, E, A".parse().expect("names");
+        assert_eq!(named, numeric);
+        assert!("0 1 2 3 4 5 6 7 8 9 10 12".parse::<ToneRow12>().is_err());
+    }
+
+    #[test]
+    fn forms_and_matrix() {
+        let prime = row([0, 11, 7, 8, 3, 1, 2, 10, 6, 5, 4, 9]);
+        let label = |form, n| RowLabel::new(form, n);
+        assert_eq!(
+            indices(&prime.form(label(RowForm::Prime, 2))),
+            [2, 1, 9, 10, 5, 3, 4, 0, 8, 7, 6, 11]
+        );
+        assert_eq!(
+            indices(&prime.form(label(RowForm::Inversion, 0))),
+            [0, 1, 5, 4, 9, 11, 10, 2, 6, 7, 8, 3]
+        );
+        assert_eq!(indices(&prime.form(label(RowForm::Retrograde, 0)))[0], 9);
+        assert_eq!(indices(&prime.form(label(RowForm::RetrogradeInversion, 0)))[0], 3);
+        assert_eq!(label(RowForm::RetrogradeInversion, 7).to_string(), "RI7");
+        assert_eq!(prime.forms().len(), 48);
+
+        let matrix = prime.matrix();
+        let column: Vec<u16> = matrix.iter().map(|line| line[0].index()).collect();
+        assert_eq!(column, indices(&prime.form(label(RowForm::Inversion, 0))));
+        assert!(matrix.iter().all(|line| ToneRow::new(line.clone()).is_ok()));
+    }
+
+    #[test]
+    fn combinatoriality_and_derivation() {
+        // Chromatic hexachord: all-combinatorial.
+        let chromatic = row([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+        assert_eq!(chromatic.combinatorial_transpositions(RowForm::Prime), [6]);
+        assert_eq!(chromatic.combinatorial_transpositions(RowForm::Retrograde), [0]);
+        assert!(chromatic.is_all_combinatorial());
+        let quarter_tones = (0..24).map(PitchClass::<music_acoustic::T24>::new).collect();
+        let chromatic_24 = ToneRow::new(quarter_tones).expect("24-step row");
+        assert_eq!(chromatic_24.combinatorial_transpositions(RowForm::Prime), [12]);
+        // Schoenberg op. 25: inversionally combinatorial (at I7 here) but not all-combinatorial.
+        let suite = row([4, 5, 7, 1, 6, 3, 8, 2, 11, 0, 9, 10]);
+        assert!(suite.combinatorial_transpositions(RowForm::Inversion).contains(&7));
+        assert!(!suite.is_all_combinatorial());
+
+        let webern = row([11, 10, 2, 3, 7, 6, 8, 4, 5, 0, 1, 9]);
+        let derivation = webern.derivation(3).expect("derived row");
+        let labels: Vec<String> = derivation.iter().map(ToString::to_string).collect();
+        assert_eq!(labels, ["P0", "RI7", "R6", "I1"]);
+        let segments = webern.pitch_classes();
+        for (label, chunk) in derivation.iter().zip(segments.chunks(3)) {
+            let generator = ToneRow::<T12> { pitch_classes: segments[..3].to_vec() };
+            assert_eq!(generator.form(*label).pitch_classes, chunk);
+        }
+        assert!(suite.derivation(3).is_none());
+        assert!(webern.derivation(5).is_none());
+    }
+}
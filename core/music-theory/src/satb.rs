@@ -0,0 +1,109 @@
+//! core/music-theory/src/satb.rs
+//! Four-voice (soprano, alto, tenor, bass) voicings and standard vocal ranges.
+//!
+//! [`SatbChord`] is the unit consumed by the part-writing checker and produced by the
+//! four-part realizer. Voices are stored as registered [`Pitch12`] values, top to bottom.
+
+use core::fmt;
+
+use crate::pitch::{Pitch12, PitchClass12};
+
+/// One of the four voices of a chorale texture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Voice {
+    Soprano,
+    Alto,
+    Tenor,
+    Bass,
+}
+
+impl Voice {
+    /// Voices from top to bottom.
+    pub const ALL: [Self; 4] = [Self::Soprano, Self::Alto, Self::Tenor, Self::Bass];
+
+    /// Position from the top (`0` = soprano, `3` = bass).
+    #[must_use]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Textbook range `(lowest, highest)`: S C4–G5, A G3–D5, T C3–G4, B E2–C4.
+    #[must_use]
+    pub fn default_range(self) -> (Pitch12, Pitch12) {
+        let pitch = Pitch12::from_semitones_and_octave;
+        match self {
+            Self::Soprano => (pitch(0, 4), pitch(7, 5)),
+            Self::Alto => (pitch(7, 3), pitch(2, 5)),
+            Self::Tenor => (pitch(0, 3), pitch(7, 4)),
+            Self::Bass => (pitch(4, 2), pitch(0, 4)),
+        }
+    }
+
+    /// Lower-case voice name.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Soprano => "soprano",
+            Self::Alto => "alto",
+            Self::Tenor => "tenor",
+            Self::Bass => "bass",
+        }
+    }
+}
+
+impl fmt::Display for Voice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A four-voice sonority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SatbChord {
+    pub soprano: Pitch12,
+    pub alto: Pitch12,
+    pub tenor: Pitch12,
+    pub bass: Pitch12,
+}
+
+impl SatbChord {
+    #[must_use]
+    pub fn new(soprano: Pitch12, alto: Pitch12, tenor: Pitch12, bass: Pitch12) -> Self {
+        Self { soprano, alto, tenor, bass }
+    }
+
+    /// Pitch sung by `voice`.
+    #[must_use]
+    pub fn voice(&self, voice: Voice) -> Pitch12 {
+        match voice {
+            Voice::Soprano => self.soprano,
+            Voice::Alto => self.alto,
+            Voice::Tenor => self.tenor,
+            Voice::Bass => self.bass,
+        }
+    }
+
+    /// Pitches from soprano down to bass.
+    #[must_use]
+    pub fn pitches(&self) -> [Pitch12; 4] {
+        [self.soprano, self.alto, self.tenor, self.bass]
+    }
+
+    /// Voices singing `pitch_class`.
+    #[must_use]
+    pub fn voices_with(&self, pitch_class: PitchClass12) -> Vec<Voice> {
+        Voice::ALL
+            .into_iter()
+            .filter(|voice| self.voice(*voice).pitch_class == pitch_class)
+            .collect()
+    }
+}
+
+impl fmt::Display for SatbChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.soprano, self.alto, self.tenor, self.bass)
+    }
+}
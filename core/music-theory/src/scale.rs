@@ -11,6 +11,8 @@ use music_acoustic::{T12, Temperament};
 
 /// Scale as an ordered set of pitch-classes in a temperament.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent, bound = ""))]
 pub struct Scale<T: Temperament> {
     pub degrees: Vec<PitchClass<T>>,
 }
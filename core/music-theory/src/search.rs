@@ -0,0 +1,168 @@
+//! core/music-theory/src/search.rs
+//! Chord search: find chords whose tones contain (or nearly contain) a pitch-class set.
+//!
+//! [`ChordQuery`] scans every root × [`ChordKind`] pairing (312 candidates in 12-TET, 384 with
+//! quartal/quintal stacks) using 12-bit pitch-class masks, so queries are cheap enough for
+//! interactive use. Stacks are left out unless the query asks for them. Candidates must
+//! avoid every forbidden tone, stay inside the key when one is given, and respect the tone-count
+//! limit; partial matches report the required tones they lack.
+//!
+//! ```
+//! use music_theory::{ChordQuery, PitchClass12};
+//! let pc = PitchClass12::from_semitones;
+//! // Which chords contain E, G and B?
+//! let e_g_b = ChordQuery::containing(&[pc(4), pc(7), pc(11)]);
+//! let query = ChordQuery { max_tones: Some(4), ..e_g_b };
+//! let names: Vec<String> = query.search().iter().map(|m| m.chord.to_string()).collect();
+//! assert_eq!(names, ["Em", "Cmaj7", "Em7", "C#m7b5", "Em(maj7)", "Gadd13"]);
+//! ```
+
+use crate::chord::{Chord12, ChordKind};
+use crate::key::Key12;
+use crate::pitch::PitchClass12;
+
+/// Constraints for a chord search.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ChordQuery {
+    /// Pitch classes the chord should contain.
+    pub required: Vec<PitchClass12>,
+    /// Pitch classes the chord must not contain.
+    pub forbidden: Vec<PitchClass12>,
+    /// When set, every chord tone must belong to the key's scale.
+    pub key: Option<Key12>,
+    /// Maximum number of chord tones (complexity limit); `None` allows every kind.
+    pub max_tones: Option<usize>,
+    /// How many required tones a partial match may lack (`0` = exact matches only).
+    pub max_missing: usize,
+    /// Also consider quartal and quintal stacks (off by default).
+    pub include_stacks: bool,
+}
+
+/// A chord returned by [`ChordQuery::search`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChordMatch {
+    /// Root-position chord built from `kind`.
+    pub chord: Chord12,
+    pub kind: ChordKind,
+    /// Required pitch classes the chord does not contain (empty for full matches).
+    pub missing: Vec<PitchClass12>,
+}
+
+impl ChordMatch {
+    /// Whether the chord contains every required tone.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+impl ChordQuery {
+    /// Query for chords containing every pitch class in `required`.
+    #[must_use]
+    pub fn containing(required: &[PitchClass12]) -> Self {
+        Self { required: required.to_vec(), ..Self::default() }
+    }
+
+    /// Every candidate chord satisfying the query.
+    ///
+    /// Results are ordered by missing-tone count, then tone count (simpler chords first), then
+    /// [`ChordKind::ALL`] order, then root.
+    #[must_use]
+    pub fn search(&self) -> Vec<ChordMatch> {
+        let required = mask(&self.required);
+        let forbidden = mask(&self.forbidden);
+        let scale = self.key.map_or(u16::MAX, |key| mask(&key.scale12().degrees));
+        let mut found: Vec<(u32, usize, usize, u16, ChordMatch)> = Vec::new();
+        for (kind_index, kind) in ChordKind::ALL.into_iter().enumerate() {
+            let tones = kind.intervals().len();
+            let stacked = matches!(kind, ChordKind::Stacked(_));
+            if self.max_tones.is_some_and(|limit| tones > limit) || stacked && !self.include_stacks
+            {
+                continue;
+            }
+            for root in 0..12 {
+                let chord = Chord12::from_kind(PitchClass12::from_semitones(root), kind);
+                let tones = mask(&chord.tones);
+                let missing = required & !tones;
+                if tones & forbidden != 0
+                    || tones & !scale != 0
+                    || missing.count_ones() as usize > self.max_missing
+                {
+                    continue;
+                }
+                let missing_tones =
+                    self.required.iter().copied().filter(|pc| bit(*pc) & missing != 0);
+                let entry = ChordMatch { chord, kind, missing: dedup(missing_tones) };
+                found.push((missing.count_ones(), entry.chord.len(), kind_index, root, entry));
+            }
+        }
+        found.sort_by_key(|(missing, len, kind, root, _)| (*missing, *len, *kind, *root));
+        found.into_iter().map(|(.., entry)| entry).collect()
+    }
+}
+
+fn bit(pitch_class: PitchClass12) -> u16 {
+    1 << (pitch_class.to_semitones() % 12)
+}
+
+fn mask(pitch_classes: &[PitchClass12]) -> u16 {
+    pitch_classes.iter().fold(0, |acc, pc| acc | bit(*pc))
+}
+
+fn dedup(tones: impl Iterator<Item = PitchClass12>) -> Vec<PitchClass12> {
+    let mut unique = Vec::new();
+    for tone in tones {
+        if !unique.contains(&tone) {
+            unique.push(tone);
+        }
+    }
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chord::{SeventhKind, TriadKind};
+
+    fn pc(semitones: u16) -> PitchClass12 {
+        PitchClass12::from_semitones(semitones)
+    }
+
+    #[test]
+    fn forbidden_tones_and_keys_filter_candidates() {
+        let mut query = ChordQuery::containing(&[pc(4), pc(7)]);
+        query.forbidden = vec![pc(0)];
+        query.max_tones = Some(4);
+        let results = query.search();
+        assert!(results.iter().all(|m| m.is_complete() && !m.chord.contains(pc(0))));
+        assert_eq!(results[0].kind, ChordKind::Triad(TriadKind::Minor));
+
+        query.key = Some(Key12::major(pc(2)));
+        let in_d: Vec<String> = query.search().iter().map(|m| m.chord.to_string()).collect();
+        assert_eq!(in_d, ["Em", "C#dim", "A7", "Em7", "C#m7b5", "Gadd13", "A7sus4"]);
+        query.include_stacks = true;
+        let stacks: Vec<String> = query.search().iter().map(|m| m.chord.to_string()).collect();
+        assert_eq!(stacks[7..], ["Equartal4", "Gquintal4"]);
+    }
+
+    #[test]
+    fn partial_matches_list_missing_tones() {
+        let query = ChordQuery {
+            max_tones: Some(3),
+            max_missing: 1,
+            ..ChordQuery::containing(&[pc(0), pc(4), pc(6)])
+        };
+        let results = query.search();
+        assert!(!results.is_empty() && results.iter().all(|m| m.missing.len() == 1));
+        let c_major = results
+            .iter()
+            .find(|m| m.chord == Chord12::major_triad(pc(0)))
+            .expect("C major lacks only F#");
+        assert_eq!(c_major.missing, [pc(6)]);
+
+        let exact = ChordQuery { max_missing: 0, ..query }.search();
+        assert!(exact.is_empty());
+        let d7 = ChordQuery::containing(&[pc(0), pc(6)]).search();
+        assert!(d7.iter().any(|m| m.kind == ChordKind::Seventh(SeventhKind::Dominant7)));
+    }
+}
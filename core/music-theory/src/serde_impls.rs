@@ -0,0 +1,417 @@
+//! core/music-theory/src/serde_impls.rs
+//! Serde encodings for theory primitives (enabled with the `serde` feature).
+//!
+//! Human-readable formats use stable text labels for 12-step temperaments: pitch classes as
+//! `"F#"`, pitches as `"C#4"`, intervals as `"m3"`, chords as `"Am7/G"` and keys as
+//! `"A minor"`. Other temperaments, and binary formats, fall back to raw step indices so every
+//! value still round-trips exactly. Deserialization accepts both forms in human-readable formats.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use music_acoustic::Temperament;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, SerializeTuple, Serializer};
+
+use crate::chord::{Chord, ChordKind};
+use crate::interval::Interval;
+use crate::key::{Key, Mode};
+use crate::pitch::{Pitch, PitchClass};
+use crate::text;
+
+/// Whether the temperament uses the 12-step letter-name encodings.
+fn named<T: Temperament>() -> bool {
+    T::STEPS_PER_OCTAVE == 12
+}
+
+fn pitch_class_from_index<T: Temperament, E: de::Error>(index: u64) -> Result<PitchClass<T>, E> {
+    u16::try_from(index)
+        .ok()
+        .filter(|index| *index < T::STEPS_PER_OCTAVE)
+        .map(PitchClass::new)
+        .ok_or_else(|| E::custom(format_args!("pitch-class index {index} out of range")))
+}
+
+fn parse_error<E: de::Error>(kind: &str, input: &str) -> E {
+    E::custom(format_args!("invalid {kind} '{input}'"))
+}
+
+/// Run a 12-step text parser, reporting why the label was rejected.
+fn parse_named<T: Temperament, V, E: de::Error>(
+    kind: &str,
+    input: &str,
+    parse: impl FnOnce(&str) -> Result<V, text::ParseError>,
+) -> Result<V, E> {
+    if !named::<T>() {
+        return Err(parse_error(kind, input));
+    }
+    parse(input).map_err(|err| E::custom(format_args!("invalid {kind} '{input}': {err}")))
+}
+
+impl<T: Temperament> Serialize for PitchClass<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() && named::<T>() {
+            serializer.serialize_str(text::PITCH_CLASS_LABELS[usize::from(self.index())])
+        } else {
+            serializer.serialize_u16(self.index())
+        }
+    }
+}
+
+struct PitchClassVisitor<T>(PhantomData<T>);
+
+impl<T: Temperament> Visitor<'_> for PitchClassVisitor<T> {
+    type Value = PitchClass<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a pitch-class name such as \"F#\" or a step index")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        pitch_class_from_index(value)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        let index = u64::try_from(value).map_err(|_| E::custom("negative pitch-class index"))?;
+        pitch_class_from_index(index)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        parse_named::<T, _, E>("pitch class", value, text::parse_pitch_class).map(PitchClass::new)
+    }
+}
+
+impl<'de, T: Temperament> Deserialize<'de> for PitchClass<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let visitor = PitchClassVisitor(PhantomData);
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_u16(visitor)
+        }
+    }
+}
+
+impl<T: Temperament> Serialize for Pitch<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() && named::<T>() {
+            let label = text::PITCH_CLASS_LABELS[usize::from(self.pitch_class.index())];
+            serializer.collect_str(&format_args!("{label}{}", self.octave))
+        } else {
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&self.pitch_class.index())?;
+            tuple.serialize_element(&self.octave)?;
+            tuple.end()
+        }
+    }
+}
+
+struct PitchVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Temperament> Visitor<'de> for PitchVisitor<T> {
+    type Value = Pitch<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a pitch such as \"C#4\" or a (step index, octave) pair")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        parse_named::<T, _, E>("pitch", value, text::parse_pitch)
+            .map(|(index, octave)| Pitch::new(PitchClass::new(index), octave))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let index: u16 = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let octave: i16 = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok(Pitch::new(pitch_class_from_index(u64::from(index))?, octave))
+    }
+}
+
+impl<'de, T: Temperament> Deserialize<'de> for Pitch<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let visitor = PitchVisitor(PhantomData);
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_tuple(2, visitor)
+        }
+    }
+}
+
+impl<T: Temperament> Serialize for Interval<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() && named::<T>() {
+            serializer.serialize_str(&text::interval_label(self.steps()))
+        } else {
+            serializer.serialize_i32(self.steps())
+        }
+    }
+}
+
+struct IntervalVisitor<T>(PhantomData<T>);
+
+impl<T: Temperament> Visitor<'_> for IntervalVisitor<T> {
+    type Value = Interval<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an interval such as \"m3\" or a signed step count")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        let steps = i32::try_from(value).map_err(|_| E::custom("interval out of range"))?;
+        Ok(Interval::new(steps))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        let steps = i32::try_from(value).map_err(|_| E::custom("interval out of range"))?;
+        Ok(Interval::new(steps))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        parse_named::<T, _, E>("interval", value, text::parse_interval).map(Interval::new)
+    }
+}
+
+impl<'de, T: Temperament> Deserialize<'de> for Interval<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let visitor = IntervalVisitor(PhantomData);
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_i32(visitor)
+        }
+    }
+}
+
+impl Serialize for ChordKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.symbol())
+    }
+}
+
+struct ChordKindVisitor;
+
+impl Visitor<'_> for ChordKindVisitor {
+    type Value = ChordKind;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a chord-symbol suffix such as \"maj7\"")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        ChordKind::from_symbol(value).ok_or_else(|| parse_error("chord kind", value))
+    }
+}
+
+impl<'de> Deserialize<'de> for ChordKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ChordKindVisitor)
+    }
+}
+
+impl<T: Temperament> Serialize for Chord<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Tones go out in root-position order, so the first one names the root.
+        let tones = self.root_position();
+        if !serializer.is_human_readable() {
+            return (&tones, &self.bass).serialize(serializer);
+        }
+        // A symbol decodes to the kind's table, so it is only exact for tones stored that way.
+        if named::<T>()
+            && let (Some(root), Some(kind)) = (self.root(), self.kind())
+            && self.tones == Chord::from_kind(root, kind).tones
+        {
+            let bass = self.bass.map(|bass| bass.index());
+            return serializer.serialize_str(&text::chord_symbol(root.index(), kind, bass));
+        }
+        match self.bass {
+            None => serializer.collect_seq(&tones),
+            Some(bass) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("tones", &tones)?;
+                map.serialize_entry("bass", &bass)?;
+                map.end()
+            }
+        }
+    }
+}
+
+struct ChordVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Temperament> Visitor<'de> for ChordVisitor<T> {
+    type Value = Chord<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a chord symbol such as \"Am7/G\", a list of pitch classes, or {tones, bass}")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let (root, kind, bass) =
+            parse_named::<T, _, E>("chord symbol", value, text::parse_chord_symbol)?;
+        let chord = Chord::from_kind(PitchClass::new(root), kind);
+        Ok(match bass {
+            Some(bass) => chord.with_bass(PitchClass::new(bass)),
+            None => chord,
+        })
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut tones = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(tone) = seq.next_element::<PitchClass<T>>()? {
+            tones.push(tone);
+        }
+        Ok(Chord::new(tones))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut tones: Option<Vec<PitchClass<T>>> = None;
+        let mut bass: Option<PitchClass<T>> = None;
+        while let Some(field) = map.next_key::<String>()? {
+            match field.as_str() {
+                "tones" => tones = Some(map.next_value()?),
+                "bass" => bass = map.next_value()?,
+                other => return Err(de::Error::unknown_field(other, &["tones", "bass"])),
+            }
+        }
+        let chord = Chord::new(tones.ok_or_else(|| de::Error::missing_field("tones"))?);
+        Ok(match bass {
+            Some(bass) => chord.with_bass(bass),
+            None => chord,
+        })
+    }
+}
+
+impl<'de, T: Temperament> Deserialize<'de> for Chord<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            return deserializer.deserialize_any(ChordVisitor(PhantomData));
+        }
+        let (tones, bass) =
+            <(Vec<PitchClass<T>>, Option<PitchClass<T>>)>::deserialize(deserializer)?;
+        let chord = Chord::new(tones);
+        Ok(match bass {
+            Some(bass) => chord.with_bass(bass),
+            None => chord,
+        })
+    }
+}
+
+impl<T: Temperament> Serialize for Key<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() && named::<T>() {
+            serializer.serialize_str(&text::key_label(self.tonic.index(), self.mode))
+        } else {
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&self.tonic)?;
+            tuple.serialize_element(&self.mode)?;
+            tuple.end()
+        }
+    }
+}
+
+struct KeyVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Temperament> Visitor<'de> for KeyVisitor<T> {
+    type Value = Key<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a key such as \"A minor\" or a (tonic, mode) pair")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        parse_named::<T, _, E>("key", value, text::parse_key)
+            .map(|(tonic, mode)| Key::new(PitchClass::new(tonic), mode))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let tonic: PitchClass<T> =
+            seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let mode: Mode = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok(Key::new(tonic, mode))
+    }
+}
+
+impl<'de, T: Temperament> Deserialize<'de> for Key<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let visitor = KeyVisitor(PhantomData);
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_tuple(2, visitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        Chord12, FunctionKind, Interval, Key12, Pitch12, PitchClass12, Scale12,
+        interval::Interval12,
+    };
+
+    fn round_trip<V>(value: &V, expected: &serde_json::Value)
+    where
+        V: serde::Serialize + serde::de::DeserializeOwned + PartialEq + core::fmt::Debug,
+    {
+        let encoded = serde_json::to_value(value).expect("serialize");
+        assert_eq!(&encoded, expected);
+        let decoded: V = serde_json::from_value(encoded).expect("deserialize");
+        assert_eq!(&decoded, value);
+    }
+
+    #[test]
+    fn pitch_types_use_note_names() {
+        round_trip(&PitchClass12::from_semitones(6), &json!("F#"));
+        round_trip(&Pitch12::from_semitones_and_octave(1, 4), &json!("C#4"));
+        round_trip(&Pitch12::from_semitones_and_octave(0, -1), &json!("C-1"));
+        let parsed: Pitch12 = serde_json::from_value(json!("Bb3")).expect("flat spelling");
+        assert_eq!(parsed, Pitch12::from_semitones_and_octave(10, 3));
+        let indexed: PitchClass12 = serde_json::from_value(json!(11)).expect("index form");
+        assert_eq!(indexed, PitchClass12::from_semitones(11));
+        assert!(serde_json::from_value::<PitchClass12>(json!(12)).is_err());
+        assert!(serde_json::from_value::<PitchClass12>(json!("H")).is_err());
+    }
+
+    #[test]
+    fn intervals_chords_and_keys_use_symbols() {
+        round_trip(&Interval12::from_semitones(3), &json!("m3"));
+        round_trip(&Interval12::from_semitones(-7), &json!("-P5"));
+        round_trip(&Chord12::major_seventh(PitchClass12::from_semitones(0)), &json!("Cmaj7"));
+        let cluster = Chord12::from_intervals(PitchClass12::from_semitones(0), &[0, 1, 2]);
+        round_trip(&cluster, &json!(["C", "C#", "D"]));
+        let open = Chord12::from_intervals(PitchClass12::from_semitones(0), &[0, 7, 4]);
+        round_trip(&open, &json!(["C", "G", "E"]));
+        round_trip(
+            &cluster.clone().with_bass(PitchClass12::from_semitones(6)),
+            &json!({"tones": ["C", "C#", "D"], "bass": "F#"}),
+        );
+        let rerooted = cluster.reroot(PitchClass12::from_semitones(1)).expect("chord tone");
+        let encoded = serde_json::to_value(&rerooted).expect("serialize");
+        assert_eq!(encoded, json!({"tones": ["C#", "D", "C"], "bass": "C"}));
+        let decoded: Chord12 = serde_json::from_value(encoded).expect("deserialize");
+        assert_eq!((decoded.root(), decoded.inversion()), (rerooted.root(), Some(2)));
+        round_trip(
+            &Chord12::minor_seventh(PitchClass12::from_semitones(9)).invert(3),
+            &json!("Am7/G"),
+        );
+        round_trip(&Key12::minor(PitchClass12::from_semitones(9)), &json!("A minor"));
+        let _: Interval<music_acoustic::T12> =
+            serde_json::from_value(json!(5)).expect("numeric interval");
+    }
+
+    #[test]
+    fn composite_types_nest_encodings() {
+        let scale = Scale12::major(PitchClass12::from_semitones(7));
+        round_trip(&scale, &json!(["G", "A", "B", "C", "D", "E", "F#"]));
+
+        let key = Key12::major(PitchClass12::from_semitones(0));
+        let function = key.function_for_degree(5).expect("dominant");
+        round_trip(
+            &function,
+            &json!({"kind": "dominant", "degree": 5, "key": "C major", "pitch_class": "G"}),
+        );
+        assert_eq!(function.kind, FunctionKind::Dominant);
+    }
+}
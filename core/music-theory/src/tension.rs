@@ -0,0 +1,277 @@
+//! core/music-theory/src/tension.rs
+//! Psychoacoustic dissonance and tension scoring for chords and registered voicings.
+//!
+//! [`TensionModel`] combines three classic measures into a normalized `0..=1` tension value:
+//! - **Roughness** (Plomp–Levelt curve in Sethares' parameterization): every pair of sounding
+//!   notes is expanded into harmonic partials and the beating between partials is summed, then
+//!   normalized against a semitone dyad in the same register.
+//! - **Root ambiguity** (Hindemith): the strongest interval in the sonority (fifth, then
+//!   third, ...) determines the root; weaker best intervals mean a less stable chord.
+//! - **Interval-class dissonance**: a weight per interval class (semitone and tritone rough,
+//!   fifths and thirds smooth) averaged over all pitch-class pairs.
+//!
+//! Frequencies come from [`Pitch::frequency`] (equal temperament, A4 = 440 Hz).
+//!
+//! ```
+//! use music_theory::{Chord12, PitchClass12, TensionModel};
+//! let model = TensionModel::default();
+//! let c = PitchClass12::from_semitones(0);
+//! let triad = model.score_chord(&Chord12::major_triad(c));
+//! let diminished = model.score_chord(&Chord12::diminished_seventh(c));
+//! assert!(triad.tension < diminished.tension);
+//! assert_eq!(triad.root, Some(c));
+//! ```
+
+use music_acoustic::Temperament;
+
+use crate::chord::Chord;
+use crate::pitch::{Pitch, PitchClass};
+
+/// Sethares' fit of the Plomp–Levelt dissonance curve.
+const SETHARES_B1: f64 = 3.5;
+const SETHARES_B2: f64 = 5.75;
+const SETHARES_S1: f64 = 0.021;
+const SETHARES_S2: f64 = 19.0;
+const SETHARES_DSTAR: f64 = 0.24;
+
+/// Weighted blend of roughness, root ambiguity, and interval-class dissonance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TensionModel {
+    /// Harmonic partials per note (including the fundamental).
+    pub partials: u8,
+    /// Amplitude ratio between successive partials (Sethares uses `0.88`).
+    pub rolloff: f64,
+    /// Weight of [`TensionBreakdown::roughness`].
+    pub roughness_weight: f64,
+    /// Weight of [`TensionBreakdown::root_ambiguity`].
+    pub root_weight: f64,
+    /// Weight of [`TensionBreakdown::interval_class`].
+    pub interval_class_weight: f64,
+    /// Dissonance of interval classes 0–6 (unison through tritone).
+    pub interval_class_weights: [f64; 7],
+}
+
+impl Default for TensionModel {
+    fn default() -> Self {
+        Self {
+            partials: 6,
+            rolloff: 0.88,
+            roughness_weight: 0.4,
+            root_weight: 0.25,
+            interval_class_weight: 0.35,
+            interval_class_weights: [0.0, 1.0, 0.6, 0.25, 0.2, 0.1, 0.7],
+        }
+    }
+}
+
+/// Tension score with its normalized contributors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TensionBreakdown<T: Temperament> {
+    /// Weighted blend of the contributors (`0..=1`).
+    pub tension: f64,
+    /// Mean pairwise sensory roughness relative to a semitone dyad (`0..=1`).
+    pub roughness: f64,
+    /// `1 - ` Hindemith root strength (`0..=1`); rootless sonorities score `1`.
+    pub root_ambiguity: f64,
+    /// Mean interval-class dissonance over pitch-class pairs (`0..=1`).
+    pub interval_class: f64,
+    /// Hindemith root, when any interval other than the tritone is present.
+    pub root: Option<PitchClass<T>>,
+}
+
+impl TensionModel {
+    /// Score a chord in close position around middle C (see [`Chord::close_voicing`]).
+    #[must_use]
+    pub fn score_chord<T: Temperament>(&self, chord: &Chord<T>) -> TensionBreakdown<T> {
+        self.score_voicing(&chord.close_voicing(4))
+    }
+
+    /// Score a registered voicing; pitch order does not matter.
+    #[must_use]
+    pub fn score_voicing<T: Temperament>(&self, pitches: &[Pitch<T>]) -> TensionBreakdown<T> {
+        let roughness = self.roughness(pitches);
+        let (root, strength) = hindemith_root(pitches).map_or((None, 0.0), |(r, s)| (Some(r), s));
+        let root_ambiguity = 1.0 - strength;
+        let interval_class = self.interval_class_dissonance(pitches);
+        let total = self.roughness_weight + self.root_weight + self.interval_class_weight;
+        let tension = if total > 0.0 {
+            (self.roughness_weight * roughness
+                + self.root_weight * root_ambiguity
+                + self.interval_class_weight * interval_class)
+                / total
+        } else {
+            0.0
+        };
+        TensionBreakdown {
+            tension: tension.clamp(0.0, 1.0),
+            roughness,
+            root_ambiguity,
+            interval_class,
+            root,
+        }
+    }
+
+    /// Mean pairwise roughness, each pair normalized by a semitone dyad on its lower note.
+    #[must_use]
+    pub fn roughness<T: Temperament>(&self, pitches: &[Pitch<T>]) -> f64 {
+        let mut total = 0.0;
+        let mut pairs = 0_u32;
+        for (index, a) in pitches.iter().enumerate() {
+            for b in &pitches[index + 1..] {
+                let (low, high) = if a <= b {
+                    (a.frequency(), b.frequency())
+                } else {
+                    (b.frequency(), a.frequency())
+                };
+                let reference = self.dyad_roughness(low, low * 2_f64.powf(1.0 / 12.0));
+                if reference > 0.0 {
+                    total += (self.dyad_roughness(low, high) / reference).min(1.0);
+                }
+                pairs += 1;
+            }
+        }
+        if pairs == 0 { 0.0 } else { total / f64::from(pairs) }
+    }
+
+    /// Mean configured interval-class weight over every pair of distinct sounding pitches.
+    #[must_use]
+    pub fn interval_class_dissonance<T: Temperament>(&self, pitches: &[Pitch<T>]) -> f64 {
+        let mut total = 0.0;
+        let mut pairs = 0_u32;
+        for (index, a) in pitches.iter().enumerate() {
+            for b in &pitches[index + 1..] {
+                let semitones = nearest_semitones::<T>(b.absolute_steps() - a.absolute_steps());
+                let class = semitones.rem_euclid(12);
+                let class = usize::try_from(class.min(12 - class)).unwrap_or_default();
+                total += self.interval_class_weights[class].clamp(0.0, 1.0);
+                pairs += 1;
+            }
+        }
+        if pairs == 0 { 0.0 } else { total / f64::from(pairs) }
+    }
+
+    /// Sethares roughness between two complex tones with harmonic partials.
+    fn dyad_roughness(&self, low: f64, high: f64) -> f64 {
+        let partials =
+            || (1..=self.partials).map(|n| (f64::from(n), self.rolloff.powi(i32::from(n) - 1)));
+        partials()
+            .flat_map(|(n, a1)| partials().map(move |(m, a2)| (n * low, a1, m * high, a2)))
+            .map(|(f1, a1, f2, a2)| sethares_roughness(f1, a1, f2, a2))
+            .sum()
+    }
+}
+
+/// Plomp–Levelt roughness between two sine partials (Sethares' model).
+#[must_use]
+pub fn sethares_roughness(f1: f64, a1: f64, f2: f64, a2: f64) -> f64 {
+    let (low, high) = if f1 <= f2 { (f1, f2) } else { (f2, f1) };
+    let s = SETHARES_DSTAR / (SETHARES_S1 * low + SETHARES_S2);
+    let delta = high - low;
+    a1.min(a2) * ((-SETHARES_B1 * s * delta).exp() - (-SETHARES_B2 * s * delta).exp())
+}
+
+/// Hindemith root and its strength (`1.0` for a fifth down to `0.2` for a semitone).
+///
+/// Interval pairs rank fifth/fourth, major third/minor sixth, minor third/major sixth, major
+/// second/minor seventh, then semitone/major seventh; the tritone has no root. Ties go to the
+/// pair with the lowest-sounding root. Only temperaments that divide into semitones are ranked.
+#[must_use]
+pub fn hindemith_root<T: Temperament>(pitches: &[Pitch<T>]) -> Option<(PitchClass<T>, f64)> {
+    let steps = i32::from(T::STEPS_PER_OCTAVE);
+    if steps % 12 != 0 {
+        return None;
+    }
+    let per_semitone = steps / 12;
+    let mut sorted = pitches.to_vec();
+    sorted.sort();
+    let mut best: Option<(u8, Pitch<T>)> = None;
+    for (index, low) in sorted.iter().enumerate() {
+        for high in &sorted[index + 1..] {
+            let distance = high.absolute_steps() - low.absolute_steps();
+            if distance % per_semitone != 0 {
+                continue;
+            }
+            // (rank, root is the upper note) for each simple interval.
+            let (rank, upper_root) = match (distance / per_semitone).rem_euclid(12) {
+                7 => (1, false),
+                5 => (1, true),
+                4 => (2, false),
+                8 => (2, true),
+                3 => (3, false),
+                9 => (3, true),
+                2 => (4, true),
+                10 => (4, false),
+                1 => (5, true),
+                11 => (5, false),
+                _ => continue,
+            };
+            let root = if upper_root { *high } else { *low };
+            let better = best.is_none_or(|(best_rank, best_root)| {
+                rank < best_rank || (rank == best_rank && root < best_root)
+            });
+            if better {
+                best = Some((rank, root));
+            }
+        }
+    }
+    const STRENGTH: [f64; 5] = [1.0, 0.8, 0.6, 0.4, 0.2];
+    best.map(|(rank, root)| (root.pitch_class, STRENGTH[usize::from(rank - 1)]))
+}
+
+/// Step distance rounded to the nearest 12-TET semitone.
+#[allow(clippy::cast_possible_truncation)] // |result| <= |steps| * 12, well inside i32.
+fn nearest_semitones<T: Temperament>(steps: i32) -> i32 {
+    (f64::from(steps) * 12.0 / f64::from(T::STEPS_PER_OCTAVE)).round() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chord::Chord12;
+    use crate::pitch::{Pitch12, PitchClass12};
+
+    fn pitches(notes: &[(u16, i16)]) -> Vec<Pitch12> {
+        notes.iter().map(|(s, o)| Pitch12::from_semitones_and_octave(*s, *o)).collect()
+    }
+
+    #[test]
+    fn chord_tension_orders_common_sonorities() {
+        let model = TensionModel::default();
+        let c = PitchClass12::from_semitones(0);
+        let scores: Vec<f64> = [
+            Chord12::major_triad(c),
+            Chord12::dominant_seventh(c),
+            Chord12::diminished_seventh(c),
+            Chord12::from_intervals(c, &[0, 1, 2, 3]),
+        ]
+        .iter()
+        .map(|chord| model.score_chord(chord).tension)
+        .collect();
+        assert!(scores.windows(2).all(|pair| pair[0] < pair[1]), "{scores:?}");
+        assert!(scores.iter().all(|score| (0.0..=1.0).contains(score)));
+    }
+
+    #[test]
+    fn roughness_follows_critical_bandwidth() {
+        let model = TensionModel::default();
+        let semitone = model.roughness(&pitches(&[(0, 4), (1, 4)]));
+        let fifth = model.roughness(&pitches(&[(0, 4), (7, 4)]));
+        assert!((semitone - 1.0).abs() < 1e-9 && fifth < 0.5 * semitone);
+        // The same major third is rougher low in the register.
+        let low = model.roughness(&pitches(&[(0, 2), (4, 2)]));
+        let high = model.roughness(&pitches(&[(0, 5), (4, 5)]));
+        assert!(low > high, "{low} vs {high}");
+        assert_eq!(model.roughness(&pitches(&[(0, 4)])), 0.0);
+    }
+
+    #[test]
+    fn hindemith_root_prefers_the_fifth_and_ignores_the_bass() {
+        let first_inversion = pitches(&[(4, 3), (7, 3), (0, 4)]);
+        let (root, strength) = hindemith_root(&first_inversion).expect("root");
+        assert_eq!((root, strength), (PitchClass12::from_semitones(0), 1.0));
+        let tritone = pitches(&[(0, 4), (6, 4)]);
+        assert!(hindemith_root(&tritone).is_none());
+        let breakdown = TensionModel::default().score_voicing(&tritone);
+        assert_eq!(breakdown.root_ambiguity, 1.0);
+    }
+}
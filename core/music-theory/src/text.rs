@@ -0,0 +1,545 @@
+//! core/music-theory/src/text.rs
+//! Text formatting and parsing for 12-TET pitch classes, pitches, intervals, chords, and keys.
+//!
+//! Theory types carry no enharmonic spelling, so rendering picks one label per pitch class from
+//! a [`NoteFormat`] (sharps, flats, or the mixed default `C C# D Eb E F F# G Ab A Bb B`) while
+//! parsing accepts any letter + accidental combination (`"Bb"`, `"A#"`, `"C##"`, `"E♭"`).
+//! Octave numbers default to scientific pitch notation (middle C = `C4`).
+//!
+//! ```
+//! use music_theory::{Interval12, Key12, Pitch12, PitchClass12};
+//! use music_theory::text::{Accidentals, NoteFormat};
+//! let pitch: Pitch12 = "Bb3".parse().unwrap();
+//! assert_eq!(pitch, Pitch12::from_semitones_and_octave(10, 3));
+//! assert_eq!(pitch.to_string(), "Bb3");
+//!
+//! let sharps = NoteFormat { accidentals: Accidentals::Sharps, ..NoteFormat::default() };
+//! assert_eq!(sharps.pitch_label(pitch), "A#3");
+//!
+//! assert_eq!("m3".parse::<Interval12>().unwrap(), Interval12::from_semitones(3));
+//! assert_eq!("Ebm".parse::<Key12>().unwrap().to_string(), "Eb minor");
+//! assert_eq!(PitchClass12::from_semitones(6).to_string(), "F#");
+//! ```
+
+use core::fmt;
+use core::str::FromStr;
+
+use music_acoustic::T12;
+
+use crate::chord::{Chord, Chord12, ChordKind};
+use crate::interval::{Interval, Interval12};
+use crate::key::{Key, Key12, Mode};
+use crate::pitch::{Pitch, Pitch12, PitchClass, PitchClass12};
+use crate::polychord::CompoundChord;
+
+/// Which accidental spells the five black-key pitch classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Accidentals {
+    /// `C# D# F# G# A#`.
+    Sharps,
+    /// `Db Eb Gb Ab Bb`.
+    Flats,
+    /// `C# Eb F# Ab Bb`, the most common spellings across keys.
+    #[default]
+    Mixed,
+}
+
+/// Glyphs used when rendering accidentals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AccidentalGlyphs {
+    /// ASCII `#` and `b`.
+    #[default]
+    Ascii,
+    /// Unicode `♯` and `♭`.
+    Unicode,
+}
+
+/// Conventions for rendering and parsing note names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NoteFormat {
+    /// Accidental preference for black-key pitch classes.
+    pub accidentals: Accidentals,
+    /// Accidental glyphs used when rendering.
+    pub glyphs: AccidentalGlyphs,
+    /// Octave number written for middle C (`4` scientific, `3` for the Yamaha convention).
+    pub middle_c_octave: i16,
+}
+
+impl Default for NoteFormat {
+    fn default() -> Self {
+        Self::SCIENTIFIC
+    }
+}
+
+/// Error returned when parsing note names, intervals, chord symbols, keys, or Roman numerals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// Input was empty or whitespace only.
+    Empty,
+    /// The note name did not start with a letter A–G.
+    InvalidLetter(char),
+    /// An unexpected character followed the note letter.
+    InvalidAccidental(char),
+    /// The octave number was missing or malformed.
+    InvalidOctave(String),
+    /// The interval label was malformed.
+    InvalidInterval(String),
+    /// The key mode was not recognized.
+    InvalidMode(String),
+    /// The chord-symbol suffix was not recognized.
+    InvalidChordSymbol(String),
+    /// The Roman numeral or its quality suffix was not recognized.
+    InvalidRomanNumeral(String),
+    /// The figured-bass figures were malformed.
+    InvalidFigure(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("input cannot be empty"),
+            Self::InvalidLetter(ch) => {
+                write!(f, "note must begin with A, B, C, D, E, F, or G (found '{ch}')")
+            }
+            Self::InvalidAccidental(ch) => write!(f, "unrecognized accidental '{ch}' (use # or b)"),
+            Self::InvalidOctave(input) => write!(f, "invalid octave number '{input}'"),
+            Self::InvalidInterval(input) => {
+                write!(f, "invalid interval '{input}' (expected forms like m3, P5, A4)")
+            }
+            Self::InvalidMode(input) => {
+                write!(f, "invalid key mode '{input}' (expected major or minor)")
+            }
+            Self::InvalidChordSymbol(input) => write!(f, "unrecognized chord symbol '{input}'"),
+            Self::InvalidRomanNumeral(input) => write!(f, "unrecognized Roman numeral '{input}'"),
+            Self::InvalidFigure(input) => {
+                write!(f, "invalid figured bass '{input}' (expected figures like 6, 6/4, #6, 4/2)")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Default pitch-class labels (sharps for C#/F#, flats elsewhere).
+pub(crate) const PITCH_CLASS_LABELS: [&str; 12] =
+    ["C", "C#", "D", "Eb", "E", "F", "F#", "G", "Ab", "A", "Bb", "B"];
+const SHARP_LABELS: [&str; 12] = ["C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B"];
+const FLAT_LABELS: [&str; 12] = ["C", "Db", "D", "Eb", "E", "F", "Gb", "G", "Ab", "A", "Bb", "B"];
+const MIXED_GLYPHS: [&str; 12] = ["C", "C♯", "D", "E♭", "E", "F", "F♯", "G", "A♭", "A", "B♭", "B"];
+const SHARP_GLYPHS: [&str; 12] = ["C", "C♯", "D", "D♯", "E", "F", "F♯", "G", "G♯", "A", "A♯", "B"];
+const FLAT_GLYPHS: [&str; 12] = ["C", "D♭", "D", "E♭", "E", "F", "G♭", "G", "A♭", "A", "B♭", "B"];
+
+/// Semitone offsets of the natural letters `C D E F G A B` within the octave.
+const LETTER_SEMITONES: [(char, i32); 7] =
+    [('C', 0), ('D', 2), ('E', 4), ('F', 5), ('G', 7), ('A', 9), ('B', 11)];
+
+impl NoteFormat {
+    /// Mixed accidentals, ASCII glyphs, scientific octave numbers.
+    pub const SCIENTIFIC: Self = Self {
+        accidentals: Accidentals::Mixed,
+        glyphs: AccidentalGlyphs::Ascii,
+        middle_c_octave: 4,
+    };
+
+    /// Label for a pitch class (`"F#"`, `"Bb"`).
+    #[must_use]
+    pub fn pitch_class_label(&self, pitch_class: PitchClass12) -> &'static str {
+        let table = match (self.accidentals, self.glyphs) {
+            (Accidentals::Mixed, AccidentalGlyphs::Ascii) => &PITCH_CLASS_LABELS,
+            (Accidentals::Sharps, AccidentalGlyphs::Ascii) => &SHARP_LABELS,
+            (Accidentals::Flats, AccidentalGlyphs::Ascii) => &FLAT_LABELS,
+            (Accidentals::Mixed, AccidentalGlyphs::Unicode) => &MIXED_GLYPHS,
+            (Accidentals::Sharps, AccidentalGlyphs::Unicode) => &SHARP_GLYPHS,
+            (Accidentals::Flats, AccidentalGlyphs::Unicode) => &FLAT_GLYPHS,
+        };
+        table[usize::from(pitch_class.to_semitones() % 12)]
+    }
+
+    /// Label for a pitch with its written octave (`"C#4"`).
+    #[must_use]
+    pub fn pitch_label(&self, pitch: Pitch12) -> String {
+        let octave = i32::from(pitch.octave) + i32::from(self.middle_c_octave) - 4;
+        format!("{}{octave}", self.pitch_class_label(pitch.pitch_class))
+    }
+
+    /// Label for a key (`"F# minor"`).
+    #[must_use]
+    pub fn key_label(&self, key: Key12) -> String {
+        format!("{} {}", self.pitch_class_label(key.tonic), mode_label(key.mode))
+    }
+
+    /// Parse a pitch-class name such as `"F#"`, `"Bb"`, or `"C##"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError`] when the letter or accidentals are not recognized.
+    pub fn parse_pitch_class(&self, input: &str) -> Result<PitchClass12, ParseError> {
+        parse_pitch_class(input).map(PitchClass12::from_semitones)
+    }
+
+    /// Parse a pitch with a written octave (`"Bb3"`, `"C##5"`, `"C-1"`).
+    ///
+    /// Accidentals may cross octave boundaries: `"B#3"` is the same pitch as `"C4"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError`] when the note name or octave number is malformed.
+    pub fn parse_pitch(&self, input: &str) -> Result<Pitch12, ParseError> {
+        let (semitone, octave) = parse_pitch(input)?;
+        let scientific = i32::from(octave) + 4 - i32::from(self.middle_c_octave);
+        let octave = i16::try_from(scientific)
+            .map_err(|_| ParseError::InvalidOctave(input.trim().to_string()))?;
+        Ok(Pitch12::from_semitones_and_octave(semitone, octave))
+    }
+}
+
+impl fmt::Display for PitchClass<T12> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(NoteFormat::SCIENTIFIC.pitch_class_label(*self))
+    }
+}
+
+impl FromStr for PitchClass<T12> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        NoteFormat::SCIENTIFIC.parse_pitch_class(input)
+    }
+}
+
+impl fmt::Display for Pitch<T12> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&NoteFormat::SCIENTIFIC.pitch_label(*self))
+    }
+}
+
+impl FromStr for Pitch<T12> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        NoteFormat::SCIENTIFIC.parse_pitch(input)
+    }
+}
+
+impl fmt::Display for Interval<T12> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&interval_label(self.steps()))
+    }
+}
+
+impl FromStr for Interval<T12> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_interval(input).map(Interval12::from_semitones)
+    }
+}
+
+impl fmt::Display for Chord<T12> {
+    /// Chord symbol with slash bass (`"Am7/G"`), or a polychord (`"D|C"`); other unnamed
+    /// sonorities list their tones (`"[C, C#, D]"`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bass = self.bass.map(PitchClass12::to_semitones);
+        if let (Some(root), Some(kind)) = (self.root(), self.kind()) {
+            return f.write_str(&chord_symbol(root.to_semitones(), kind, bass));
+        }
+        if let Some(poly @ CompoundChord::Polychord { .. }) = self.identify_compound() {
+            return write!(f, "{poly}");
+        }
+        let labels: Vec<&str> = self
+            .root_position()
+            .iter()
+            .map(|tone| PITCH_CLASS_LABELS[usize::from(tone.to_semitones() % 12)])
+            .collect();
+        write!(f, "[{}]", labels.join(", "))?;
+        match bass {
+            Some(bass) => write!(f, "/{}", PITCH_CLASS_LABELS[usize::from(bass % 12)]),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FromStr for Chord<T12> {
+    type Err = ParseError;
+
+    /// Accepts chord symbols with an optional slash bass (`"C/E"`, `"Am7/G"`, `"Bbm7b5"`) and
+    /// polychords, flattened (`"D|C"`).
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input.contains('|') {
+            return input.parse::<CompoundChord<T12>>().map(|poly| poly.to_chord());
+        }
+        let (root, kind, bass) = parse_chord_symbol(input)?;
+        let chord = Chord12::from_kind(PitchClass12::from_semitones(root), kind);
+        Ok(match bass {
+            Some(bass) => chord.with_bass(PitchClass12::from_semitones(bass)),
+            None => chord,
+        })
+    }
+}
+
+impl fmt::Display for Key<T12> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&key_label(self.tonic.to_semitones(), self.mode))
+    }
+}
+
+impl FromStr for Key<T12> {
+    type Err = ParseError;
+
+    /// Accepts `"F# minor"`, `"F#m"`, `"F#min"`, `"Eb major"`, `"EbM"`, or a bare tonic (major).
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (tonic, mode) = parse_key(input)?;
+        Ok(Key12::new(PitchClass12::from_semitones(tonic), mode))
+    }
+}
+
+/// Parse a letter plus accidentals prefix, returning the signed semitone offset (relative to C
+/// of the same octave) and the unparsed remainder.
+pub(crate) fn parse_note_name(input: &str) -> Result<(i32, &str), ParseError> {
+    let mut chars = input.char_indices();
+    let (_, first) = chars.next().ok_or(ParseError::Empty)?;
+    let letter = first.to_ascii_uppercase();
+    let (_, base) = LETTER_SEMITONES
+        .iter()
+        .find(|(name, _)| *name == letter)
+        .ok_or(ParseError::InvalidLetter(first))?;
+    let mut offset = *base;
+    let mut rest = &input[first.len_utf8()..];
+    for (index, ch) in chars {
+        let delta = match ch {
+            '#' | '♯' => 1,
+            'b' | '♭' => -1,
+            'x' | '𝄪' => 2,
+            '𝄫' => -2,
+            _ => break,
+        };
+        offset += delta;
+        rest = &input[index + ch.len_utf8()..];
+    }
+    Ok((offset, rest))
+}
+
+/// Parse a pitch-class label such as `"F#"` or `"Bb"` into a semitone index.
+pub(crate) fn parse_pitch_class(input: &str) -> Result<u16, ParseError> {
+    let (offset, rest) = parse_note_name(input.trim())?;
+    match rest.chars().next() {
+        None => Ok(semitone_index(offset)),
+        Some(ch) => Err(ParseError::InvalidAccidental(ch)),
+    }
+}
+
+/// Parse a scientific pitch label (`"C#4"`, `"Bb-1"`) into `(semitone, octave)`.
+pub(crate) fn parse_pitch(input: &str) -> Result<(u16, i16), ParseError> {
+    let (offset, rest) = parse_note_name(input.trim())?;
+    let invalid = || ParseError::InvalidOctave(rest.to_string());
+    let octave: i32 = rest.parse().map_err(|_| invalid())?;
+    let absolute =
+        octave.checked_mul(12).and_then(|s| s.checked_add(offset)).ok_or_else(invalid)?;
+    let octave = i16::try_from(absolute.div_euclid(12)).map_err(|_| invalid())?;
+    Ok((semitone_index(absolute), octave))
+}
+
+/// Interval label (`"m3"`, `"P5"`, `"M9"`, `"-P4"`) for a signed semitone distance.
+pub(crate) fn interval_label(semitones: i32) -> String {
+    const SIMPLE: [(char, u32); 12] = [
+        ('P', 1),
+        ('m', 2),
+        ('M', 2),
+        ('m', 3),
+        ('M', 3),
+        ('P', 4),
+        ('A', 4),
+        ('P', 5),
+        ('m', 6),
+        ('M', 6),
+        ('m', 7),
+        ('M', 7),
+    ];
+    let sign = if semitones < 0 { "-" } else { "" };
+    let magnitude = semitones.unsigned_abs();
+    let octaves = magnitude / 12;
+    if octaves > 0 && magnitude.is_multiple_of(12) {
+        return format!("{sign}P{}", 1 + 7 * octaves);
+    }
+    let (quality, number) = SIMPLE[(magnitude % 12) as usize];
+    format!("{sign}{quality}{}", number + 7 * octaves)
+}
+
+/// Parse an interval label into a signed semitone distance.
+///
+/// Qualities: `P` perfect, `M` major, `m` minor, `A`/`d` augmented/diminished (repeatable).
+pub(crate) fn parse_interval(input: &str) -> Result<i32, ParseError> {
+    const GENERIC_SEMITONES: [i32; 7] = [0, 2, 4, 5, 7, 9, 11];
+    let trimmed = input.trim();
+    let invalid = || ParseError::InvalidInterval(trimmed.to_string());
+    let (sign, body) = match trimmed.strip_prefix('-') {
+        Some(body) => (-1, body),
+        None => (1, trimmed),
+    };
+    let split = body.find(|ch: char| ch.is_ascii_digit()).ok_or_else(invalid)?;
+    let (quality, number) = body.split_at(split);
+    let number: i32 = number.parse().ok().filter(|n| *n > 0).ok_or_else(invalid)?;
+    let generic = usize::try_from((number - 1) % 7).map_err(|_| invalid())?;
+    let octaves = (number - 1) / 7;
+    let perfect = matches!(generic, 0 | 3 | 4);
+    let count = |symbol: char| {
+        (!quality.is_empty() && quality.chars().all(|ch| ch == symbol))
+            .then(|| i32::try_from(quality.len()).ok())
+            .flatten()
+    };
+    let adjustment = match (quality, perfect) {
+        ("P", true) | ("M", false) => 0,
+        ("m", false) => -1,
+        _ => match (count('A'), count('d')) {
+            (Some(sharps), _) => sharps,
+            (_, Some(flats)) if perfect => -flats,
+            (_, Some(flats)) => -flats - 1,
+            _ => return Err(invalid()),
+        },
+    };
+    12_i32
+        .checked_mul(octaves)
+        .and_then(|octave_semitones| octave_semitones.checked_add(GENERIC_SEMITONES[generic]))
+        .and_then(|semitones| semitones.checked_add(adjustment))
+        .and_then(|semitones| semitones.checked_mul(sign))
+        .ok_or_else(invalid)
+}
+
+/// Chord symbol (`"Cmaj7"`, `"F#m"`, `"C/E"`) for a root semitone, kind, and optional bass.
+pub(crate) fn chord_symbol(root: u16, kind: ChordKind, bass: Option<u16>) -> String {
+    let symbol = format!("{}{}", PITCH_CLASS_LABELS[usize::from(root % 12)], kind.symbol());
+    match bass {
+        Some(bass) if bass % 12 != root % 12 => {
+            format!("{symbol}/{}", PITCH_CLASS_LABELS[usize::from(bass % 12)])
+        }
+        _ => symbol,
+    }
+}
+
+/// Parse a chord symbol with an optional slash bass into `(root, kind, bass)` semitones.
+///
+/// A trailing `/X` is read as a bass only when `X` is a note name, so `"C6/9"` stays a six-nine.
+pub(crate) fn parse_chord_symbol(input: &str) -> Result<(u16, ChordKind, Option<u16>), ParseError> {
+    let trimmed = input.trim();
+    let (symbol, bass) = match trimmed.rsplit_once('/') {
+        Some((symbol, bass)) => match parse_pitch_class(bass) {
+            Ok(bass) => (symbol, Some(bass)),
+            Err(_) => (trimmed, None),
+        },
+        None => (trimmed, None),
+    };
+    let (offset, suffix) = parse_note_name(symbol.trim())?;
+    let kind = ChordKind::from_symbol(suffix)
+        .ok_or_else(|| ParseError::InvalidChordSymbol(trimmed.to_string()))?;
+    Ok((semitone_index(offset), kind, bass))
+}
+
+/// Key label (`"C major"`, `"F# minor"`) for a tonic semitone.
+pub(crate) fn key_label(tonic: u16, mode: Mode) -> String {
+    format!("{} {}", PITCH_CLASS_LABELS[usize::from(tonic % 12)], mode_label(mode))
+}
+
+/// Parse a key label: a tonic followed by an optional mode (`"minor"`, `"m"`, `"maj"`, ...).
+pub(crate) fn parse_key(input: &str) -> Result<(u16, Mode), ParseError> {
+    let (offset, rest) = parse_note_name(input.trim())?;
+    let mode = match rest.trim() {
+        "" | "M" => Mode::Major,
+        "m" => Mode::Minor,
+        word => match word.to_ascii_lowercase().as_str() {
+            "major" | "maj" => Mode::Major,
+            "minor" | "min" => Mode::Minor,
+            _ => return Err(ParseError::InvalidMode(word.to_string())),
+        },
+    };
+    Ok((semitone_index(offset), mode))
+}
+
+fn mode_label(mode: Mode) -> &'static str {
+    match mode {
+        Mode::Major => "major",
+        Mode::Minor => "minor",
+    }
+}
+
+fn semitone_index(offset: i32) -> u16 {
+    u16::try_from(offset.rem_euclid(12)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pitches_parse_with_any_spelling() {
+        assert_eq!("bb".parse::<PitchClass12>(), Ok(PitchClass12::from_semitones(10)));
+        assert_eq!("E♭".parse::<PitchClass12>(), Ok(PitchClass12::from_semitones(3)));
+        assert_eq!("H".parse::<PitchClass12>(), Err(ParseError::InvalidLetter('H')));
+        assert_eq!("C$".parse::<PitchClass12>(), Err(ParseError::InvalidAccidental('$')));
+        assert_eq!("  ".parse::<PitchClass12>(), Err(ParseError::Empty));
+        let pitch = Pitch12::from_semitones_and_octave;
+        assert_eq!("C##5".parse::<Pitch12>(), Ok(pitch(2, 5)));
+        assert_eq!("B#3".parse::<Pitch12>(), Ok(pitch(0, 4)));
+        assert_eq!("Cb4".parse::<Pitch12>(), Ok(pitch(11, 3)));
+        assert_eq!("C-1".parse::<Pitch12>(), Ok(pitch(0, -1)));
+        assert!(matches!("C".parse::<Pitch12>(), Err(ParseError::InvalidOctave(_))));
+    }
+
+    #[test]
+    fn formats_follow_accidental_and_octave_conventions() {
+        let c_sharp = Pitch12::from_semitones_and_octave(1, 4);
+        let flats = NoteFormat { accidentals: Accidentals::Flats, ..NoteFormat::default() };
+        assert_eq!(flats.pitch_label(c_sharp), "Db4");
+        let yamaha = NoteFormat {
+            glyphs: AccidentalGlyphs::Unicode,
+            middle_c_octave: 3,
+            ..NoteFormat::default()
+        };
+        assert_eq!(yamaha.pitch_label(c_sharp), "C♯3");
+        assert_eq!(yamaha.parse_pitch("C3"), Ok(Pitch12::from_semitones_and_octave(0, 4)));
+        for semitone in 0..12 {
+            let pc = PitchClass12::from_semitones(semitone);
+            assert_eq!(pc.to_string().parse::<PitchClass12>(), Ok(pc));
+        }
+    }
+
+    #[test]
+    fn interval_labels_round_trip() {
+        for semitones in -30..=30 {
+            let interval = Interval12::from_semitones(semitones);
+            assert_eq!(interval.to_string().parse::<Interval12>(), Ok(interval));
+        }
+        assert_eq!(Interval12::from_semitones(6).to_string(), "A4");
+        assert_eq!(Interval12::from_semitones(14).to_string(), "M9");
+        assert_eq!(Interval12::from_semitones(24).to_string(), "P15");
+        assert_eq!("d5".parse::<Interval12>(), Ok(Interval12::from_semitones(6)));
+        assert_eq!("d7".parse::<Interval12>(), Ok(Interval12::from_semitones(9)));
+        assert!("P3".parse::<Interval12>().is_err());
+        let huge = "P2147483647";
+        let overflow = Err(ParseError::InvalidInterval(huge.to_string()));
+        assert_eq!(huge.parse::<Interval12>(), overflow);
+    }
+
+    #[test]
+    fn keys_accept_long_and_short_modes() {
+        let f_sharp_minor = Key12::minor(PitchClass12::from_semitones(6));
+        assert_eq!("F# minor".parse::<Key12>(), Ok(f_sharp_minor));
+        assert_eq!("F#m".parse::<Key12>(), Ok(f_sharp_minor));
+        assert_eq!("Bb".parse::<Key12>().map(|key| key.to_string()), Ok("Bb major".to_string()));
+        assert!(matches!("C dorian".parse::<Key12>(), Err(ParseError::InvalidMode(_))));
+    }
+
+    #[test]
+    fn chord_symbols_carry_slash_basses() {
+        let pc = PitchClass12::from_semitones;
+        let chord: Chord12 = "Am7/G".parse().expect("slash chord");
+        assert_eq!(chord.tones, Chord12::minor_seventh(pc(9)).tones);
+        assert_eq!((chord.sounding_bass(), chord.inversion()), (Some(pc(7)), Some(3)));
+        assert_eq!(chord.to_string(), "Am7/G");
+        assert_eq!("C6/9".parse::<Chord12>().map(|c| c.bass), Ok(None));
+        assert_eq!("D/C".parse::<Chord12>().map(|c| c.to_string()), Ok("D/C".to_string()));
+        let cluster = Chord12::from_intervals(pc(0), &[0, 1, 2]).with_bass(pc(6));
+        assert_eq!(cluster.to_string(), "[C, C#, D]/F#");
+        assert_eq!("Csus4".parse::<Chord12>().map(|c| c.to_string()), Ok("Csus4".to_string()));
+        assert!(matches!("Csus3".parse::<Chord12>(), Err(ParseError::InvalidChordSymbol(_))));
+    }
+}
@@ -3,34 +3,55 @@
 
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 
-/// Abstract beat index within a piece (non-negative floating value).
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-pub struct Beat(f64);
+use crate::Rational;
+
+/// Abstract beat index within a piece (non-negative exact value).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Beat(Rational);
 
 impl Beat {
     /// Create a beat index ensuring it is finite and non-negative.
+    ///
+    /// The value is snapped to the float grid (see [`Rational::from_f64`]).
     #[must_use]
     pub fn new(value: f64) -> Self {
         assert!(value.is_finite() && value >= 0.0, "beat index must be finite and non-negative");
+        Self::from_rational(Rational::from_f64(value).expect("beat index out of range"))
+    }
+
+    /// Create from an exact value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is negative.
+    #[must_use]
+    pub fn from_rational(value: Rational) -> Self {
+        assert!(!value.is_negative(), "beat index must be finite and non-negative");
         Self(value)
     }
 
     /// Zero-beat convenience.
     #[must_use]
     pub const fn zero() -> Self {
-        Self(0.0)
+        Self(Rational::ZERO)
     }
 
     /// Raw beat value.
     #[must_use]
     pub fn as_f64(self) -> f64 {
+        self.0.to_f64()
+    }
+
+    /// Exact beat value.
+    #[must_use]
+    pub const fn as_rational(self) -> Rational {
         self.0
     }
 
     /// Attempt to subtract another beat, returning `None` if it would go negative.
     #[must_use]
     pub fn checked_sub(self, other: Self) -> Option<Self> {
-        (self.0 >= other.0).then(|| Self::new(self.0 - other.0))
+        (self.0 >= other.0).then(|| Self(self.0 - other.0))
     }
 }
 
@@ -42,7 +63,7 @@ impl From<f64> for Beat {
 
 impl From<Beat> for f64 {
     fn from(beat: Beat) -> Self {
-        beat.0
+        beat.as_f64()
     }
 }
 
@@ -50,7 +71,7 @@ impl Add for Beat {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Self::new(self.0 + rhs.0)
+        Self(self.0 + rhs.0)
     }
 }
 
@@ -86,6 +107,8 @@ mod tests {
         assert!((a.as_f64() - 5.5).abs() < 1e-9);
         a -= b;
         assert!((a.as_f64() - 4.0).abs() < 1e-9);
+        let triplet = Beat::from_rational(Rational::new(1, 3));
+        assert_eq!(triplet + triplet + triplet, Beat::new(1.0));
     }
 
     #[test]
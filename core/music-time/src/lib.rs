@@ -1,14 +1,29 @@
 //! core/music-time/src/lib.rs
 //! Time grid primitives: beat, meter, tempo, time points and spans.
+//!
+//! Positions and durations are exact [`Rational`] beat counts (quarter note = 1), so tuplet
+//! grids stay exact; `f64` constructors and accessors remain for interop. A [`TempoMap`] adds
+//! tempo changes and ramps on top of a single [`Tempo`], and a [`MeterMap`] numbers bars across
+//! meter changes and pickups with bar:beat:tick addressing.
+//!
+//! Enable the `serde` feature to serialize them (beats as numbers, meters as `"6/8"`).
 
 pub mod beat;
 pub mod meter;
+pub mod metermap;
+pub mod rational;
+#[cfg(feature = "serde")]
+mod serde_impls;
 pub mod tempo;
+pub mod tempomap;
 pub mod timegrid;
 pub mod timespan;
 
 pub use beat::Beat;
 pub use meter::Meter;
+pub use metermap::{Bbt, MeterChange, MeterMap};
+pub use rational::{Rational, RationalError};
 pub use tempo::Tempo;
+pub use tempomap::{TempoEvent, TempoMap, TempoRamp};
 pub use timegrid::{GridConfig, TimeGrid};
 pub use timespan::{TimePoint, TimeSpan};
@@ -1,6 +1,7 @@
 //! core/music-time/src/meter.rs
 //! Meter (time signature).
 
+use crate::Rational;
 use crate::timespan::TimeSpan;
 
 /// Time signature / meter.
@@ -43,7 +44,45 @@ impl Meter {
     /// Length of a single bar as a [`TimeSpan`] using quarter-note beats.
     #[must_use]
     pub fn bar_span(&self) -> TimeSpan {
-        TimeSpan::new(self.beats_per_bar())
+        TimeSpan::exact(4 * i64::from(self.numerator), i64::from(self.denominator))
+    }
+
+    /// Length of the denominator unit in quarter-note beats (`1/2` in 6/8).
+    #[must_use]
+    pub fn unit_span(&self) -> TimeSpan {
+        TimeSpan::exact(4, i64::from(self.denominator))
+    }
+
+    /// Whether the meter groups its beats in threes (6/8, 9/8, 12/16, 6/4, ...).
+    ///
+    /// Any denominator counts; 3/8 and 3/4 stay simple triple meters with one pulse per unit.
+    #[must_use]
+    pub fn is_compound(&self) -> bool {
+        self.numerator > 3 && self.numerator.is_multiple_of(3)
+    }
+
+    /// Felt pulse length in quarter-note beats: the dotted beat in compound meters, the
+    /// denominator unit otherwise.
+    #[must_use]
+    pub fn pulse_span(&self) -> TimeSpan {
+        if self.is_compound() { self.unit_span() * 3 } else { self.unit_span() }
+    }
+
+    /// Metric weight of a position `offset` beats after a downbeat.
+    ///
+    /// Downbeats weigh `1.0`, other pulses `0.5`, and positions between pulses `0.25`.
+    #[must_use]
+    pub fn metric_weight(&self, offset: TimeSpan) -> f64 {
+        let on = |unit: TimeSpan| {
+            offset.as_rational().rem_euclid(unit.as_rational()) == Some(Rational::ZERO)
+        };
+        if on(self.bar_span()) {
+            1.0
+        } else if on(self.pulse_span()) {
+            0.5
+        } else {
+            0.25
+        }
     }
 
     /// How many bars fit within the provided span.
@@ -73,4 +112,21 @@ mod tests {
         let span = TimeSpan::new(15.0);
         assert_eq!(meter.bars_for_span(span), 3.0);
     }
+
+    #[test]
+    fn pulses_and_metric_weights() {
+        assert!(Meter::SIX_EIGHT.is_compound() && !Meter::THREE_FOUR.is_compound());
+        assert!(Meter::new(6, 4).is_compound() && Meter::new(12, 4).is_compound());
+        assert!(!Meter::new(3, 8).is_compound());
+        assert_eq!(Meter::new(9, 4).pulse_span().as_beats(), 3.0);
+        assert_eq!(Meter::SIX_EIGHT.pulse_span().as_beats(), 1.5);
+        assert_eq!(Meter::SEVEN_EIGHT.pulse_span().as_beats(), 0.5);
+        let weight = |meter: Meter, beats| meter.metric_weight(TimeSpan::new(beats));
+        assert_eq!(weight(Meter::FOUR_FOUR, 8.0), 1.0);
+        assert_eq!(weight(Meter::FOUR_FOUR, 2.0), 0.5);
+        assert_eq!(weight(Meter::FOUR_FOUR, 2.5), 0.25);
+        assert_eq!(weight(Meter::SIX_EIGHT, 1.5), 0.5);
+        assert_eq!(weight(Meter::SIX_EIGHT, 1.0), 0.25);
+        assert_eq!(Meter::SEVEN_EIGHT.bar_span(), TimeSpan::exact(7, 2));
+    }
 }
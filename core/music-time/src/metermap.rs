@@ -0,0 +1,342 @@
+//! core/music-time/src/metermap.rs
+//! Meter maps: time-signature changes, pickup bars, and bar:beat:tick addressing.
+//!
+//! A [`MeterMap`] numbers bars from 1 at the first full bar; an optional pickup (anacrusis) is
+//! bar 0 and holds the tail of a bar in the opening meter. Meter changes land on bar
+//! boundaries. Absolute [`TimePoint`]s convert to [`Bbt`] positions (bar, 1-based beat in the
+//! meter's denominator unit, tick within that beat) and back, and [`MeterMap::grid`] lays a
+//! [`TimeGrid`] across meter changes.
+//!
+//! ```
+//! use music_time::{Bbt, Meter, MeterMap, TimePoint, TimeSpan};
+//! // One-beat pickup in 4/4, switching to 3/4 at bar 3.
+//! let map = MeterMap::new(Meter::FOUR_FOUR)
+//!     .with_pickup(TimeSpan::new(1.0))
+//!     .with_change(3, Meter::THREE_FOUR);
+//! assert_eq!(map.to_bbt(TimePoint::zero()), Bbt::new(0, 4, 0));
+//! assert_eq!(map.bar_start(3), TimePoint::new(9.0));
+//! assert_eq!(map.to_bbt(TimePoint::new(11.5)).to_string(), "3:3:240");
+//! assert_eq!(map.to_point(Bbt::new(3, 3, 240)), Some(TimePoint::new(11.5)));
+//! ```
+
+use core::fmt;
+
+use crate::{Meter, Rational, TimeGrid, TimePoint, TimeSpan};
+
+/// Meter taking effect at the start of a bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MeterChange {
+    pub bar: u32,
+    pub meter: Meter,
+}
+
+/// Bar:beat:tick position. Bars count from 1 (0 is the pickup), beats from 1 in the meter's
+/// denominator unit, and ticks from 0 at the map's ticks-per-quarter resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bbt {
+    pub bar: u32,
+    pub beat: u32,
+    pub tick: u32,
+}
+
+impl Bbt {
+    #[must_use]
+    pub const fn new(bar: u32, beat: u32, tick: u32) -> Self {
+        Self { bar, beat, tick }
+    }
+}
+
+impl fmt::Display for Bbt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{:03}", self.bar, self.beat, self.tick)
+    }
+}
+
+/// Bar numbering authority: meters per bar, the pickup, and tick resolution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeterMap {
+    changes: Vec<MeterChange>,
+    /// Start of each change's first bar.
+    starts: Vec<TimePoint>,
+    pickup: TimeSpan,
+    ticks_per_quarter: u32,
+}
+
+impl MeterMap {
+    /// Tick resolution matching common MIDI files.
+    pub const DEFAULT_TICKS_PER_QUARTER: u32 = 480;
+
+    /// Constant `meter` from bar 1 at beat zero, without a pickup.
+    #[must_use]
+    pub fn new(meter: Meter) -> Self {
+        Self {
+            changes: vec![MeterChange { bar: 1, meter }],
+            starts: vec![TimePoint::zero()],
+            pickup: TimeSpan::zero(),
+            ticks_per_quarter: Self::DEFAULT_TICKS_PER_QUARTER,
+        }
+    }
+
+    /// Open with a pickup bar (bar 0) of `length`; bar 1 then starts at `length`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `length` is not shorter than a bar of the opening meter.
+    #[must_use]
+    pub fn with_pickup(mut self, length: TimeSpan) -> Self {
+        assert!(length < self.changes[0].meter.bar_span(), "pickup must be shorter than a bar");
+        self.pickup = length;
+        self.rebuild();
+        self
+    }
+
+    /// Builder form of [`MeterMap::insert`].
+    #[must_use]
+    pub fn with_change(mut self, bar: u32, meter: Meter) -> Self {
+        self.insert(bar, meter);
+        self
+    }
+
+    /// Set the tick resolution used by [`Bbt`] positions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ticks` is zero.
+    #[must_use]
+    pub fn with_ticks_per_quarter(mut self, ticks: u32) -> Self {
+        assert!(ticks > 0, "ticks per quarter must be > 0");
+        self.ticks_per_quarter = ticks;
+        self
+    }
+
+    /// Switch to `meter` at the start of `bar`, replacing any change already there.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bar` is 0 (the pickup always shares bar 1's meter), or if a new bar-1 meter
+    /// is not longer than the pickup.
+    pub fn insert(&mut self, bar: u32, meter: Meter) {
+        assert!(bar > 0, "meter changes start at bar 1");
+        assert!(bar > 1 || self.pickup < meter.bar_span(), "pickup must be shorter than a bar");
+        let change = MeterChange { bar, meter };
+        match self.changes.binary_search_by_key(&bar, |existing| existing.bar) {
+            Ok(index) => self.changes[index] = change,
+            Err(index) => self.changes.insert(index, change),
+        }
+        self.rebuild();
+    }
+
+    /// Meter changes in bar order; the first is at bar 1.
+    #[must_use]
+    pub fn changes(&self) -> &[MeterChange] {
+        &self.changes
+    }
+
+    #[must_use]
+    pub fn pickup(&self) -> TimeSpan {
+        self.pickup
+    }
+
+    #[must_use]
+    pub fn ticks_per_quarter(&self) -> u32 {
+        self.ticks_per_quarter
+    }
+
+    /// Meter in force during `bar` (bar 1's meter for the pickup).
+    #[must_use]
+    pub fn meter_at_bar(&self, bar: u32) -> Meter {
+        self.changes[self.change_index(bar)].meter
+    }
+
+    /// Meter in force at `point`.
+    #[must_use]
+    pub fn meter_at(&self, point: TimePoint) -> Meter {
+        self.meter_at_bar(self.bar_at(point))
+    }
+
+    /// Downbeat of `bar` (beat zero for the pickup bar).
+    #[must_use]
+    pub fn bar_start(&self, bar: u32) -> TimePoint {
+        if bar == 0 {
+            return TimePoint::zero();
+        }
+        let index = self.change_index(bar);
+        let change = self.changes[index];
+        self.starts[index] + change.meter.bar_span() * (bar - change.bar)
+    }
+
+    /// Length of `bar`: the pickup length for bar 0, a full bar of its meter otherwise.
+    #[must_use]
+    pub fn bar_length(&self, bar: u32) -> TimeSpan {
+        if bar == 0 { self.pickup } else { self.meter_at_bar(bar).bar_span() }
+    }
+
+    /// Number of the bar containing `point`.
+    #[must_use]
+    pub fn bar_at(&self, point: TimePoint) -> u32 {
+        if point < self.starts[0] {
+            return 0;
+        }
+        let index = self.starts.partition_point(|start| *start <= point) - 1;
+        let change = self.changes[index];
+        let bars =
+            (point - self.starts[index]).as_rational() / change.meter.bar_span().as_rational();
+        change.bar.saturating_add(count(bars))
+    }
+
+    /// Bar:beat:tick address of `point`; ticks below the resolution are truncated.
+    #[must_use]
+    pub fn to_bbt(&self, point: TimePoint) -> Bbt {
+        let bar = self.bar_at(point);
+        let meter = self.meter_at_bar(bar);
+        // Pickup beats count from the end of a full bar, so a one-beat 4/4 pickup is beat 4.
+        let offset = (point - self.bar_start(bar)) + (meter.bar_span() - self.bar_length(bar));
+        let unit = meter.unit_span().as_rational();
+        let beats = offset.as_rational() / unit;
+        let within = offset.as_rational() - unit * Rational::from_integer(beats.floor());
+        let ticks = within * Rational::from(self.ticks_per_quarter);
+        Bbt { bar, beat: count(beats) + 1, tick: count(ticks) }
+    }
+
+    /// Absolute position of `bbt`, or `None` when the beat or tick falls outside its bar (or
+    /// before the pickup).
+    #[must_use]
+    pub fn to_point(&self, bbt: Bbt) -> Option<TimePoint> {
+        let meter = self.meter_at_bar(bbt.bar);
+        if bbt.beat == 0 || bbt.beat > u32::from(meter.numerator) {
+            return None;
+        }
+        let tick = TimeSpan::exact(i64::from(bbt.tick), i64::from(self.ticks_per_quarter));
+        if tick >= meter.unit_span() {
+            return None;
+        }
+        let offset = meter.unit_span() * (bbt.beat - 1) + tick;
+        let skipped = meter.bar_span() - self.bar_length(bbt.bar);
+        offset.checked_sub(skipped).map(|into| self.bar_start(bbt.bar) + into)
+    }
+
+    /// Grid of `bars` bars from the downbeat of `first_bar` (0 for the pickup), each bar
+    /// taking its meter and length from the map; `subdivisions_per_beat` splits the meter's
+    /// denominator unit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bars` or `subdivisions_per_beat` is zero.
+    #[must_use]
+    pub fn grid(&self, first_bar: u32, bars: u32, subdivisions_per_beat: u32) -> TimeGrid {
+        assert!(bars > 0, "grid must contain at least one bar");
+        assert!(subdivisions_per_beat > 0, "subdivisions per beat must be > 0");
+        let mut grid =
+            TimeGrid { measures: Vec::new(), beats: Vec::new(), subdivisions: Vec::new() };
+        let mut bar_start = self.bar_start(first_bar);
+        for bar in first_bar..first_bar.saturating_add(bars) {
+            let meter = self.meter_at_bar(bar);
+            let length = self.bar_length(bar);
+            // A pickup bar keeps only the tail of a full bar.
+            let skipped = meter.bar_span() - length;
+            let subdivision_span = meter.unit_span() / subdivisions_per_beat;
+            grid.measures.push(bar_start);
+            for step in 0..u32::from(meter.numerator) * subdivisions_per_beat {
+                let Some(offset) = (subdivision_span * step).checked_sub(skipped) else {
+                    continue;
+                };
+                if step.is_multiple_of(subdivisions_per_beat) {
+                    grid.beats.push(bar_start + offset);
+                }
+                grid.subdivisions.push(bar_start + offset);
+            }
+            bar_start = bar_start + length;
+        }
+        grid.measures.push(bar_start);
+        grid.beats.push(bar_start);
+        grid.subdivisions.push(bar_start);
+        grid
+    }
+
+    fn change_index(&self, bar: u32) -> usize {
+        self.changes.partition_point(|change| change.bar <= bar).saturating_sub(1)
+    }
+
+    fn rebuild(&mut self) {
+        let mut start = TimePoint::zero() + self.pickup;
+        let mut previous: Option<MeterChange> = None;
+        self.starts = self
+            .changes
+            .iter()
+            .map(|change| {
+                if let Some(previous) = previous.replace(*change) {
+                    start = start + previous.meter.bar_span() * (change.bar - previous.bar);
+                }
+                start
+            })
+            .collect();
+    }
+}
+
+/// Whole part of a non-negative ratio as a count.
+fn count(value: Rational) -> u32 {
+    u32::try_from(value.floor()).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bars_follow_meter_changes() {
+        let map = MeterMap::new(Meter::FOUR_FOUR)
+            .with_change(5, Meter::SEVEN_EIGHT)
+            .with_change(3, Meter::SIX_EIGHT);
+        assert_eq!(map.changes().iter().map(|change| change.bar).collect::<Vec<_>>(), [1, 3, 5]);
+        assert_eq!(map.bar_start(3), TimePoint::new(8.0));
+        assert_eq!(map.bar_start(5), TimePoint::new(14.0));
+        assert_eq!(map.bar_start(7), TimePoint::new(21.0));
+        assert_eq!(map.bar_at(TimePoint::new(13.9)), 4);
+        assert_eq!(map.bar_at(TimePoint::new(17.5)), 6);
+        assert_eq!(map.meter_at(TimePoint::new(9.0)), Meter::SIX_EIGHT);
+        assert_eq!(map.bar_length(6), TimeSpan::exact(7, 2));
+    }
+
+    #[test]
+    fn pickup_is_bar_zero_counted_from_the_bar_end() {
+        let map = MeterMap::new(Meter::THREE_FOUR).with_pickup(TimeSpan::new(1.5));
+        assert_eq!(map.to_bbt(TimePoint::zero()), Bbt::new(0, 2, 240));
+        assert_eq!(map.to_bbt(TimePoint::new(0.5)), Bbt::new(0, 3, 0));
+        assert_eq!(map.to_bbt(TimePoint::new(1.5)), Bbt::new(1, 1, 0));
+        assert_eq!(map.to_point(Bbt::new(0, 1, 0)), None);
+        assert_eq!(map.to_point(Bbt::new(0, 3, 0)), Some(TimePoint::new(0.5)));
+        assert_eq!(map.bar_start(2), TimePoint::new(4.5));
+    }
+
+    #[test]
+    fn bbt_round_trips_and_rejects_out_of_bar_positions() {
+        let map = MeterMap::new(Meter::SIX_EIGHT)
+            .with_change(2, Meter::FIVE_FOUR)
+            .with_ticks_per_quarter(960);
+        let triplet = TimePoint::exact(10, 3);
+        let bbt = map.to_bbt(triplet);
+        assert_eq!(bbt, Bbt::new(2, 1, 320));
+        assert_eq!(map.to_point(bbt), Some(triplet));
+        assert_eq!(map.to_bbt(TimePoint::new(2.75)).to_string(), "1:6:240");
+        assert_eq!(map.to_point(Bbt::new(1, 7, 0)), None);
+        assert_eq!(map.to_point(Bbt::new(1, 1, 480)), None);
+        assert_eq!(map.to_point(Bbt::new(2, 0, 0)), None);
+    }
+
+    #[test]
+    fn grid_follows_the_map_from_the_pickup() {
+        let map = MeterMap::new(Meter::THREE_FOUR)
+            .with_pickup(TimeSpan::new(1.0))
+            .with_change(2, Meter::SIX_EIGHT);
+        let grid = map.grid(0, 3, 2);
+        let at = |beats: f64| TimePoint::new(beats);
+        assert_eq!(grid.measures(), [at(0.0), at(1.0), at(4.0), at(7.0)]);
+        assert_eq!(grid.beats()[..3], [at(0.0), at(1.0), at(2.0)]);
+        assert_eq!(grid.beats().len(), 1 + 3 + 6 + 1);
+        assert_eq!(grid.beats()[4..6], [at(4.0), at(4.5)]);
+        assert_eq!(grid.subdivisions().len(), 2 + 6 + 12 + 1);
+        assert!(grid.subdivisions().windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -0,0 +1,387 @@
+//! core/music-time/src/rational.rs
+//! Exact rational numbers for musical positions and durations.
+//!
+//! [`Rational`] keeps a normalized `numerator / denominator` pair (lowest terms, positive
+//! denominator), so triplet and quintuplet positions add up exactly and equal positions
+//! compare and hash equal. Arithmetic runs in `i128` and reduces back to `i64` terms; like
+//! integer arithmetic, the operators panic when the reduced terms still overflow, and the
+//! `checked_*` methods return `None` instead. [`Rational::from_f64`] snaps floating-point
+//! input to a fixed musical grid (`1.0 / 3.0` becomes exactly `1/3`).
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use core::str::FromStr;
+
+/// Normalized fraction with `i64` terms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self { numerator: 0, denominator: 1 };
+    pub const ONE: Self = Self { numerator: 1, denominator: 1 };
+    /// Ticks per unit that [`Rational::from_f64`] snaps to (`2^8 · 3^2 · 5 · 7`).
+    pub const FLOAT_GRID: i64 = 80_640;
+
+    /// Fraction `numerator / denominator` in lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero, or for `i64::MIN / -1`.
+    #[must_use]
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        assert!(denominator != 0, "rational denominator must be non-zero");
+        Self::reduce(i128::from(numerator), i128::from(denominator))
+    }
+
+    /// Like [`Rational::new`], returning `None` for a zero denominator or an overflow.
+    #[must_use]
+    pub fn checked_new(numerator: i64, denominator: i64) -> Option<Self> {
+        Self::checked_reduce(i128::from(numerator), i128::from(denominator))
+    }
+
+    /// Whole number `value / 1`.
+    #[must_use]
+    pub const fn from_integer(value: i64) -> Self {
+        Self { numerator: value, denominator: 1 }
+    }
+
+    /// Nearest multiple of `1 / FLOAT_GRID` to `value`, or `None` for non-finite values and
+    /// magnitudes beyond the `i64` tick range.
+    ///
+    /// Snapping every float to one grid keeps sums of float-derived values on that grid, so
+    /// their terms never grow; halves through 256ths and 3-, 5- and 7-tuplets stay exact.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)] // Range-checked.
+    pub fn from_f64(value: f64) -> Option<Self> {
+        let ticks = (value * Self::FLOAT_GRID as f64).round();
+        if !ticks.is_finite() || ticks.abs() >= 9.0e18 {
+            return None;
+        }
+        Self::checked_new(ticks as i64, Self::FLOAT_GRID)
+    }
+
+    #[must_use]
+    pub const fn numerator(self) -> i64 {
+        self.numerator
+    }
+
+    /// Always positive.
+    #[must_use]
+    pub const fn denominator(self) -> i64 {
+        self.denominator
+    }
+
+    /// Nearest `f64`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Interop conversion; exactness ends here.
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    #[must_use]
+    pub const fn is_integer(self) -> bool {
+        self.denominator == 1
+    }
+
+    #[must_use]
+    pub const fn is_negative(self) -> bool {
+        self.numerator < 0
+    }
+
+    /// Largest integer not above the value.
+    #[must_use]
+    pub const fn floor(self) -> i64 {
+        self.numerator.div_euclid(self.denominator)
+    }
+
+    /// Smallest integer not below the value.
+    #[must_use]
+    pub const fn ceil(self) -> i64 {
+        -(-self.numerator).div_euclid(self.denominator)
+    }
+
+    #[must_use]
+    pub const fn abs(self) -> Self {
+        Self { numerator: self.numerator.saturating_abs(), denominator: self.denominator }
+    }
+
+    /// `1 / self`, or `None` for zero.
+    #[must_use]
+    pub fn recip(self) -> Option<Self> {
+        Self::checked_new(self.denominator, self.numerator)
+    }
+
+    /// Remainder of `self / rhs` in `[0, |rhs|)`, or `None` when `rhs` is zero.
+    #[must_use]
+    pub fn rem_euclid(self, rhs: Self) -> Option<Self> {
+        let quotient = self.checked_div(rhs)?.floor();
+        let whole = rhs * Self::from_integer(quotient);
+        let remainder = self - whole;
+        Some(if remainder.is_negative() { remainder + rhs.abs() } else { remainder })
+    }
+
+    /// `self + rhs`, or `None` if the result's terms overflow `i64`.
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::checked_reduce(
+            i128::from(self.numerator) * i128::from(rhs.denominator)
+                + i128::from(rhs.numerator) * i128::from(self.denominator),
+            i128::from(self.denominator) * i128::from(rhs.denominator),
+        )
+    }
+
+    /// `self - rhs`, or `None` if the result's terms overflow `i64`.
+    #[must_use]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::checked_reduce(
+            i128::from(self.numerator) * i128::from(rhs.denominator)
+                - i128::from(rhs.numerator) * i128::from(self.denominator),
+            i128::from(self.denominator) * i128::from(rhs.denominator),
+        )
+    }
+
+    /// `self * rhs`, or `None` if the result's terms overflow `i64`.
+    #[must_use]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::checked_reduce(
+            i128::from(self.numerator) * i128::from(rhs.numerator),
+            i128::from(self.denominator) * i128::from(rhs.denominator),
+        )
+    }
+
+    /// `self / rhs`, or `None` when `rhs` is zero or the result's terms overflow `i64`.
+    #[must_use]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        Self::checked_reduce(
+            i128::from(self.numerator) * i128::from(rhs.denominator),
+            i128::from(self.denominator) * i128::from(rhs.numerator),
+        )
+    }
+
+    /// Lowest terms of `numerator / denominator`, panicking like integer overflow.
+    fn reduce(numerator: i128, denominator: i128) -> Self {
+        Self::checked_reduce(numerator, denominator).expect("rational arithmetic overflowed i64")
+    }
+
+    /// Lowest terms of `numerator / denominator`, or `None` for a zero denominator or terms
+    /// beyond `i64`.
+    fn checked_reduce(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let divisor = gcd(numerator, denominator).max(1);
+        let sign = if denominator < 0 { -1 } else { 1 };
+        let (numerator, denominator) = (sign * numerator / divisor, sign * denominator / divisor);
+        Some(Self {
+            numerator: i64::try_from(numerator).ok()?,
+            denominator: i64::try_from(denominator).ok()?,
+        })
+    }
+}
+
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    a = a.abs();
+    b = b.abs();
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self::from_integer(value)
+    }
+}
+
+impl From<u32> for Rational {
+    fn from(value: u32) -> Self {
+        Self::from_integer(i64::from(value))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (i128::from(self.numerator) * i128::from(other.denominator))
+            .cmp(&(i128::from(other.numerator) * i128::from(self.denominator)))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("rational addition overflowed i64")
+    }
+}
+
+impl AddAssign for Rational {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect("rational subtraction overflowed i64")
+    }
+}
+
+impl SubAssign for Rational {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::reduce(-i128::from(self.numerator), i128::from(self.denominator))
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).expect("rational multiplication overflowed i64")
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(rhs.numerator != 0, "rational division by zero");
+        self.checked_div(rhs).expect("rational division overflowed i64")
+    }
+}
+
+impl fmt::Display for Rational {
+    /// `"7/3"`, or `"4"` for whole numbers.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// Errors from parsing a [`Rational`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RationalError {
+    /// The denominator was zero.
+    ZeroDenominator,
+    /// The input was not `n`, `n/d`, or a decimal number.
+    Malformed(String),
+}
+
+impl fmt::Display for RationalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroDenominator => f.write_str("rational denominator must be non-zero"),
+            Self::Malformed(input) => {
+                write!(f, "invalid rational '{input}' (expected forms like 3, 7/3, 1.5)")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RationalError {}
+
+impl FromStr for Rational {
+    type Err = RationalError;
+
+    /// Accepts `"3"`, `"-7/3"`, and decimal numbers (`"1.5"`, approximated as in
+    /// [`Rational::from_f64`]).
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let malformed = || RationalError::Malformed(input.trim().to_string());
+        let trimmed = input.trim();
+        if let Some((numerator, denominator)) = trimmed.split_once('/') {
+            let numerator = numerator.trim().parse().map_err(|_| malformed())?;
+            let denominator = denominator.trim().parse().map_err(|_| malformed())?;
+            if denominator == 0 {
+                return Err(RationalError::ZeroDenominator);
+            }
+            return Self::checked_new(numerator, denominator).ok_or_else(malformed);
+        }
+        if let Ok(whole) = trimmed.parse::<i64>() {
+            return Ok(Self::from_integer(whole));
+        }
+        trimmed.parse::<f64>().ok().and_then(Self::from_f64).ok_or_else(malformed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fractions_normalize_and_compare() {
+        assert_eq!(Rational::new(6, -4), Rational::new(-3, 2));
+        assert_eq!(Rational::new(-3, 2).denominator(), 2);
+        let third = Rational::new(1, 3);
+        assert_eq!(third + third + third, Rational::ONE);
+        assert!(Rational::new(2, 3) < Rational::new(3, 4));
+        assert_eq!(Rational::new(7, 3).floor(), 2);
+        assert_eq!(Rational::new(-7, 3).floor(), -3);
+        assert_eq!(Rational::new(-7, 3).ceil(), -2);
+        assert_eq!(Rational::new(7, 2).rem_euclid(Rational::new(3, 2)), Some(Rational::new(1, 2)));
+        assert_eq!(Rational::new(7, 2) / Rational::new(7, 4), Rational::from_integer(2));
+        assert_eq!(Rational::checked_new(1, 0), None);
+    }
+
+    #[test]
+    fn overflow_is_reported_not_approximated() {
+        let huge = Rational::from_integer(i64::MAX);
+        assert_eq!(huge.checked_add(Rational::ONE), None);
+        assert_eq!(huge.checked_mul(Rational::new(1, 2)), Some(Rational::new(i64::MAX, 2)));
+        let tiny = Rational::new(1, i64::MAX);
+        assert_eq!(tiny.checked_mul(Rational::new(1, 2)), None);
+        assert_eq!(Rational::checked_new(i64::MIN, -1), None);
+        assert_eq!(huge.checked_sub(huge), Some(Rational::ZERO));
+        assert!(std::panic::catch_unwind(|| huge + Rational::ONE).is_err());
+    }
+
+    #[test]
+    fn floats_recover_simple_fractions() {
+        assert_eq!(Rational::from_f64(1.0 / 3.0), Some(Rational::new(1, 3)));
+        assert_eq!(Rational::from_f64(4.4), Some(Rational::new(22, 5)));
+        assert_eq!(Rational::from_f64(-0.625), Some(Rational::new(-5, 8)));
+        assert_eq!(Rational::from_f64(f64::NAN), None);
+        let awkward = Rational::from_f64(1_234.567_891_2).expect("finite");
+        assert!((awkward.to_f64() - 1_234.567_891_2).abs() <= 0.5 / 80_640.0);
+        assert_eq!(Rational::FLOAT_GRID % awkward.denominator(), 0);
+        assert_eq!(Rational::from_f64(1.0 / 7.0), Some(Rational::new(1, 7)));
+    }
+
+    #[test]
+    fn text_round_trips() {
+        for text in ["7/3", "-1/2", "4"] {
+            assert_eq!(text.parse::<Rational>().map(|r| r.to_string()), Ok(text.to_string()));
+        }
+        assert_eq!("0.75".parse(), Ok(Rational::new(3, 4)));
+        assert_eq!("1/0".parse::<Rational>(), Err(RationalError::ZeroDenominator));
+        assert!(matches!("x/2".parse::<Rational>(), Err(RationalError::Malformed(_))));
+    }
+}
@@ -0,0 +1,380 @@
+//! core/music-time/src/serde_impls.rs
+//! Serde encodings for time primitives (enabled with the `serde` feature).
+//!
+//! Beats, time points, and spans encode as plain beat numbers when `f64` holds them exactly and
+//! as `"7/3"` strings otherwise, tempos as BPM numbers, and meters as `"6/8"` strings in
+//! human-readable formats (`(numerator, denominator)` tuples otherwise). Tempo maps encode as
+//! their event list, meter maps as their changes, pickup, and tick resolution.
+//! Deserialization re-validates every invariant instead of panicking.
+
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+use crate::{
+    Beat, Meter, MeterChange, MeterMap, Rational, Tempo, TempoEvent, TempoMap, TimePoint, TimeSpan,
+};
+
+/// Encode an exact beat count: a plain number when it reads back exactly (whole numbers and
+/// binary fractions down to 256ths, see [`Rational::from_f64`]), an `"n/d"` string otherwise,
+/// and an `(n, d)` tuple in binary formats.
+fn serialize_rational<S: Serializer>(value: Rational, serializer: S) -> Result<S::Ok, S::Error> {
+    if !serializer.is_human_readable() {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&value.numerator())?;
+        tuple.serialize_element(&value.denominator())?;
+        return tuple.end();
+    }
+    let dyadic = value.denominator().count_ones() == 1;
+    let on_grid = Rational::FLOAT_GRID % value.denominator() == 0;
+    if dyadic && on_grid && value.numerator().unsigned_abs() < 1 << 53 {
+        serializer.serialize_f64(value.to_f64())
+    } else {
+        serializer.collect_str(&value)
+    }
+}
+
+struct RationalVisitor;
+
+impl<'de> Visitor<'de> for RationalVisitor {
+    type Value = Rational;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a beat count such as 1.5 or \"7/3\"")
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Rational::from_f64(value)
+            .ok_or_else(|| E::custom(format_args!("beat count must be finite, got {value}")))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(Rational::from_integer(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        i64::try_from(value)
+            .map(Rational::from_integer)
+            .map_err(|_| E::custom(format_args!("beat count {value} out of range")))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let numerator = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let denominator = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Rational::checked_new(numerator, denominator)
+            .ok_or_else(|| de::Error::custom("rational denominator must be non-zero"))
+    }
+}
+
+fn deserialize_rational<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rational, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(RationalVisitor)
+    } else {
+        deserializer.deserialize_tuple(2, RationalVisitor)
+    }
+}
+
+fn non_negative<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    kind: &str,
+) -> Result<Rational, D::Error> {
+    let value = deserialize_rational(deserializer)?;
+    if value.is_negative() {
+        Err(de::Error::custom(format_args!("{kind} must be non-negative and finite, got {value}")))
+    } else {
+        Ok(value)
+    }
+}
+
+impl Serialize for Rational {
+    /// `"7/3"` in human-readable formats, `(7, 3)` otherwise.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serialize_rational(*self, serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Rational {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_rational(deserializer)
+    }
+}
+
+impl Serialize for Beat {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_rational(self.as_rational(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Beat {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        non_negative(deserializer, "beat").map(Beat::from_rational)
+    }
+}
+
+impl Serialize for TimePoint {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_rational(self.as_rational(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TimePoint {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        non_negative(deserializer, "time point").map(TimePoint::from_rational)
+    }
+}
+
+impl Serialize for TimeSpan {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_rational(self.as_rational(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeSpan {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        non_negative(deserializer, "time span").map(TimeSpan::from_rational)
+    }
+}
+
+impl Serialize for Tempo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.bpm())
+    }
+}
+
+impl<'de> Deserialize<'de> for Tempo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bpm = f64::deserialize(deserializer)?;
+        if bpm.is_finite() && bpm > 0.0 {
+            Ok(Tempo::new(bpm))
+        } else {
+            Err(de::Error::custom(format_args!("tempo must be positive and finite, got {bpm}")))
+        }
+    }
+}
+
+impl Serialize for Meter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&format_args!("{}/{}", self.numerator, self.denominator))
+        } else {
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&self.numerator)?;
+            tuple.serialize_element(&self.denominator)?;
+            tuple.end()
+        }
+    }
+}
+
+fn checked_meter<E: de::Error>(numerator: u8, denominator: u8) -> Result<Meter, E> {
+    if numerator == 0 || denominator == 0 {
+        return Err(E::custom("meter numerator and denominator must be > 0"));
+    }
+    Ok(Meter::new(numerator, denominator))
+}
+
+struct MeterVisitor;
+
+impl<'de> Visitor<'de> for MeterVisitor {
+    type Value = Meter;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a time signature such as \"6/8\"")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let invalid = || E::custom(format_args!("invalid meter '{value}'"));
+        let (numerator, denominator) = value.split_once('/').ok_or_else(invalid)?;
+        let numerator = numerator.trim().parse().map_err(|_| invalid())?;
+        let denominator = denominator.trim().parse().map_err(|_| invalid())?;
+        checked_meter(numerator, denominator)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let numerator = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let denominator = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        checked_meter(numerator, denominator)
+    }
+}
+
+impl<'de> Deserialize<'de> for Meter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(MeterVisitor)
+        } else {
+            deserializer.deserialize_tuple(2, MeterVisitor)
+        }
+    }
+}
+
+impl Serialize for TempoMap {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.events().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TempoMap {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let events = Vec::<TempoEvent>::deserialize(deserializer)?;
+        TempoMap::from_events(&events)
+            .ok_or_else(|| de::Error::custom("tempo map needs at least one event"))
+    }
+}
+
+/// Wire form of a [`MeterMap`].
+#[derive(serde::Serialize, serde::Deserialize)]
+struct MeterMapRepr {
+    changes: Vec<MeterChange>,
+    #[serde(default = "TimeSpan::zero")]
+    pickup: TimeSpan,
+    #[serde(default = "default_ticks_per_quarter")]
+    ticks_per_quarter: u32,
+}
+
+const fn default_ticks_per_quarter() -> u32 {
+    MeterMap::DEFAULT_TICKS_PER_QUARTER
+}
+
+impl Serialize for MeterMap {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MeterMapRepr {
+            changes: self.changes().to_vec(),
+            pickup: self.pickup(),
+            ticks_per_quarter: self.ticks_per_quarter(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MeterMap {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut repr = MeterMapRepr::deserialize(deserializer)?;
+        repr.changes.sort_by_key(|change| change.bar);
+        if let Some(pair) = repr.changes.windows(2).find(|pair| pair[0].bar == pair[1].bar) {
+            return Err(de::Error::custom(format!(
+                "duplicate meter change at bar {}",
+                pair[0].bar
+            )));
+        }
+        let opening = match repr.changes.first() {
+            Some(change) if change.bar == 1 => change.meter,
+            _ => return Err(de::Error::custom("meter map needs a meter at bar 1")),
+        };
+        if repr.pickup >= opening.bar_span() {
+            return Err(de::Error::custom("pickup must be shorter than a bar"));
+        }
+        if repr.ticks_per_quarter == 0 {
+            return Err(de::Error::custom("ticks per quarter must be > 0"));
+        }
+        Ok(repr.changes.iter().fold(
+            MeterMap::new(opening)
+                .with_pickup(repr.pickup)
+                .with_ticks_per_quarter(repr.ticks_per_quarter),
+            |map, change| map.with_change(change.bar, change.meter),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{Beat, Meter, MeterMap, Rational, Tempo, TempoMap, TempoRamp, TimePoint, TimeSpan};
+
+    #[test]
+    fn tuplet_positions_stay_exact() {
+        let triplet = TimePoint::exact(7, 3);
+        assert_eq!(serde_json::to_value(triplet).expect("serialize"), json!("7/3"));
+        assert_eq!(serde_json::to_value(TimeSpan::exact(3, 8)).expect("serialize"), json!(0.375));
+        let decoded: TimePoint = serde_json::from_value(json!("7/3")).expect("deserialize");
+        assert_eq!(decoded, triplet);
+        let ratio: Rational = serde_json::from_value(json!("-2/6")).expect("rational");
+        assert_eq!(serde_json::to_value(ratio).expect("serialize"), json!("-1/3"));
+        for fine in [TimePoint::exact(1, 1 << 20), TimePoint::exact(3, 1 << 17)] {
+            let value = serde_json::to_value(fine).expect("serialize");
+            assert!(value.is_string(), "{value}");
+            assert_eq!(serde_json::from_value::<TimePoint>(value).expect("deserialize"), fine);
+        }
+    }
+
+    #[test]
+    fn meter_uses_time_signature_text() {
+        assert_eq!(serde_json::to_value(Meter::SIX_EIGHT).expect("serialize"), json!("6/8"));
+        let meter: Meter = serde_json::from_value(json!("7/8")).expect("deserialize");
+        assert_eq!(meter, Meter::SEVEN_EIGHT);
+        assert!(serde_json::from_value::<Meter>(json!("0/4")).is_err());
+        assert!(serde_json::from_value::<Meter>(json!("4-4")).is_err());
+    }
+
+    #[test]
+    fn numeric_types_round_trip_and_validate() {
+        let point: TimePoint = serde_json::from_value(json!(4.5)).expect("point");
+        assert_eq!(point, TimePoint::new(4.5));
+        let span: TimeSpan = serde_json::from_value(json!(2)).expect("span");
+        assert_eq!(serde_json::to_value(span).expect("serialize"), json!(2.0));
+        let beat: Beat = serde_json::from_value(json!(1.5)).expect("beat");
+        assert_eq!(beat, Beat::new(1.5));
+        let tempo: Tempo = serde_json::from_value(json!(96.0)).expect("tempo");
+        assert_eq!(tempo.bpm(), 96.0);
+
+        assert!(serde_json::from_value::<TimeSpan>(json!(-1.0)).is_err());
+        assert!(serde_json::from_value::<TimePoint>(json!("-1/3")).is_err());
+        assert!(serde_json::from_value::<Tempo>(json!(0.0)).is_err());
+    }
+
+    #[test]
+    fn tempo_map_encodes_its_events() {
+        let map = TempoMap::new(Tempo::new(60.0)).with_event(
+            TimePoint::exact(8, 3),
+            Tempo::new(90.0),
+            TempoRamp::Exponential,
+        );
+        let value = serde_json::to_value(&map).expect("serialize");
+        assert_eq!(
+            value,
+            json!([
+                {"at": 0.0, "tempo": 60.0, "ramp": "instant"},
+                {"at": "8/3", "tempo": 90.0, "ramp": "exponential"},
+            ])
+        );
+        assert_eq!(serde_json::from_value::<TempoMap>(value).expect("deserialize"), map);
+        let stepped: TempoMap =
+            serde_json::from_value(json!([{"at": 4, "tempo": 100}])).expect("default ramp");
+        assert_eq!(stepped.tempo_at(TimePoint::zero()).bpm(), 100.0);
+        assert!(serde_json::from_value::<TempoMap>(json!([])).is_err());
+    }
+
+    #[test]
+    fn meter_map_encodes_changes_and_pickup() {
+        let map = MeterMap::new(Meter::FOUR_FOUR)
+            .with_pickup(TimeSpan::exact(1, 2))
+            .with_change(9, Meter::SEVEN_EIGHT);
+        let value = serde_json::to_value(&map).expect("serialize");
+        assert_eq!(
+            value,
+            json!({
+                "changes": [{"bar": 1, "meter": "4/4"}, {"bar": 9, "meter": "7/8"}],
+                "pickup": 0.5,
+                "ticks_per_quarter": 480,
+            })
+        );
+        assert_eq!(serde_json::from_value::<MeterMap>(value).expect("deserialize"), map);
+        let bad_pickup = json!({"changes": [{"bar": 1, "meter": "3/4"}], "pickup": 3});
+        assert!(serde_json::from_value::<MeterMap>(bad_pickup).is_err());
+        assert!(serde_json::from_value::<MeterMap>(json!({"changes": []})).is_err());
+        let duplicate = json!({
+            "changes": [{"bar": 1, "meter": "4/4"}, {"bar": 1, "meter": "2/8"}],
+            "pickup": 1.5,
+        });
+        let error = serde_json::from_value::<MeterMap>(duplicate).expect_err("duplicate bar");
+        assert_eq!(error.to_string(), "duplicate meter change at bar 1");
+    }
+}
@@ -45,7 +45,8 @@ impl Tempo {
         meter.beats_per_bar() * self.seconds_per_beat()
     }
 
-    /// Convert a beat-based [`TimeSpan`] to seconds.
+    /// Convert a beat-based [`TimeSpan`] to seconds at this constant tempo (see
+    /// [`crate::TempoMap`] for tempo changes and ramps).
     #[must_use]
     pub fn seconds_for_span(&self, span: TimeSpan) -> f64 {
         span.as_beats() * self.seconds_per_beat()
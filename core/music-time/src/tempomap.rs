@@ -0,0 +1,290 @@
+//! core/music-time/src/tempomap.rs
+//! Tempo maps: tempo changes and accelerando/ritardando ramps over the beat axis.
+//!
+//! A [`TempoMap`] is a list of [`TempoEvent`]s, the first at beat zero. Each event names the
+//! tempo reached at its position and, through [`TempoRamp`], how the tempo travels there from
+//! the previous event: an instantaneous change, or a linear or exponential glide. Beat positions
+//! and seconds convert in both directions by integrating `60 / bpm` in closed form across
+//! segments.
+//!
+//! ```
+//! use music_time::{Tempo, TempoMap, TempoRamp, TimePoint};
+//! // Four beats at 60 BPM, then a linear accelerando to 120 BPM over the next four.
+//! let map = TempoMap::new(Tempo::new(60.0))
+//!     .with_event(TimePoint::new(4.0), Tempo::new(60.0), TempoRamp::Instant)
+//!     .with_event(TimePoint::new(8.0), Tempo::new(120.0), TempoRamp::Linear);
+//! assert_eq!(map.tempo_at(TimePoint::new(6.0)).bpm(), 90.0);
+//! let seconds = map.seconds_at(TimePoint::new(8.0));
+//! assert!((seconds - (4.0 + 4.0 * 2_f64.ln())).abs() < 1e-12);
+//! assert_eq!(map.point_at_seconds(seconds), TimePoint::new(8.0));
+//! ```
+
+use crate::{Tempo, TimePoint, TimeSpan};
+
+/// How the tempo reaches an event from the previous one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum TempoRamp {
+    /// Hold the previous tempo, then jump at the event.
+    #[default]
+    Instant,
+    /// BPM changes linearly with beat position.
+    Linear,
+    /// BPM changes by a constant ratio per beat (even-sounding accelerando).
+    Exponential,
+}
+
+impl TempoRamp {
+    pub const ALL: [Self; 3] = [Self::Instant, Self::Linear, Self::Exponential];
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Instant => "instant",
+            Self::Linear => "linear",
+            Self::Exponential => "exponential",
+        }
+    }
+}
+
+/// Tempo reached at a beat position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TempoEvent {
+    pub at: TimePoint,
+    pub tempo: Tempo,
+    /// Approach from the previous event (ignored for the first event).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ramp: TempoRamp,
+}
+
+impl TempoEvent {
+    #[must_use]
+    pub fn new(at: TimePoint, tempo: Tempo, ramp: TempoRamp) -> Self {
+        Self { at, tempo, ramp }
+    }
+}
+
+/// Piecewise tempo curve with beat ↔ seconds conversion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TempoMap {
+    events: Vec<TempoEvent>,
+    /// Seconds elapsed at each event.
+    seconds: Vec<f64>,
+}
+
+impl TempoMap {
+    /// Constant `initial` tempo from beat zero.
+    #[must_use]
+    pub fn new(initial: Tempo) -> Self {
+        Self {
+            events: vec![TempoEvent::new(TimePoint::zero(), initial, TempoRamp::Instant)],
+            seconds: vec![0.0],
+        }
+    }
+
+    /// Map from events in any order; the earliest event's tempo also applies before it.
+    /// Returns `None` for an empty list.
+    #[must_use]
+    pub fn from_events(events: &[TempoEvent]) -> Option<Self> {
+        let earliest = events.iter().min_by_key(|event| event.at)?;
+        let mut map = Self::new(earliest.tempo);
+        for event in events {
+            map.insert(*event);
+        }
+        Some(map)
+    }
+
+    /// Builder form of [`TempoMap::insert`].
+    #[must_use]
+    pub fn with_event(mut self, at: TimePoint, tempo: Tempo, ramp: TempoRamp) -> Self {
+        self.insert(TempoEvent::new(at, tempo, ramp));
+        self
+    }
+
+    /// Add an event, replacing any event at the same position.
+    pub fn insert(&mut self, event: TempoEvent) {
+        match self.events.binary_search_by_key(&event.at, |existing| existing.at) {
+            Ok(index) => self.events[index] = event,
+            Err(index) => self.events.insert(index, event),
+        }
+        self.seconds = self
+            .events
+            .iter()
+            .scan((0.0, None), |(total, previous), event| {
+                if let Some(previous) = previous.replace(*event) {
+                    *total += segment_seconds(&previous, event, event.at - previous.at);
+                }
+                Some(*total)
+            })
+            .collect();
+    }
+
+    /// Events in time order; the first sits at beat zero.
+    #[must_use]
+    pub fn events(&self) -> &[TempoEvent] {
+        &self.events
+    }
+
+    /// Instantaneous tempo at `point` (the new tempo at an instantaneous change).
+    #[must_use]
+    pub fn tempo_at(&self, point: TimePoint) -> Tempo {
+        let index = self.segment(point);
+        let current = &self.events[index];
+        match self.events.get(index + 1) {
+            Some(next) if next.ramp != TempoRamp::Instant => {
+                let fraction = (point - current.at).as_beats() / (next.at - current.at).as_beats();
+                Tempo::new(ramp_bpm(current.tempo.bpm(), next.tempo.bpm(), next.ramp, fraction))
+            }
+            _ => current.tempo,
+        }
+    }
+
+    /// Seconds from beat zero to `point`.
+    #[must_use]
+    pub fn seconds_at(&self, point: TimePoint) -> f64 {
+        let index = self.segment(point);
+        let current = &self.events[index];
+        let into = point - current.at;
+        let partial = match self.events.get(index + 1) {
+            Some(next) => segment_seconds(current, next, into),
+            None => current.tempo.seconds_for_span(into),
+        };
+        self.seconds[index] + partial
+    }
+
+    /// Seconds between two points (negative when `end` precedes `start`).
+    #[must_use]
+    pub fn seconds_between(&self, start: TimePoint, end: TimePoint) -> f64 {
+        self.seconds_at(end) - self.seconds_at(start)
+    }
+
+    /// Beat position reached after `seconds` (snapped as in [`TimePoint::new`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `seconds` is negative or not finite.
+    #[must_use]
+    pub fn point_at_seconds(&self, seconds: f64) -> TimePoint {
+        assert!(seconds.is_finite() && seconds >= 0.0, "seconds must be non-negative and finite");
+        let index = self.seconds.partition_point(|start| *start <= seconds).saturating_sub(1);
+        let current = &self.events[index];
+        let into = seconds - self.seconds[index];
+        let beats = match self.events.get(index + 1) {
+            Some(next) => {
+                let length = (next.at - current.at).as_beats();
+                let (from, to) = (current.tempo.bpm(), next.tempo.bpm());
+                match next.ramp {
+                    TempoRamp::Linear if from != to => {
+                        let reached = from * (into * (to - from) / (60.0 * length)).exp();
+                        (reached - from) * length / (to - from)
+                    }
+                    TempoRamp::Exponential if from != to => {
+                        let ratio = (to / from).ln();
+                        -length * (1.0 - into * from * ratio / (60.0 * length)).ln() / ratio
+                    }
+                    _ => into * from / 60.0,
+                }
+            }
+            None => into * current.tempo.bpm() / 60.0,
+        };
+        current.at + TimeSpan::new(beats.max(0.0))
+    }
+
+    /// Index of the event governing `point` (the last at or before it).
+    fn segment(&self, point: TimePoint) -> usize {
+        self.events.partition_point(|event| event.at <= point).saturating_sub(1)
+    }
+}
+
+/// BPM `fraction` of the way along a ramp from `from` to `to`.
+fn ramp_bpm(from: f64, to: f64, ramp: TempoRamp, fraction: f64) -> f64 {
+    match ramp {
+        TempoRamp::Instant => from,
+        TempoRamp::Linear => from + (to - from) * fraction,
+        TempoRamp::Exponential => from * (to / from).powf(fraction),
+    }
+}
+
+/// Seconds spent in the first `into` beats of the segment from `current` to `next`.
+fn segment_seconds(current: &TempoEvent, next: &TempoEvent, into: TimeSpan) -> f64 {
+    let length = (next.at - current.at).as_beats();
+    let beats = into.as_beats();
+    let (from, to) = (current.tempo.bpm(), next.tempo.bpm());
+    match next.ramp {
+        // ∫ 60 / (from + (to - from)·u/L) du = 60·L/(to - from) · ln(bpm(x)/from)
+        TempoRamp::Linear if from != to && length > 0.0 => {
+            let reached = ramp_bpm(from, to, TempoRamp::Linear, beats / length);
+            60.0 * length / (to - from) * (reached / from).ln()
+        }
+        // ∫ 60 / (from·r^(u/L)) du = 60·L/(from·ln r) · (1 - r^(-x/L))
+        TempoRamp::Exponential if from != to && length > 0.0 => {
+            let ratio = (to / from).ln();
+            60.0 * length / (from * ratio) * (1.0 - (-ratio * beats / length).exp())
+        }
+        _ => current.tempo.seconds_for_span(into),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(beats: f64) -> TimePoint {
+        TimePoint::new(beats)
+    }
+
+    #[test]
+    fn constant_and_stepped_maps_match_tempo() {
+        let map = TempoMap::new(Tempo::new(120.0));
+        assert!(
+            (map.seconds_at(at(10.0)) - Tempo::new(120.0).seconds_for_span(TimeSpan::new(10.0)))
+                .abs()
+                < 1e-12
+        );
+        let stepped = map.with_event(at(4.0), Tempo::new(60.0), TempoRamp::Instant);
+        assert_eq!(stepped.tempo_at(at(3.5)).bpm(), 120.0);
+        assert_eq!(stepped.tempo_at(at(4.0)).bpm(), 60.0);
+        assert!((stepped.seconds_at(at(6.0)) - 4.0).abs() < 1e-12);
+        assert_eq!(stepped.point_at_seconds(4.0), at(6.0));
+        assert!((stepped.seconds_between(at(6.0), at(2.0)) + 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ramps_integrate_in_closed_form() {
+        let ramp =
+            |shape| TempoMap::new(Tempo::new(60.0)).with_event(at(4.0), Tempo::new(120.0), shape);
+        let linear = ramp(TempoRamp::Linear);
+        assert!((linear.seconds_at(at(4.0)) - 4.0 * 2_f64.ln()).abs() < 1e-12);
+        let exponential = ramp(TempoRamp::Exponential);
+        assert!((exponential.seconds_at(at(4.0)) - 2.0 / 2_f64.ln()).abs() < 1e-12);
+        assert!((exponential.tempo_at(at(2.0)).bpm() - 60.0 * 2_f64.sqrt()).abs() < 1e-9);
+        // Past the last event the final tempo holds.
+        assert!((linear.seconds_at(at(6.0)) - linear.seconds_at(at(4.0)) - 1.0).abs() < 1e-12);
+        let ritardando = TempoMap::new(Tempo::new(120.0)).with_event(
+            at(8.0),
+            Tempo::new(40.0),
+            TempoRamp::Exponential,
+        );
+        assert!(ritardando.seconds_at(at(8.0)) > 4.0);
+    }
+
+    #[test]
+    fn seconds_and_beats_round_trip_across_segments() {
+        let map = TempoMap::from_events(&[
+            TempoEvent::new(at(12.0), Tempo::new(90.0), TempoRamp::Exponential),
+            TempoEvent::new(at(0.0), Tempo::new(72.0), TempoRamp::Instant),
+            TempoEvent::new(at(4.0), Tempo::new(72.0), TempoRamp::Instant),
+            TempoEvent::new(at(8.0), Tempo::new(144.0), TempoRamp::Linear),
+        ])
+        .expect("events");
+        assert_eq!(map.events().len(), 4);
+        for point in
+            [at(0.0), at(2.5), at(4.0), TimePoint::exact(17, 3), at(8.0), at(10.25), at(20.0)]
+        {
+            assert_eq!(map.point_at_seconds(map.seconds_at(point)), point, "{point:?}");
+        }
+        assert!(TempoMap::from_events(&[]).is_none());
+    }
+}
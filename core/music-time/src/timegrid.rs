@@ -3,6 +3,7 @@
 
 use crate::{
     meter::Meter,
+    metermap::MeterMap,
     timespan::{TimePoint, TimeSpan},
 };
 
@@ -62,32 +63,31 @@ impl GridConfig {
 
     #[must_use]
     pub fn build(self) -> TimeGrid {
-        let measure_span = self.meter.bar_span();
-        let measures = accumulate(self.start, usize::try_from(self.bars).unwrap(), measure_span);
+        let measures = accumulate(self.start, self.bars, self.meter.bar_span());
 
-        let beats_per_bar = usize::from(self.meter.numerator);
-        let total_beats = beats_per_bar * usize::try_from(self.bars).unwrap();
-        let beat_span = TimeSpan::new(4.0 / f64::from(self.meter.denominator));
+        let total_beats = u32::from(self.meter.numerator) * self.bars;
+        let beat_span = self.meter.unit_span();
         let beats = accumulate(self.start, total_beats, beat_span);
 
-        let subdivision_steps = total_beats * usize::try_from(self.subdivisions_per_beat).unwrap();
-        let subdivision_span =
-            TimeSpan::new(beat_span.as_beats() / f64::from(self.subdivisions_per_beat));
+        let subdivision_steps = total_beats * self.subdivisions_per_beat;
+        let subdivision_span = beat_span / self.subdivisions_per_beat;
         let subdivisions = accumulate(self.start, subdivision_steps, subdivision_span);
 
         TimeGrid { measures, beats, subdivisions }
     }
-}
 
-fn accumulate(start: TimePoint, steps: usize, increment: TimeSpan) -> Vec<TimePoint> {
-    let mut points = Vec::with_capacity(steps + 1);
-    points.push(start);
-    let mut current = start;
-    for _ in 0..steps {
-        current = current + increment;
-        points.push(current);
+    /// Build across the meter changes of `map` (see [`MeterMap::grid`]): `bars` bars from the
+    /// downbeat of the bar containing `start`, each taking its meter and length from the map,
+    /// so `meter` is ignored.
+    #[must_use]
+    pub fn build_across(self, map: &MeterMap) -> TimeGrid {
+        map.grid(map.bar_at(self.start), self.bars, self.subdivisions_per_beat)
     }
-    points
+}
+
+/// `start` and the `steps` exact multiples of `increment` after it.
+fn accumulate(start: TimePoint, steps: u32, increment: TimeSpan) -> Vec<TimePoint> {
+    (0..=steps).map(|step| start + increment * step).collect()
 }
 
 #[cfg(test)]
@@ -121,4 +121,31 @@ mod tests {
             grid.measures.last().unwrap().as_beats() - grid.measures.first().unwrap().as_beats();
         assert!((span - Meter::SEVEN_EIGHT.bar_span().as_beats() * 32.0).abs() < 1e-6);
     }
+
+    #[test]
+    fn triplet_grids_land_exactly_on_beats() {
+        let grid = GridConfig::new(TimePoint::exact(1, 3), Meter::FOUR_FOUR)
+            .bars(64)
+            .subdivisions_per_beat(3)
+            .build();
+        for (index, beat) in grid.beats().iter().enumerate() {
+            assert_eq!(grid.subdivisions()[index * 3], *beat);
+        }
+        assert_eq!(grid.measures().last(), Some(&TimePoint::exact(769, 3)));
+        assert!(grid.subdivisions().windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn grid_follows_meter_map_from_the_pickup() {
+        let map = MeterMap::new(Meter::THREE_FOUR)
+            .with_pickup(TimeSpan::new(1.0))
+            .with_change(2, Meter::SIX_EIGHT);
+        let config =
+            GridConfig::new(TimePoint::new(0.5), Meter::FOUR_FOUR).bars(3).subdivisions_per_beat(2);
+        let grid = config.build_across(&map);
+        let at = |beats: f64| TimePoint::new(beats);
+        assert_eq!(grid.measures(), [at(0.0), at(1.0), at(4.0), at(7.0)]);
+        assert_eq!(grid.beats()[4..6], [at(4.0), at(4.5)]);
+        assert_eq!(grid.subdivisions(), map.grid(0, 3, 2).subdivisions());
+    }
 }
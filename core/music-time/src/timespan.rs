@@ -1,97 +1,168 @@
 //! core/music-time/src/timespan.rs
 //! Time points and spans in beats.
+//!
+//! Both store exact [`Rational`] beat counts, so tuplet positions compare, hash, and order
+//! reliably; the `f64` constructors and accessors remain for interop.
 
-use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
 
-use crate::Beat;
+use crate::{Beat, Rational, Tempo};
 
 /// Absolute musical time point in beats.
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-pub struct TimePoint(f64);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TimePoint(Rational);
 
 /// Musical duration in beats.
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-pub struct TimeSpan(f64);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TimeSpan(Rational);
 
 impl TimePoint {
     /// Construct a time point with non-negative beat value.
+    ///
+    /// The value is snapped to the float grid (see [`Rational::from_f64`]).
     #[must_use]
     pub fn new(beats: f64) -> Self {
         assert!(beats.is_finite() && beats >= 0.0, "time point must be non-negative and finite");
+        Self::from_rational(Rational::from_f64(beats).expect("time point out of range"))
+    }
+
+    /// Construct from an exact beat count.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `beats` is negative.
+    #[must_use]
+    pub fn from_rational(beats: Rational) -> Self {
+        assert!(!beats.is_negative(), "time point must be non-negative and finite");
         Self(beats)
     }
 
+    /// `numerator / denominator` beats.
+    #[must_use]
+    pub fn exact(numerator: i64, denominator: i64) -> Self {
+        Self::from_rational(Rational::new(numerator, denominator))
+    }
+
+    /// The origin.
+    #[must_use]
+    pub const fn zero() -> Self {
+        Self(Rational::ZERO)
+    }
+
     /// Create from a [`Beat`].
     #[must_use]
     pub fn from_beat(beat: Beat) -> Self {
-        Self::new(beat.as_f64())
+        Self(beat.as_rational())
     }
 
     /// Raw beat value.
     #[must_use]
     pub fn as_beats(&self) -> f64 {
+        self.0.to_f64()
+    }
+
+    /// Exact beat value.
+    #[must_use]
+    pub const fn as_rational(&self) -> Rational {
         self.0
     }
 
     /// Add a span, producing a new time point.
     #[must_use]
     pub fn add_span(self, span: TimeSpan) -> Self {
-        Self::new(self.0 + span.0)
+        Self(self.0 + span.0)
     }
 
     /// Subtract a span if possible, returning `None` when it would go negative.
     #[must_use]
     pub fn checked_sub_span(self, span: TimeSpan) -> Option<Self> {
-        (self.0 >= span.0).then(|| Self::new(self.0 - span.0))
+        (self.0 >= span.0).then(|| Self(self.0 - span.0))
     }
 
     /// Distance from another point (absolute difference).
     #[must_use]
     pub fn distance_to(self, other: TimePoint) -> TimeSpan {
-        TimeSpan::new((self.0 - other.0).abs())
+        TimeSpan((self.0 - other.0).abs())
+    }
+
+    /// Seconds since beat zero at a constant `tempo`.
+    #[must_use]
+    pub fn to_seconds(self, tempo: Tempo) -> f64 {
+        tempo.seconds_for_span(TimeSpan(self.0))
     }
 }
 
 impl TimeSpan {
     /// Construct a span from raw beat units.
     ///
+    /// The value is snapped to the float grid (see [`Rational::from_f64`]).
+    ///
     /// # Panics
     ///
     /// Panics if `beats` is negative or not finite.
     #[must_use]
     pub fn new(beats: f64) -> Self {
         assert!(beats.is_finite() && beats >= 0.0, "duration must be non-negative and finite");
+        Self::from_rational(Rational::from_f64(beats).expect("duration out of range"))
+    }
+
+    /// Construct from an exact beat count.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `beats` is negative.
+    #[must_use]
+    pub fn from_rational(beats: Rational) -> Self {
+        assert!(!beats.is_negative(), "duration must be non-negative and finite");
         Self(beats)
     }
 
+    /// `numerator / denominator` beats (`TimeSpan::exact(1, 3)` is a triplet eighth).
+    #[must_use]
+    pub fn exact(numerator: i64, denominator: i64) -> Self {
+        Self::from_rational(Rational::new(numerator, denominator))
+    }
+
     /// Convert from a [`Beat`] wrapper (beat magnitude interpreted as duration).
     #[must_use]
     pub fn from_beats(beat: Beat) -> Self {
-        Self::new(beat.as_f64())
+        Self(beat.as_rational())
     }
 
     /// A zero-length span.
     #[must_use]
     pub const fn zero() -> Self {
-        Self(0.0)
+        Self(Rational::ZERO)
     }
 
     /// Return the raw beat count.
     #[must_use]
     pub fn as_beats(&self) -> f64 {
+        self.0.to_f64()
+    }
+
+    /// Exact beat count.
+    #[must_use]
+    pub const fn as_rational(&self) -> Rational {
         self.0
     }
 
     /// Add two spans.
     #[must_use]
     pub fn add_span(self, other: Self) -> Self {
-        Self::new(self.0 + other.0)
+        Self(self.0 + other.0)
     }
 
     /// Subtract a smaller span, returning `None` if it would become negative.
     #[must_use]
     pub fn checked_sub(self, other: Self) -> Option<Self> {
-        (self.0 >= other.0).then(|| Self::new(self.0 - other.0))
+        (self.0 >= other.0).then(|| Self(self.0 - other.0))
+    }
+
+    /// Length in seconds at a constant `tempo`.
+    #[must_use]
+    pub fn to_seconds(self, tempo: Tempo) -> f64 {
+        tempo.seconds_for_span(self)
     }
 }
 
@@ -116,7 +187,7 @@ impl Sub for TimePoint {
 
     fn sub(self, rhs: Self) -> Self::Output {
         assert!(self.0 >= rhs.0, "time point subtraction cannot go negative");
-        TimeSpan::new(self.0 - rhs.0)
+        TimeSpan(self.0 - rhs.0)
     }
 }
 
@@ -148,6 +219,24 @@ impl SubAssign for TimeSpan {
     }
 }
 
+impl Mul<u32> for TimeSpan {
+    type Output = Self;
+
+    fn mul(self, rhs: u32) -> Self::Output {
+        Self(self.0 * Rational::from(rhs))
+    }
+}
+
+impl Div<u32> for TimeSpan {
+    type Output = Self;
+
+    /// Split into `rhs` equal parts.
+    fn div(self, rhs: u32) -> Self::Output {
+        assert!(rhs > 0, "cannot divide a duration into zero parts");
+        Self(self.0 / Rational::from(rhs))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -175,6 +264,26 @@ mod tests {
         assert!((span.as_beats() - 1.5).abs() < 1e-9);
     }
 
+    #[test]
+    fn tuplets_accumulate_exactly() {
+        let quintuplet = TimeSpan::new(1.0) / 5;
+        let mut point = TimePoint::zero();
+        for _ in 0..5 {
+            point = point + quintuplet;
+        }
+        assert_eq!(point, TimePoint::new(1.0));
+        assert_eq!(TimeSpan::new(1.0 / 3.0), TimeSpan::exact(1, 3));
+        assert_eq!(TimeSpan::exact(1, 3) * 6, TimeSpan::new(2.0));
+        let positions: std::collections::HashSet<TimePoint> =
+            [TimePoint::exact(2, 3), TimePoint::new(2.0 / 3.0)].into_iter().collect();
+        assert_eq!(positions.len(), 1);
+        assert!((TimePoint::exact(3, 2).to_seconds(Tempo::new(90.0)) - 1.0).abs() < 1e-12);
+        let humanized = [0.123_456_7, 0.765_432_1, 0.314_159_2, 0.271_828_1, 0.577_215_6];
+        let total =
+            humanized.iter().fold(TimePoint::zero(), |at, beats| at + TimeSpan::new(*beats));
+        assert_eq!(Rational::FLOAT_GRID % total.as_rational().denominator(), 0);
+    }
+
     #[test]
     #[should_panic(expected = "cannot subtract span beyond origin")]
     fn timepoint_sub_panics_when_negative() {
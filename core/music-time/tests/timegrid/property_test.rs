@@ -1,4 +1,4 @@
-use music_time::{TimePoint, meter::Meter, timegrid::GridConfig};
+use music_time::{MeterMap, TimePoint, meter::Meter, timegrid::GridConfig};
 use proptest::prelude::*;
 
 fn arb_meter() -> impl Strategy<Value = Meter> {
@@ -51,4 +51,32 @@ proptest! {
         let first_bar = grid_b.measures()[1] - grid_b.measures()[0];
         prop_assert!((first_bar.as_beats() - meter_b.bar_span().as_beats()).abs() <= 1e-9 * (1.0 + meter_b.bar_span().as_beats()));
     }
+
+    #[test]
+    fn meter_map_grids_match_chained_grids(
+        meter_a in arb_meter(),
+        meter_b in arb_meter(),
+        bars_a in 1u32..=16,
+        bars_b in 1u32..=16,
+        subdivisions in 1u32..=4,
+    ) {
+        let map = MeterMap::new(meter_a).with_change(1 + bars_a, meter_b);
+        let grid = map.grid(1, bars_a + bars_b, subdivisions);
+        let grid_a = GridConfig::new(TimePoint::zero(), meter_a)
+            .bars(bars_a)
+            .subdivisions_per_beat(subdivisions)
+            .build();
+        let handoff = *grid_a.measures().last().unwrap();
+        let grid_b = GridConfig::new(handoff, meter_b)
+            .bars(bars_b)
+            .subdivisions_per_beat(subdivisions)
+            .build();
+
+        let chained: Vec<_> =
+            grid_a.subdivisions().iter().chain(&grid_b.subdivisions()[1..]).copied().collect();
+        prop_assert_eq!(grid.subdivisions(), chained.as_slice());
+        for point in grid.subdivisions() {
+            prop_assert_eq!(map.to_point(map.to_bbt(*point)), Some(*point));
+        }
+    }
 }
@@ -27,4 +27,12 @@ proptest! {
         let back = after - span;
         prop_assert!(approx_eq(start.as_beats(), back.as_beats()));
     }
+
+    #[test]
+    fn float_spans_sum_without_overflow(spans in prop::collection::vec(0.0f64..=10_000.0, 0..64)) {
+        let total = spans.iter().fold(TimePoint::zero(), |point, beats| point + TimeSpan::new(*beats));
+        let expected: f64 = spans.iter().sum();
+        // Each span is snapped by at most half a grid tick.
+        prop_assert!((total.as_beats() - expected).abs() <= 1e-9 * (1.0 + expected) + spans.len() as f64 / 80_640.0);
+    }
 }